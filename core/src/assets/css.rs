@@ -0,0 +1,295 @@
+//! Stylesheet compilation: Sass/SCSS files are compiled with grass, then every stylesheet is
+//! bundled with lightningcss (`@import`s are inlined), prefixed for the configured browserslist
+//! targets and minified when enabled.
+//!
+//! SCSS partials (`_variables.scss`) are only compiled into the stylesheets using them, the
+//! other stylesheets are published with a `.css` extension. grass does not emit source maps, so
+//! only plain CSS stylesheets get one.
+//!
+//! Invalid rules and declarations are left out of the compiled stylesheets instead of failing
+//! the compilation, and reported as warnings.
+
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, RwLock};
+
+use colored::Colorize;
+use eyre::{eyre, Result};
+use lightningcss::bundler::{Bundler, FileProvider};
+use lightningcss::error::{Error, ParserError};
+use lightningcss::stylesheet::{MinifyOptions, ParserOptions, PrinterOptions, StyleSheet};
+use lightningcss::targets::{Browsers, Targets};
+use parcel_sourcemap::SourceMap;
+
+use crate::config::SiteConfigAssets;
+
+/// A compiled stylesheet
+#[derive(Debug)]
+pub struct CompiledCss {
+    pub code: String,
+    /// Source map of the stylesheet, as JSON
+    pub map: Option<String>,
+    /// The invalid rules and declarations left out of the stylesheet
+    pub warnings: Vec<String>,
+}
+
+/// Compiles the site stylesheets
+#[derive(Debug, Clone, Default)]
+pub struct CssCompiler {
+    targets: Targets,
+    minify: bool,
+    source_maps: bool,
+}
+
+impl CssCompiler {
+    /// Creates a stylesheet compiler
+    ///
+    /// # Arguments
+    /// * `config` - The `[assets]` configuration, stylesheets are prefixed for its
+    ///   `browserslist` query.
+    /// * `minify` - Whether stylesheets are minified.
+    /// * `source_maps` - Whether source maps are generated for plain CSS stylesheets, SCSS
+    ///   stylesheets never have one.
+    pub fn new(config: Option<&SiteConfigAssets>, minify: bool, source_maps: bool) -> Result<Self> {
+        let browsers = match config.and_then(|assets| assets.browserslist.as_deref()) {
+            Some(query) => Browsers::from_browserslist([query])
+                .map_err(|e| eyre!("{} '{}': {}", "Invalid browserslist query".bold(), query, e))?,
+            None => None,
+        };
+        Ok(Self {
+            targets: Targets {
+                browsers,
+                ..Default::default()
+            },
+            minify,
+            source_maps,
+        })
+    }
+
+    /// Compiles a stylesheet along with the stylesheets it imports
+    ///
+    /// # Arguments
+    /// * `assets_dir` - The assets directory the stylesheet belongs to, source map paths are
+    ///   relative to it.
+    /// * `rel_path` - The stylesheet path relative to `assets_dir`.
+    pub fn compile(&self, assets_dir: &Path, rel_path: &Path) -> Result<CompiledCss> {
+        let path = assets_dir.join(rel_path);
+        let compile_error = |e: &dyn std::fmt::Display| {
+            eyre!(
+                "{} {}: {}",
+                "Failed to compile stylesheet".bold(),
+                path.display(),
+                e
+            )
+        };
+
+        let parser_warnings = Arc::new(RwLock::new(Vec::new()));
+
+        if is_scss(rel_path) {
+            // grass already inlines the imported stylesheets and does not emit source maps
+            let css = grass::from_path(&path, &grass::Options::default())
+                .map_err(|e| compile_error(&e))?;
+            let mut stylesheet = StyleSheet::parse(&css, parser_options(&path, &parser_warnings))
+                .map_err(|e| compile_error(&e))?;
+            let code = self
+                .print(&mut stylesheet, None)
+                .map_err(|e| compile_error(&e))?;
+            // Positions refer to the CSS compiled by grass, not to the SCSS source
+            let warnings = format_warnings(&parser_warnings, false);
+            return Ok(CompiledCss {
+                code,
+                map: None,
+                warnings,
+            });
+        }
+
+        let provider = FileProvider::new();
+        let mut source_map = self
+            .source_maps
+            .then(|| SourceMap::new(&assets_dir.to_string_lossy()));
+        let mut stylesheet = {
+            let mut bundler = Bundler::new(
+                &provider,
+                source_map.as_mut(),
+                parser_options(&path, &parser_warnings),
+            );
+            bundler.bundle(&path).map_err(|e| compile_error(&e))?
+        };
+        let code = self
+            .print(&mut stylesheet, source_map.as_mut())
+            .map_err(|e| compile_error(&e))?;
+        let map = match source_map.as_mut() {
+            Some(source_map) => Some(
+                source_map
+                    .to_json(Some("/assets/"))
+                    .map_err(|e| compile_error(&e))?,
+            ),
+            None => None,
+        };
+        let warnings = format_warnings(&parser_warnings, true);
+        Ok(CompiledCss {
+            code,
+            map,
+            warnings,
+        })
+    }
+
+    fn print(
+        &self,
+        stylesheet: &mut StyleSheet,
+        source_map: Option<&mut SourceMap>,
+    ) -> Result<String> {
+        stylesheet.minify(MinifyOptions {
+            targets: self.targets,
+            ..Default::default()
+        })?;
+        let css = stylesheet.to_css(PrinterOptions {
+            minify: self.minify,
+            source_map,
+            targets: self.targets,
+            ..Default::default()
+        })?;
+        Ok(css.code)
+    }
+}
+
+type ParserWarnings<'i> = Arc<RwLock<Vec<Error<ParserError<'i>>>>>;
+
+fn parser_options<'i>(path: &Path, warnings: &ParserWarnings<'i>) -> ParserOptions<'i> {
+    ParserOptions {
+        filename: path.to_string_lossy().into_owned(),
+        error_recovery: true,
+        warnings: Some(Arc::clone(warnings)),
+        ..Default::default()
+    }
+}
+
+/// Formats the parser warnings as `<message> at <file>:<line>:<column>`
+///
+/// # Arguments
+/// * `warnings` - The warnings collected by the parser.
+/// * `with_location` - Whether the warnings locations refer to the stylesheet sources.
+fn format_warnings(warnings: &ParserWarnings<'_>, with_location: bool) -> Vec<String> {
+    let Ok(warnings) = warnings.read() else {
+        return Vec::new();
+    };
+    warnings
+        .iter()
+        .map(|warning| match &warning.loc {
+            // lightningcss lines are 0-based
+            Some(loc) if with_location => format!(
+                "{} at {}:{}:{}",
+                warning.kind,
+                loc.filename,
+                loc.line + 1,
+                loc.column
+            ),
+            _ => warning.kind.to_string(),
+        })
+        .collect()
+}
+
+/// Whether a file is a stylesheet published by the build, i.e. a CSS file or a SCSS file that
+/// is not a partial
+pub fn is_stylesheet(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| ext == "css") || (is_scss(path) && !is_partial(path))
+}
+
+/// Whether a file is a SCSS partial, only used by the stylesheets importing it
+pub fn is_partial(path: &Path) -> bool {
+    is_scss(path)
+        && path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| name.starts_with('_'))
+}
+
+fn is_scss(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| ext == "scss")
+}
+
+/// Returns the published path of an asset, SCSS stylesheets are published as CSS
+pub fn output_path(rel_path: &Path) -> PathBuf {
+    if is_scss(rel_path) {
+        rel_path.with_extension("css")
+    } else {
+        rel_path.to_path_buf()
+    }
+}
+
+/// Finds the source of a published asset in the assets directories, e.g. `css/style.scss` for
+/// `css/style.css`. The first directories take precedence
+///
+/// # Returns
+/// The assets directory holding the source and the source path relative to it.
+pub fn find_source<'a>(assets_dirs: &[&'a Path], rel_path: &Path) -> Option<(&'a Path, PathBuf)> {
+    let scss_path = rel_path
+        .extension()
+        .is_some_and(|ext| ext == "css")
+        .then(|| rel_path.with_extension("scss"));
+    assets_dirs.iter().find_map(|dir| {
+        std::iter::once(rel_path.to_path_buf())
+            .chain(scss_path.clone())
+            .find(|path| dir.join(path).is_file())
+            .map(|path| (*dir, path))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compile_stylesheets() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join("css")).unwrap();
+        std::fs::write(dir.path().join("css/base.css"), "body { color: red; }").unwrap();
+        std::fs::write(
+            dir.path().join("css/main.css"),
+            "@import \"base.css\";\nh1 { color: blue; }",
+        )
+        .unwrap();
+        std::fs::write(dir.path().join("css/_colors.scss"), "$accent: #ff0000;").unwrap();
+        std::fs::write(
+            dir.path().join("css/style.scss"),
+            "@import \"colors\";\nnav { a { color: $accent; } }",
+        )
+        .unwrap();
+
+        let compiler = CssCompiler::new(None, true, true).unwrap();
+        let main = compiler
+            .compile(dir.path(), Path::new("css/main.css"))
+            .unwrap();
+        assert_eq!(main.code, "body{color:red}h1{color:#00f}");
+        assert!(main.map.unwrap().contains("css/base.css"));
+        assert!(main.warnings.is_empty());
+
+        let style = compiler
+            .compile(dir.path(), Path::new("css/style.scss"))
+            .unwrap();
+        assert_eq!(style.code, "nav a{color:red}");
+        assert!(style.map.is_none());
+
+        std::fs::write(
+            dir.path().join("css/broken.css"),
+            "h1 { color: red; }\nh2 > > p { color: red; }\np { color: blue; }",
+        )
+        .unwrap();
+        let broken = compiler
+            .compile(dir.path(), Path::new("css/broken.css"))
+            .unwrap();
+        assert_eq!(broken.code, "h1{color:red}p{color:#00f}");
+        assert_eq!(broken.warnings.len(), 1);
+        assert!(broken.warnings[0].contains("css/broken.css:2:"));
+
+        assert!(is_partial(Path::new("css/_colors.scss")));
+        assert!(!is_stylesheet(Path::new("css/_colors.scss")));
+        assert_eq!(
+            output_path(Path::new("css/style.scss")),
+            PathBuf::from("css/style.css")
+        );
+        assert_eq!(
+            find_source(&[dir.path()], Path::new("css/style.css")),
+            Some((dir.path(), PathBuf::from("css/style.scss")))
+        );
+    }
+}
@@ -0,0 +1,371 @@
+//! Asset fingerprinting: CSS, JS and image assets are renamed to content-hashed file names
+//! (`css/style.css` -> `css/style.1a2b3c4d.css`) so CDNs can cache them forever.
+//!
+//! References to the assets are rewritten in the rendered HTML and in the `url()`s and
+//! `@import`s of stylesheets, templates can also get the fingerprinted path with the
+//! `asset_url` Tera function.
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Component, Path};
+use std::sync::OnceLock;
+
+use eyre::{eyre, Result};
+use walkdir::WalkDir;
+
+use super::css::{self, CssCompiler};
+use super::js::JsBundles;
+
+/// Extensions of the fingerprinted assets
+const FINGERPRINTED_EXTENSIONS: &[&str] = &[
+    "css", "js", "mjs", "png", "jpg", "jpeg", "gif", "svg", "webp", "avif", "ico",
+];
+
+/// Number of hash characters in fingerprinted file names
+const HASH_LENGTH: usize = 8;
+
+fn asset_url_re() -> &'static regex::Regex {
+    static RE: OnceLock<regex::Regex> = OnceLock::new();
+    RE.get_or_init(|| regex::Regex::new(r#"/assets/([^"'\s<>()?#,]+)"#).expect("valid regex"))
+}
+
+/// Fingerprinted names of the site assets
+#[derive(Debug, Clone, Default)]
+pub struct Fingerprints {
+    /// Fingerprinted path of each asset, both relative to the assets directory with `/`
+    /// separators
+    names: BTreeMap<String, String>,
+    /// Asset path of each fingerprinted path
+    originals: HashMap<String, String>,
+}
+
+impl Fingerprints {
+    /// Computes the fingerprinted names of the assets
+    ///
+    /// # Arguments
+    /// * `assets_dirs` - The assets directories, later ones override the assets of the previous
+    ///   ones (e.g. the theme assets and then the site assets).
+    /// * `compiler` - The stylesheet compiler, stylesheets are hashed once compiled.
+    /// * `bundles` - The JavaScript bundles, hashed instead of the modules bundled into them.
+    pub fn compute(
+        assets_dirs: &[&Path],
+        compiler: &CssCompiler,
+        bundles: &JsBundles,
+    ) -> Result<Self> {
+        let mut sources = BTreeMap::new();
+        for dir in assets_dirs.iter().filter(|dir| dir.exists()) {
+            for entry in WalkDir::new(dir)
+                .follow_links(true)
+                .into_iter()
+                .filter_map(|e| e.ok())
+                .filter(|e| e.file_type().is_file())
+            {
+                let Ok(rel_path) = entry.path().strip_prefix(dir) else {
+                    continue;
+                };
+                let output = css::output_path(rel_path);
+                if is_fingerprinted(&output)
+                    && !css::is_partial(rel_path)
+                    && !bundles.is_bundled(entry.path())
+                {
+                    sources.insert(to_asset_path(&output), (*dir, rel_path.to_path_buf()));
+                }
+            }
+        }
+
+        let mut fingerprints = Self::default();
+        for (output, bundle) in bundles.iter() {
+            fingerprints.insert(
+                &to_asset_path(output),
+                blake3::hash(bundle.code.as_bytes()).to_hex().as_str(),
+            );
+        }
+        let mut stylesheets = BTreeMap::new();
+        for (output, (dir, rel_path)) in &sources {
+            if css::is_stylesheet(rel_path) {
+                stylesheets.insert(output.clone(), compiler.compile(dir, rel_path)?.code);
+            } else {
+                let path = dir.join(rel_path);
+                let content = std::fs::read(&path)
+                    .map_err(|e| eyre!("Failed to read asset {}: {}", path.display(), e))?;
+                fingerprints.insert(output, blake3::hash(&content).to_hex().as_str());
+            }
+        }
+
+        // Stylesheets are hashed once the assets they reference are fingerprinted, so their name
+        // changes along with the name of the referenced assets
+        while !stylesheets.is_empty() {
+            let ready: Vec<String> = stylesheets
+                .iter()
+                .filter(|(rel_path, css)| {
+                    css_references(css, Path::new(rel_path.as_str()))
+                        .iter()
+                        .all(|reference| {
+                            !stylesheets.contains_key(reference) || reference == *rel_path
+                        })
+                })
+                .map(|(rel_path, _)| rel_path.clone())
+                .collect();
+            // Stylesheets importing each other are hashed with their references unresolved
+            let ready = if ready.is_empty() {
+                stylesheets.keys().cloned().collect()
+            } else {
+                ready
+            };
+            for rel_path in ready {
+                let css = stylesheets.remove(&rel_path).unwrap_or_default();
+                let rewritten = fingerprints.rewrite_css(&css, Path::new(&rel_path));
+                fingerprints.insert(
+                    &rel_path,
+                    blake3::hash(rewritten.as_bytes()).to_hex().as_str(),
+                );
+            }
+        }
+
+        Ok(fingerprints)
+    }
+
+    fn insert(&mut self, rel_path: &str, hash: &str) {
+        let (dir, file_name) = rel_path.rsplit_once('/').unwrap_or(("", rel_path));
+        let (stem, extension) = file_name.rsplit_once('.').unwrap_or((file_name, ""));
+        let fingerprinted = format!("{}.{}.{}", stem, &hash[..HASH_LENGTH], extension);
+        let fingerprinted = if dir.is_empty() {
+            fingerprinted
+        } else {
+            format!("{}/{}", dir, fingerprinted)
+        };
+        self.originals
+            .insert(fingerprinted.clone(), rel_path.to_string());
+        self.names.insert(rel_path.to_string(), fingerprinted);
+    }
+
+    /// Returns the fingerprinted path of an asset, relative to the assets directory
+    pub fn get(&self, rel_path: &Path) -> Option<&str> {
+        self.names.get(&to_asset_path(rel_path)).map(String::as_str)
+    }
+
+    /// Returns the asset path of a fingerprinted path, both relative to the assets directory
+    pub fn original(&self, fingerprinted: &Path) -> Option<&str> {
+        self.originals
+            .get(&to_asset_path(fingerprinted))
+            .map(String::as_str)
+    }
+
+    /// Returns the URL of an asset, e.g. `/assets/css/style.1a2b3c4d.css` for `css/style.css`
+    ///
+    /// Assets that are not fingerprinted keep their path.
+    pub fn url(&self, path: &str) -> String {
+        let path = path.trim_start_matches('/');
+        let path = path.strip_prefix("assets/").unwrap_or(path);
+        let path = self.names.get(path).map(String::as_str).unwrap_or(path);
+        format!("/assets/{}", path)
+    }
+
+    /// Hash of every fingerprinted name, pages depending on the assets are rendered again when
+    /// it changes
+    pub fn fingerprint(&self) -> String {
+        let mut hasher = blake3::Hasher::new();
+        for (rel_path, fingerprinted) in &self.names {
+            hasher.update(rel_path.as_bytes());
+            hasher.update(fingerprinted.as_bytes());
+        }
+        hasher.finalize().to_hex().to_string()
+    }
+
+    /// Rewrites the `/assets/...` references of a rendered page to the fingerprinted paths
+    pub fn rewrite_html(&self, html: &str) -> String {
+        asset_url_re()
+            .replace_all(html, |caps: &regex::Captures| {
+                match self.names.get(&caps[1]) {
+                    Some(fingerprinted) => format!("/assets/{}", fingerprinted),
+                    None => caps[0].to_string(),
+                }
+            })
+            .into_owned()
+    }
+
+    /// Rewrites the `url()`s and `@import`s of a stylesheet to the fingerprinted paths
+    ///
+    /// # Arguments
+    /// * `css` - The stylesheet content.
+    /// * `css_path` - The stylesheet path relative to the assets directory, used to resolve
+    ///   relative URLs.
+    pub fn rewrite_css(&self, css: &str, css_path: &Path) -> String {
+        map_css_urls(css, |url| {
+            let (target, suffix) = split_url_suffix(url);
+            let asset = resolve_css_url(target, css_path)?;
+            let fingerprinted = self.names.get(&asset)?;
+            let file_name = fingerprinted.rsplit('/').next().unwrap_or(fingerprinted);
+            let dir = target.rsplit_once('/').map(|(dir, _)| dir);
+            Some(match dir {
+                Some(dir) => format!("{}/{}{}", dir, file_name, suffix),
+                None => format!("{}{}", file_name, suffix),
+            })
+        })
+    }
+}
+
+/// Whether an asset gets a fingerprinted name
+fn is_fingerprinted(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| FINGERPRINTED_EXTENSIONS.contains(&ext.to_lowercase().as_str()))
+}
+
+/// Converts a path relative to the assets directory to a `/` separated asset path
+fn to_asset_path(path: &Path) -> String {
+    path.components()
+        .map(|c| c.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Lists the assets referenced by a stylesheet, relative to the assets directory
+fn css_references(css: &str, css_path: &Path) -> Vec<String> {
+    let mut references = Vec::new();
+    map_css_urls(css, |url| {
+        if let Some(asset) = resolve_css_url(split_url_suffix(url).0, css_path) {
+            references.push(asset);
+        }
+        None
+    });
+    references
+}
+
+/// Splits the query and fragment (e.g. `?v=2` or `#iefix`) off a URL
+fn split_url_suffix(url: &str) -> (&str, &str) {
+    let end = url.find(['?', '#']).unwrap_or(url.len());
+    url.split_at(end)
+}
+
+/// Resolves a stylesheet URL to an asset path, `None` for external and data URLs
+fn resolve_css_url(url: &str, css_path: &Path) -> Option<String> {
+    if url.is_empty() || url.starts_with("//") || url.contains(':') {
+        return None;
+    }
+    if let Some(asset) = url.strip_prefix("/assets/") {
+        return Some(asset.to_string());
+    }
+    if url.starts_with('/') {
+        return None;
+    }
+
+    let path = css_path.parent().unwrap_or(Path::new("")).join(url);
+    let mut resolved = Vec::new();
+    for component in path.components() {
+        match component {
+            // URLs leaving the assets directory are not assets
+            Component::ParentDir => {
+                resolved.pop()?;
+            }
+            Component::Normal(part) => resolved.push(part.to_string_lossy()),
+            _ => {}
+        }
+    }
+    Some(resolved.join("/"))
+}
+
+/// Replaces the URLs of the `url()`s and `@import`s of a stylesheet with the value returned by
+/// `f`, URLs are kept as-is when it returns `None`
+fn map_css_urls(css: &str, mut f: impl FnMut(&str) -> Option<String>) -> String {
+    let mut output = String::with_capacity(css.len());
+    let mut rest = css;
+    loop {
+        let (start, token) = match (rest.find("url("), rest.find("@import")) {
+            (Some(url), Some(import)) if import < url => (import, "@import"),
+            (Some(url), _) => (url, "url("),
+            (None, Some(import)) => (import, "@import"),
+            (None, None) => break,
+        };
+        let after = &rest[start + token.len()..];
+        let value = after.trim_start();
+        let value_start = start + token.len() + (after.len() - value.len());
+
+        let (offset, len) = match value.chars().next() {
+            Some(quote @ ('"' | '\'')) => match value[1..].find(quote) {
+                Some(end) => (1, end),
+                None => break,
+            },
+            // `@import url(...)` is handled as an `url()`
+            _ if token == "@import" => {
+                output.push_str(&rest[..start + token.len()]);
+                rest = after;
+                continue;
+            }
+            _ => match value.find(')') {
+                Some(end) => (0, value[..end].trim_end().len()),
+                None => break,
+            },
+        };
+
+        let url = &value[offset..offset + len];
+        output.push_str(&rest[..value_start + offset]);
+        match f(url.trim()) {
+            Some(new_url) => output.push_str(&new_url),
+            None => output.push_str(url),
+        }
+        rest = &rest[value_start + offset + len..];
+    }
+    output.push_str(rest);
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fingerprints() {
+        let dir = tempfile::tempdir().unwrap();
+        let theme = dir.path().join("theme");
+        let site = dir.path().join("site");
+        std::fs::create_dir_all(theme.join("css")).unwrap();
+        std::fs::create_dir_all(site.join("images")).unwrap();
+        std::fs::write(theme.join("css/base.css"), "body { color: red; }").unwrap();
+        std::fs::write(
+            theme.join("css/style.css"),
+            "@import \"base.css\";\nh1 { background: url(../images/bg.png?v=1); }",
+        )
+        .unwrap();
+        std::fs::write(site.join("images/bg.png"), "png").unwrap();
+        std::fs::write(site.join("robots.txt"), "txt").unwrap();
+
+        let fingerprints = Fingerprints::compute(
+            &[theme.as_path(), site.as_path()],
+            &CssCompiler::default(),
+            &JsBundles::default(),
+        )
+        .unwrap();
+        let bg = fingerprints
+            .get(Path::new("images/bg.png"))
+            .unwrap()
+            .to_string();
+        let base = fingerprints
+            .get(Path::new("css/base.css"))
+            .unwrap()
+            .to_string();
+        assert!(bg.starts_with("images/bg.") && bg.ends_with(".png"));
+        assert_eq!(fingerprints.get(Path::new("robots.txt")), None);
+        assert_eq!(fingerprints.original(Path::new(&bg)), Some("images/bg.png"));
+
+        let css = fingerprints.rewrite_css(
+            "@import \"base.css\";\nh1 { background: url(../images/bg.png?v=1); }",
+            Path::new("css/style.css"),
+        );
+        assert_eq!(
+            css,
+            format!(
+                "@import \"{}\";\nh1 {{ background: url(../{}?v=1); }}",
+                base.trim_start_matches("css/"),
+                bg
+            )
+        );
+
+        let html = fingerprints.rewrite_html(
+            "<link href=\"/assets/css/style.css\"><img src=\"/assets/images/bg.png\" srcset=\"/assets/photo-480w.webp 480w\">",
+        );
+        assert!(html.contains(&fingerprints.url("css/style.css")));
+        assert!(html.contains(&format!("/assets/{}", bg)));
+        assert!(html.contains("/assets/photo-480w.webp 480w"));
+        assert_eq!(fingerprints.url("/assets/robots.txt"), "/assets/robots.txt");
+    }
+}
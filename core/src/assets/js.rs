@@ -0,0 +1,378 @@
+//! JavaScript bundling: the entry points listed in `[assets] entries` are bundled with swc into
+//! single ES module files, with their TypeScript types stripped, unused code removed and
+//! minified when enabled.
+//!
+//! Modules bundled into an entry (and TypeScript files) are not published on their own.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use colored::Colorize;
+use eyre::{bail, eyre, Result};
+use swc_core::bundler::{Bundle, BundleKind, Bundler, Load, ModuleData, ModuleRecord};
+use swc_core::common::{sync::Lrc, FileName, Globals, Mark, SourceFile, SourceMap, Span, GLOBALS};
+use swc_core::ecma::ast::{EsVersion, KeyValueProp, Module, Program};
+use swc_core::ecma::codegen::text_writer::{omit_trailing_semi, JsWriter, WriteJs};
+use swc_core::ecma::codegen::{self, Emitter};
+use swc_core::ecma::loader::resolvers::{lru::CachingResolver, node::NodeModulesResolver};
+use swc_core::ecma::loader::TargetEnv;
+use swc_core::ecma::minifier::option::{
+    CompressOptions, ExtraOptions, MangleOptions, MinifyOptions, TopLevelOptions,
+};
+use swc_core::ecma::parser::{parse_file_as_module, EsSyntax, Syntax, TsSyntax};
+use swc_core::ecma::transforms::base::{fixer::fixer, resolver};
+use swc_core::ecma::transforms::typescript;
+
+/// Bundles JavaScript and TypeScript entry points
+#[derive(Debug, Clone, Copy, Default)]
+pub struct JsBundler {
+    minify: bool,
+}
+
+/// A bundled entry point
+#[derive(Debug)]
+pub struct JsBundle {
+    pub code: String,
+    /// The entry point source file
+    pub entry: PathBuf,
+    /// Every module bundled into the output, including the entry point
+    pub modules: Vec<PathBuf>,
+}
+
+impl JsBundler {
+    /// Creates a bundler, minifying the bundles when `minify` is set
+    pub fn new(minify: bool) -> Self {
+        Self { minify }
+    }
+
+    /// Bundles an entry point along with the modules it imports
+    pub fn bundle(&self, entry: &Path) -> Result<JsBundle> {
+        let bundle_error = |e: &dyn std::fmt::Display| {
+            eyre!("{} {}: {}", "Failed to bundle".bold(), entry.display(), e)
+        };
+
+        let cm: Lrc<SourceMap> = Default::default();
+        let globals = Globals::new();
+        let loader = Loader {
+            cm: cm.clone(),
+            loaded: Mutex::new(Vec::new()),
+        };
+        let module = GLOBALS.set(&globals, || -> Result<Module> {
+            let resolver = CachingResolver::new(
+                4096,
+                NodeModulesResolver::new(TargetEnv::Browser, Default::default(), true),
+            );
+            let mut bundler = Bundler::new(
+                &globals,
+                cm.clone(),
+                &loader,
+                resolver,
+                swc_core::bundler::Config {
+                    require: false,
+                    disable_inliner: false,
+                    disable_dce: false,
+                    ..Default::default()
+                },
+                Box::new(Hook),
+            );
+            let entries =
+                HashMap::from([("main".to_string(), FileName::Real(entry.to_path_buf()))]);
+            let bundles: Vec<Bundle> = bundler.bundle(entries).map_err(|e| bundle_error(&e))?;
+            let bundle = bundles
+                .into_iter()
+                .find(|bundle| matches!(bundle.kind, BundleKind::Named { .. }))
+                .ok_or_else(|| bundle_error(&"no output bundle"))?;
+            Ok(if self.minify {
+                minify(cm.clone(), bundle.module)
+            } else {
+                bundle.module
+            })
+        })?;
+
+        let code = emit(cm, &module, self.minify).map_err(|e| bundle_error(&e))?;
+        Ok(JsBundle {
+            code,
+            entry: entry.to_path_buf(),
+            modules: loader.loaded.into_inner().unwrap_or_default(),
+        })
+    }
+}
+
+/// The bundles of the entry points of a site
+#[derive(Debug, Default)]
+pub struct JsBundles {
+    /// Bundles keyed by their output path, relative to the assets directory
+    bundles: BTreeMap<PathBuf, JsBundle>,
+    /// Canonical paths of the bundled modules
+    modules: HashSet<PathBuf>,
+}
+
+impl JsBundles {
+    /// Bundles the entry points of a site
+    ///
+    /// # Arguments
+    /// * `bundler` - The JavaScript bundler.
+    /// * `assets_dirs` - The assets directories, the first directories take precedence.
+    /// * `entries` - The entry points relative to the assets directories, e.g. `js/main.ts`.
+    pub fn build(bundler: &JsBundler, assets_dirs: &[&Path], entries: &[String]) -> Result<Self> {
+        let mut bundles = Self::default();
+        for entry in entries {
+            let Some(path) = find_entry(assets_dirs, Path::new(entry)) else {
+                bail!(
+                    "{} '{}': not found in the assets directories",
+                    "Missing asset entry".bold(),
+                    entry
+                );
+            };
+            let bundle = bundler.bundle(&path)?;
+            bundles.modules.extend(
+                bundle
+                    .modules
+                    .iter()
+                    .filter_map(|module| std::fs::canonicalize(module).ok()),
+            );
+            bundles
+                .bundles
+                .insert(output_path(Path::new(entry)), bundle);
+        }
+        Ok(bundles)
+    }
+
+    /// Iterates over the bundles and their output path, relative to the assets directory
+    pub fn iter(&self) -> impl Iterator<Item = (&Path, &JsBundle)> {
+        self.bundles
+            .iter()
+            .map(|(output, bundle)| (output.as_path(), bundle))
+    }
+
+    /// Returns the bundle written to an output path, relative to the assets directory
+    pub fn get(&self, output: &Path) -> Option<&JsBundle> {
+        self.bundles.get(output)
+    }
+
+    /// Whether an asset is bundled into an entry point, or is a TypeScript file, so it must not
+    /// be published on its own
+    pub fn is_bundled(&self, path: &Path) -> bool {
+        is_typescript(path)
+            || std::fs::canonicalize(path).is_ok_and(|path| self.modules.contains(&path))
+    }
+}
+
+/// Returns the output path of an entry point, e.g. `js/main.js` for `js/main.ts`
+pub fn output_path(entry: &Path) -> PathBuf {
+    entry.with_extension("js")
+}
+
+/// Finds the entry point of an output path in the site entry points
+///
+/// # Arguments
+/// * `assets_dirs` - The assets directories, the first directories take precedence.
+/// * `entries` - The entry points relative to the assets directories.
+/// * `output` - The output path relative to the assets directory, e.g. `js/main.js`.
+pub fn find_output_entry(
+    assets_dirs: &[&Path],
+    entries: &[String],
+    output: &Path,
+) -> Option<PathBuf> {
+    entries
+        .iter()
+        .map(Path::new)
+        .find(|entry| output_path(entry) == output)
+        .and_then(|entry| find_entry(assets_dirs, entry))
+}
+
+fn find_entry(assets_dirs: &[&Path], entry: &Path) -> Option<PathBuf> {
+    assets_dirs
+        .iter()
+        .map(|dir| dir.join(entry))
+        .find(|path| path.is_file())
+}
+
+/// Whether a file is a TypeScript module
+pub fn is_typescript(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| matches!(ext, "ts" | "mts" | "tsx"))
+}
+
+/// Loads the modules of a bundle, stripping TypeScript types
+struct Loader {
+    cm: Lrc<SourceMap>,
+    /// Paths of the loaded modules
+    loaded: Mutex<Vec<PathBuf>>,
+}
+
+impl Load for &Loader {
+    fn load(&self, file: &FileName) -> anyhow::Result<ModuleData> {
+        let FileName::Real(path) = file else {
+            anyhow::bail!("unsupported module {}", file);
+        };
+        let fm = self.cm.load_file(path)?;
+        let (fm, module) = if is_typescript(path) {
+            let module = parse(&fm, path)?;
+            // The stripped module is parsed again, so the bundler gets a plain JavaScript module
+            let js = emit(self.cm.clone(), &strip_types(module), false)?;
+            let fm = self
+                .cm
+                .new_source_file(Lrc::new(FileName::Real(path.clone())), js);
+            let module = parse(&fm, path)?;
+            (fm, module)
+        } else {
+            let module = parse(&fm, path)?;
+            (fm, module)
+        };
+
+        if let Ok(mut loaded) = self.loaded.lock() {
+            loaded.push(path.clone());
+        }
+        Ok(ModuleData {
+            fm,
+            module,
+            helpers: Default::default(),
+        })
+    }
+}
+
+/// Provides `import.meta` properties, none are supported in bundles
+struct Hook;
+
+impl swc_core::bundler::Hook for Hook {
+    fn get_import_meta_props(
+        &self,
+        _span: Span,
+        _module_record: &ModuleRecord,
+    ) -> anyhow::Result<Vec<KeyValueProp>> {
+        Ok(Vec::new())
+    }
+}
+
+fn parse(fm: &SourceFile, path: &Path) -> anyhow::Result<Module> {
+    let syntax = if is_typescript(path) {
+        Syntax::Typescript(TsSyntax {
+            tsx: path.extension().is_some_and(|ext| ext == "tsx"),
+            ..Default::default()
+        })
+    } else {
+        Syntax::Es(EsSyntax {
+            jsx: path.extension().is_some_and(|ext| ext == "jsx"),
+            ..Default::default()
+        })
+    };
+    parse_file_as_module(fm, syntax, EsVersion::latest(), None, &mut Vec::new()).map_err(|e| {
+        anyhow::anyhow!(
+            "{}:{}: {}",
+            path.display(),
+            fm.lookup_line(e.span().lo).map_or(0, |line| line + 1),
+            e.kind().msg()
+        )
+    })
+}
+
+fn strip_types(module: Module) -> Module {
+    let unresolved_mark = Mark::new();
+    let top_level_mark = Mark::new();
+    let mut program = Program::Module(module);
+    program.mutate(resolver(unresolved_mark, top_level_mark, true));
+    program.mutate(typescript::strip(unresolved_mark, top_level_mark));
+    program.expect_module()
+}
+
+/// Minifies a bundle, removing the code unused by its top-level scope
+fn minify(cm: Lrc<SourceMap>, module: Module) -> Module {
+    let unresolved_mark = Mark::new();
+    let top_level_mark = Mark::new();
+    let mut program = Program::Module(module);
+    program.mutate(resolver(unresolved_mark, top_level_mark, false));
+    let mut program = swc_core::ecma::minifier::optimize(
+        program,
+        cm,
+        None,
+        None,
+        &MinifyOptions {
+            compress: Some(CompressOptions {
+                top_level: Some(TopLevelOptions { functions: true }),
+                ..Default::default()
+            }),
+            mangle: Some(MangleOptions {
+                top_level: Some(true),
+                ..Default::default()
+            }),
+            ..Default::default()
+        },
+        &ExtraOptions {
+            unresolved_mark,
+            top_level_mark,
+            mangle_name_cache: None,
+        },
+    );
+    program.mutate(fixer(None));
+    program.expect_module()
+}
+
+fn emit(cm: Lrc<SourceMap>, module: &Module, minify: bool) -> anyhow::Result<String> {
+    let mut code = Vec::new();
+    {
+        let writer = JsWriter::new(cm.clone(), "\n", &mut code, None);
+        let wr: Box<dyn WriteJs> = if minify {
+            Box::new(omit_trailing_semi(writer))
+        } else {
+            Box::new(writer)
+        };
+        let mut emitter = Emitter {
+            cfg: codegen::Config::default()
+                .with_minify(minify)
+                .with_target(EsVersion::Es2020),
+            cm,
+            comments: None,
+            wr,
+        };
+        emitter.emit_module(module)?;
+    }
+    Ok(String::from_utf8(code)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bundle_entries() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join("js")).unwrap();
+        std::fs::write(
+            dir.path().join("js/util.ts"),
+            "export function greet(name: string): string { return `Hello ${name}`; }\nexport function unused(): void {}",
+        )
+        .unwrap();
+        std::fs::write(
+            dir.path().join("js/main.ts"),
+            "import { greet } from './util';\ninterface Page { title: string }\nconst page: Page = { title: 'Home' };\nconsole.log(greet(page.title));",
+        )
+        .unwrap();
+        std::fs::write(dir.path().join("js/plain.js"), "console.log(1);").unwrap();
+
+        let bundles = JsBundles::build(
+            &JsBundler::new(false),
+            &[dir.path()],
+            &["js/main.ts".to_string()],
+        )
+        .unwrap();
+        let bundle = bundles.get(Path::new("js/main.js")).unwrap();
+        assert!(bundle.code.contains("Hello"));
+        assert!(!bundle.code.contains("unused"));
+        assert!(!bundle.code.contains("interface"));
+        assert_eq!(bundle.modules.len(), 2);
+
+        assert!(bundles.is_bundled(&dir.path().join("js/util.ts")));
+        assert!(!bundles.is_bundled(&dir.path().join("js/plain.js")));
+        assert_eq!(
+            find_output_entry(
+                &[dir.path()],
+                &["js/main.ts".to_string()],
+                Path::new("js/main.js")
+            ),
+            Some(dir.path().join("js/main.ts"))
+        );
+    }
+}
@@ -0,0 +1,5 @@
+//! Processing of the site assets at build time.
+
+pub mod css;
+pub mod fingerprint;
+pub mod js;
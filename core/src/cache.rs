@@ -1,48 +1,159 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::path::{Path, PathBuf};
 
 use colored::Colorize;
 use eyre::{eyre, Result};
 use serde::{Deserialize, Serialize};
+use tera::Tera;
 use tracing::{debug, warn};
 
-/// Cached metadata entry with content hash for invalidation.
+use crate::converter::diagnostics::Diagnostic;
+
+/// Version of the cache entries format, bump it when [`CacheEntry`] changes.
+///
+/// It is part of the global hash along with the norgolith version, so upgrading norgolith (which
+/// may convert documents differently) or changing the format discards the whole cache.
+const CACHE_FORMAT_VERSION: u32 = 2;
+
+/// Cached conversion of a document, with content hash for invalidation.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct CacheEntry {
     content_hash: String,
+    /// Document metadata, without the converted HTML and the TOC
     metadata: serde_json::Value,
+    /// Converted HTML body, the `raw` metadata field
+    html: String,
+    /// Table of contents, the `toc` metadata field
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    toc: Option<serde_json::Value>,
+    /// Conversion diagnostics, kept so cached pages are still reported
+    #[serde(default)]
+    diagnostics: Vec<Diagnostic>,
 }
 
-/// Returns the XDG cache directory for a site: `~/.cache/norgolith/{site_name}/`
-fn cache_dir_for_site(site_root: &Path) -> Result<PathBuf> {
+/// Name of the directory that holds the cache inside a site, used instead of the user cache
+/// directory when it exists
+pub const PROJECT_CACHE_DIR: &str = ".lith-cache";
+
+/// Returns the cache directory of a site.
+///
+/// The `.lith-cache/` directory of the site is used when it exists. Otherwise the cache lives
+/// in `~/.cache/norgolith/{site_name}-{path_hash}/` (XDG_CACHE_HOME), keyed by the canonical
+/// site path so two checkouts with the same directory name do not share their cache.
+pub fn cache_dir_for_site(site_root: &Path) -> Result<PathBuf> {
+    let project_cache = site_root.join(PROJECT_CACHE_DIR);
+    if project_cache.is_dir() {
+        return Ok(project_cache);
+    }
+
     let cache_base = dirs::cache_dir()
         .ok_or_else(|| eyre!("{}: cannot determine cache directory", "Failed".bold()))?;
+    let site_root = site_root
+        .canonicalize()
+        .unwrap_or_else(|_| site_root.to_path_buf());
     let site_name = site_root
         .file_name()
         .and_then(|n| n.to_str())
         .unwrap_or("unknown");
-    Ok(cache_base.join("norgolith").join(site_name))
+    let path_hash = blake3_hash(&site_root.to_string_lossy());
+    Ok(cache_base
+        .join("norgolith")
+        .join(format!("{}-{}", site_name, &path_hash[..16])))
+}
+
+/// Name of the directory holding processed image variants, inside the site cache directory
+const IMAGES_DIR: &str = "images";
+
+/// Name of the file recording the outputs of the last build, inside the site cache directory
+const OUTPUTS_FILE: &str = ".outputs";
+
+/// Name of the file holding the statistics of the last build, inside the site cache directory
+const LAST_BUILD_FILE: &str = ".last_build";
+
+/// Statistics of the last build that used the cache
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BuildStats {
+    /// Number of content pages
+    pub pages: usize,
+    /// Number of content pages that had to be converted
+    pub converted: usize,
+}
+
+impl BuildStats {
+    /// Ratio of pages that did not have to be converted, `None` when there were no pages
+    pub fn hit_ratio(&self) -> Option<f64> {
+        (self.pages > 0)
+            .then(|| self.pages.saturating_sub(self.converted) as f64 / self.pages as f64)
+    }
+}
+
+/// Inputs a rendered page depends on, compared between builds to skip unchanged pages.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PageInputs {
+    /// Hash of the page source and the site-wide conversion inputs
+    pub source: String,
+    /// Hash of the content of the local images used by the page
+    #[serde(default)]
+    pub images: String,
+    /// Hash of the link targets of the page, see `LinkIndex::is_current`
+    #[serde(default)]
+    pub links: String,
+    /// Hash of the page layout and the templates it extends, includes or imports
+    pub templates: String,
+    /// Hash of the posts list, available to every page
+    pub posts: String,
+    /// Hash of the norgolith version, the site configuration, the build options and the
+    /// fingerprinted asset names, `[extra]` values are only part of it when the page templates
+    /// use them
+    pub config: String,
+}
+
+/// A file written to the public directory by the last build
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct OutputRecord {
+    /// Inputs of the output, only recorded for content pages
+    #[serde(default)]
+    inputs: Option<PageInputs>,
+}
+
+/// Returns the directory where processed image variants of a site are cached.
+///
+/// Variants are keyed by the content hash of their source image, so they are kept when the
+/// rest of the cache is cleared.
+pub fn images_dir(site_root: &Path) -> Result<PathBuf> {
+    Ok(cache_dir_for_site(site_root)?.join(IMAGES_DIR))
 }
 
 /// Build cache for incremental builds.
 ///
 /// Stores parsed metadata keyed by relative file path. Entries are invalidated when:
 /// - File content changes (blake3 hash comparison)
-/// - Templates, config, or theme change (global hash stored in `.global_hash` file)
+/// - Macros or config (except `[extra]` values) change (global hash stored in `.global_hash` file)
+/// - Site inputs that affect the conversion change (e.g. the macros, see
+///   [`BuildCache::set_site_inputs`])
+/// - Link targets or local images used by the page change (see `shared::cached_conversion`)
+///
+/// It also records the outputs of the last build with the inputs of each page, so unchanged
+/// pages are not rendered again and outputs that are not produced anymore can be removed.
 #[derive(Debug)]
 pub struct BuildCache {
     cache_dir: PathBuf,
     entries: HashMap<PathBuf, CacheEntry>,
     global_hash: String,
+    site_inputs: String,
+    /// Outputs of the last build, relative to the public directory
+    outputs: BTreeMap<PathBuf, OutputRecord>,
+    /// Number of entries converted by this build
+    converted: usize,
 }
 
 impl BuildCache {
     /// Creates or loads a build cache.
     ///
     /// `site_root` is the directory containing `norgolith.toml`.
-    /// Cache is stored in `~/.cache/norgolith/{site_name}/` (XDG_CACHE_HOME).
-    /// If the global state (templates + config + theme) changed since last build,
-    /// the entire cache is cleared.
+    /// Cache is stored in the directory returned by [`cache_dir_for_site`].
+    /// If the global state (macros + config) changed since last build, the entire cache is
+    /// cleared. Templates are not part of it, they are tracked per page with [`RenderInputs`].
     pub fn open(site_root: &Path) -> Result<Self> {
         let cache_dir = cache_dir_for_site(site_root)?;
 
@@ -61,40 +172,154 @@ impl BuildCache {
         if stored_global.as_deref() != Some(global_hash.as_str()) {
             debug!("Global state changed (or first build), clearing cache");
             entries.clear();
-            let _ = std::fs::remove_dir_all(&cache_dir);
+            clear_cache_dir(&cache_dir);
         }
 
-        Ok(Self { cache_dir, entries, global_hash })
+        // Outputs are kept across global changes, each page records its own inputs
+        let outputs = read_outputs(&cache_dir);
+
+        Ok(Self {
+            cache_dir,
+            entries,
+            global_hash,
+            site_inputs: String::new(),
+            outputs,
+            converted: 0,
+        })
     }
 
-    /// Looks up cached metadata for a file.
+    /// Sets the fingerprint of the site-wide conversion inputs.
     ///
-    /// Returns `Some(metadata)` if the cache hit (content unchanged).
+    /// The fingerprint is mixed into every entry hash, so entries converted against different
+    /// inputs (e.g. before a linked page was renamed) are treated as misses.
+    pub fn set_site_inputs(&mut self, fingerprint: String) {
+        self.site_inputs = fingerprint;
+    }
+
+    /// Looks up the cached conversion of a file.
+    ///
+    /// Returns `Some(metadata)` if the cache hit (content unchanged), including the converted
+    /// HTML (`raw`) and the TOC, so the document does not have to be converted again.
     /// Returns `None` on miss (file changed or never cached).
     pub fn get(&self, rel_path: &Path, content: &str) -> Option<serde_json::Value> {
         let entry = self.entries.get(rel_path)?;
-        let hash = blake3_hash(content);
+        let hash = self.entry_hash(content);
         if entry.content_hash == hash {
             debug!(path = %rel_path.display(), "cache hit");
-            Some(entry.metadata.clone())
+            let mut metadata = entry.metadata.clone();
+            if let Some(table) = metadata.as_object_mut() {
+                table.insert("raw".into(), entry.html.clone().into());
+                if let Some(toc) = &entry.toc {
+                    table.insert("toc".into(), toc.clone());
+                }
+            }
+            Some(metadata)
         } else {
             debug!(path = %rel_path.display(), "cache miss (content changed)");
             None
         }
     }
 
-    /// Stores metadata in the cache.
-    pub fn insert(&mut self, rel_path: &Path, content: &str, metadata: serde_json::Value) {
-        let hash = blake3_hash(content);
+    /// Returns the conversion diagnostics stored for a file, if any.
+    pub fn diagnostics(&self, rel_path: &Path) -> Vec<Diagnostic> {
+        self.entries
+            .get(rel_path)
+            .map(|entry| entry.diagnostics.clone())
+            .unwrap_or_default()
+    }
+
+    /// Stores the conversion of a file and its diagnostics in the cache.
+    ///
+    /// The converted HTML (`raw`) and the TOC are taken out of `metadata` and stored on their
+    /// own.
+    pub fn insert(
+        &mut self,
+        rel_path: &Path,
+        content: &str,
+        mut metadata: serde_json::Value,
+        diagnostics: Vec<Diagnostic>,
+    ) {
+        let hash = self.entry_hash(content);
+        let (html, toc) = match metadata.as_object_mut() {
+            Some(table) => (
+                table
+                    .remove("raw")
+                    .and_then(|raw| raw.as_str().map(String::from))
+                    .unwrap_or_default(),
+                table.remove("toc"),
+            ),
+            None => (String::new(), None),
+        };
+        self.converted += 1;
         self.entries.insert(
             rel_path.to_path_buf(),
             CacheEntry {
                 content_hash: hash,
                 metadata,
+                html,
+                toc,
+                diagnostics,
             },
         );
     }
 
+    /// Returns the hash of a page source combined with the site inputs.
+    pub fn source_hash(&self, content: &str) -> String {
+        self.entry_hash(content)
+    }
+
+    /// Whether the last build produced the given output from the same inputs.
+    pub fn is_output_fresh(&self, output: &Path, inputs: &PageInputs) -> bool {
+        self.outputs
+            .get(output)
+            .and_then(|record| record.inputs.as_ref())
+            .is_some_and(|recorded| recorded == inputs)
+    }
+
+    /// Records the outputs of the current build.
+    ///
+    /// # Arguments
+    /// * `outputs` - Every output written (or kept) by the build, relative to the public
+    ///   directory, along with the inputs of content pages.
+    ///
+    /// # Returns
+    /// The outputs of the previous build that were not produced again.
+    pub fn record_outputs(
+        &mut self,
+        outputs: BTreeMap<PathBuf, Option<PageInputs>>,
+    ) -> Vec<PathBuf> {
+        let outputs: BTreeMap<_, _> = outputs
+            .into_iter()
+            .map(|(path, inputs)| (path, OutputRecord { inputs }))
+            .collect();
+        let previous = std::mem::replace(&mut self.outputs, outputs);
+        previous
+            .into_keys()
+            .filter(|path| !self.outputs.contains_key(path))
+            .collect()
+    }
+
+    /// Returns the statistics of the current build, once its outputs are recorded.
+    pub fn stats(&self) -> BuildStats {
+        BuildStats {
+            pages: self
+                .outputs
+                .values()
+                .filter(|record| record.inputs.is_some())
+                .count(),
+            converted: self.converted,
+        }
+    }
+
+    /// Computes the hash stored in an entry, combining its content and the site inputs.
+    fn entry_hash(&self, content: &str) -> String {
+        if self.site_inputs.is_empty() {
+            blake3_hash(content)
+        } else {
+            blake3_hash(&format!("{}\0{}", self.site_inputs, content))
+        }
+    }
+
     /// Saves cache entries and global hash to disk.
     pub fn save(&self) -> Result<()> {
         if !self.cache_dir.exists() {
@@ -109,6 +334,25 @@ impl BuildCache {
             eyre!("{}: {}", "Failed to write global hash".bold(), e)
         })?;
 
+        // Write build outputs
+        let outputs_path = self.cache_dir.join(OUTPUTS_FILE);
+        let json = serde_json::to_string(&self.outputs).map_err(|e| {
+            eyre!("{}: {}", "Failed to serialize build outputs".bold(), e)
+        })?;
+        std::fs::write(&outputs_path, json).map_err(|e| {
+            eyre!("{}: {}", "Failed to write build outputs".bold(), e)
+        })?;
+
+        // Write the statistics of this build
+        let stats = self.stats();
+        let stats_path = self.cache_dir.join(LAST_BUILD_FILE);
+        let json = serde_json::to_string(&stats).map_err(|e| {
+            eyre!("{}: {}", "Failed to serialize build statistics".bold(), e)
+        })?;
+        std::fs::write(&stats_path, json).map_err(|e| {
+            eyre!("{}: {}", "Failed to write build statistics".bold(), e)
+        })?;
+
         // Write each entry
         for (rel_path, entry) in &self.entries {
             let cache_path = self.cache_dir.join(rel_path).with_extension("json");
@@ -131,39 +375,366 @@ impl BuildCache {
     }
 }
 
+/// Removes every cached entry of a site, keeping the processed images and the build outputs.
+fn clear_cache_dir(cache_dir: &Path) {
+    let Ok(entries) = std::fs::read_dir(cache_dir) else {
+        return;
+    };
+    for entry in entries.filter_map(|e| e.ok()) {
+        let path = entry.path();
+        if path.is_dir() {
+            if entry.file_name() != IMAGES_DIR {
+                let _ = std::fs::remove_dir_all(&path);
+            }
+        } else if entry.file_name() != OUTPUTS_FILE {
+            let _ = std::fs::remove_file(&path);
+        }
+    }
+}
+
+/// Reads the outputs recorded by the last build.
+fn read_outputs(cache_dir: &Path) -> BTreeMap<PathBuf, OutputRecord> {
+    std::fs::read_to_string(cache_dir.join(OUTPUTS_FILE))
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
 /// Computes a blake3 hash of the file content.
 fn blake3_hash(content: &str) -> String {
     blake3::hash(content.as_bytes()).to_hex().to_string()
 }
 
+/// Hashes the `hash` field of every item of a metadata array, e.g. the `images` of a page.
+///
+/// Returns an empty string when the array is missing or empty.
+fn dependencies_hash(metadata: &toml::Value, field: &str) -> String {
+    let hashes: Vec<_> = metadata
+        .get(field)
+        .and_then(|items| items.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|item| item.get("hash").and_then(|hash| hash.as_str()))
+        .collect();
+    if hashes.is_empty() {
+        String::new()
+    } else {
+        blake3_hash(&hashes.join("\0"))
+    }
+}
+
 /// Reads the stored global hash from cache.
 fn read_global_hash(cache_dir: &Path) -> Option<String> {
     let path = cache_dir.join(".global_hash");
     std::fs::read_to_string(path).ok().map(|s| s.trim().to_string())
 }
 
-/// Computes a global hash from templates, config, and theme directories.
+/// Inputs of the rendered pages shared by the whole site.
+///
+/// Combined with the layout of a page into its [`PageInputs`], so a template change only
+/// affects the pages rendered with it.
+#[derive(Debug)]
+pub struct RenderInputs {
+    templates: TemplateDeps,
+    posts: String,
+    config: String,
+    config_extra: String,
+}
+
+impl RenderInputs {
+    /// Creates the render inputs of a build
+    ///
+    /// # Arguments
+    /// * `templates` - The dependencies of the site templates.
+    /// * `posts` - The posts list, available to every page.
+    /// * `config_content` - The content of `norgolith.toml`.
+    /// * `minify` - Whether the build minifies its output.
+    /// * `assets` - Hash of the fingerprinted asset names, pages link to them. Empty when assets
+    ///   are not fingerprinted.
+    pub fn new(
+        templates: TemplateDeps,
+        posts: &[toml::Value],
+        config_content: &str,
+        minify: bool,
+        assets: &str,
+    ) -> Result<Self> {
+        let posts = serde_json::to_string(posts)
+            .map_err(|e| eyre!("{}: {}", "Failed to serialize posts".bold(), e))?;
+        let (config, config_extra) = split_config(config_content)?;
+        Ok(Self {
+            templates,
+            posts: blake3_hash(&posts),
+            // The norgolith version and the cache format are part of it, so pages rendered by
+            // another norgolith version are rendered again
+            config: blake3_hash(&format!(
+                "{}:{}\0{}\0minify={}\0assets={}",
+                env!("CARGO_PKG_VERSION"),
+                CACHE_FORMAT_VERSION,
+                config,
+                minify,
+                assets
+            )),
+            config_extra: blake3_hash(&config_extra),
+        })
+    }
+
+    /// Returns the inputs of a page
+    ///
+    /// # Arguments
+    /// * `source` - The source hash of the page, see [`BuildCache::source_hash`].
+    /// * `metadata` - The converted page metadata, its `images` and `links` hold the hashes of
+    ///   the images and link targets used by the page.
+    /// * `template` - The template the page is rendered with, e.g. `post.html`.
+    pub fn page_inputs(
+        &self,
+        source: String,
+        metadata: &toml::Value,
+        template: &str,
+    ) -> PageInputs {
+        let template = self.templates.get(template);
+        let config = if template.uses_config_extra {
+            blake3_hash(&format!("{}\0{}", self.config, self.config_extra))
+        } else {
+            self.config.clone()
+        };
+        PageInputs {
+            source,
+            images: dependencies_hash(metadata, "images"),
+            links: dependencies_hash(metadata, "links"),
+            templates: template.hash,
+            posts: self.posts.clone(),
+            config,
+        }
+    }
+}
+
+/// Hash of a template and its dependencies
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TemplateInputs {
+    /// Hash of the template and every template it extends, includes or imports
+    pub hash: String,
+    /// Whether any of these templates reads `config.extra`
+    pub uses_config_extra: bool,
+}
+
+/// A single template, without its dependencies
+#[derive(Debug)]
+struct TemplateNode {
+    hash: String,
+    uses_config_extra: bool,
+    /// Templates it extends, includes or imports macros from
+    dependencies: Vec<String>,
+}
+
+/// Dependencies between the site templates.
+///
+/// Parents come from the Tera inheritance chains, the other dependencies from the `include`
+/// and `import` tags of each template.
+#[derive(Debug, Default)]
+pub struct TemplateDeps {
+    inputs: HashMap<String, TemplateInputs>,
+}
+
+impl TemplateDeps {
+    /// Computes the dependencies of every template loaded in `tera`
+    pub fn new(tera: &Tera) -> Self {
+        let mut nodes = HashMap::new();
+        for name in tera.get_template_names() {
+            let Ok(template) = tera.get_template(name) else {
+                continue;
+            };
+            let mut dependencies = template.parents.clone();
+            dependencies.extend(
+                template
+                    .imported_macro_files
+                    .iter()
+                    .map(|(file, _)| file.clone()),
+            );
+
+            let source = template
+                .path
+                .as_ref()
+                .and_then(|path| std::fs::read_to_string(path).ok());
+            let node = match source {
+                Some(source) => {
+                    let tags = template_tags(&source);
+                    dependencies.extend(tags.iter().flat_map(|tag| included_templates(tag)));
+                    TemplateNode {
+                        hash: blake3_hash(&source),
+                        uses_config_extra: tags.iter().any(|tag| references_config_extra(tag)),
+                        dependencies,
+                    }
+                }
+                // Built-in templates (e.g. the default error pages) are part of the binary
+                None => TemplateNode {
+                    hash: blake3_hash(&format!("{}@{}", name, env!("CARGO_PKG_VERSION"))),
+                    uses_config_extra: false,
+                    dependencies,
+                },
+            };
+            nodes.insert(name.to_string(), node);
+        }
+
+        let inputs = nodes
+            .keys()
+            .map(|name| (name.clone(), template_inputs(&nodes, name)))
+            .collect();
+        Self { inputs }
+    }
+
+    /// Returns the inputs of a template, a missing template only hashes its name
+    pub fn get(&self, name: &str) -> TemplateInputs {
+        self.inputs
+            .get(name)
+            .cloned()
+            .unwrap_or_else(|| TemplateInputs {
+                hash: blake3_hash(&format!("{}\0missing", name)),
+                uses_config_extra: false,
+            })
+    }
+}
+
+/// Combines a template with everything it depends on, directly or not
+fn template_inputs(nodes: &HashMap<String, TemplateNode>, name: &str) -> TemplateInputs {
+    let mut visited = BTreeSet::new();
+    let mut stack = vec![name];
+    while let Some(name) = stack.pop() {
+        if !visited.insert(name) {
+            continue;
+        }
+        if let Some(node) = nodes.get(name) {
+            stack.extend(node.dependencies.iter().map(String::as_str));
+        }
+    }
+
+    let mut hasher = blake3::Hasher::new();
+    let mut uses_config_extra = false;
+    for name in visited {
+        hasher.update(name.as_bytes());
+        match nodes.get(name) {
+            Some(node) => {
+                hasher.update(node.hash.as_bytes());
+                uses_config_extra |= node.uses_config_extra;
+            }
+            // Templates included with `ignore missing` change the output once they exist
+            None => {
+                hasher.update(b"\0missing");
+            }
+        }
+    }
+    TemplateInputs {
+        hash: hasher.finalize().to_hex().to_string(),
+        uses_config_extra,
+    }
+}
+
+/// Returns the contents of the `{{ ... }}` and `{% ... %}` tags of a template
+fn template_tags(source: &str) -> Vec<&str> {
+    let mut tags = Vec::new();
+    let mut rest = source;
+    while let Some(start) = rest.find('{') {
+        let close = match rest[start + 1..].chars().next() {
+            Some('{') => "}}",
+            Some('%') => "%}",
+            _ => {
+                rest = &rest[start + 1..];
+                continue;
+            }
+        };
+        let inner = &rest[start + 2..];
+        let Some(end) = inner.find(close) else {
+            break;
+        };
+        tags.push(&inner[..end]);
+        rest = &inner[end + 2..];
+    }
+    tags
+}
+
+/// Returns the templates named by an `include` tag, e.g. `include ["a.html", "b.html"]`
+fn included_templates(tag: &str) -> Vec<String> {
+    let tag = tag.trim_matches('-').trim();
+    let Some(args) = tag.strip_prefix("include") else {
+        return Vec::new();
+    };
+    if !args.starts_with(|c: char| c.is_whitespace() || c == '[') {
+        return Vec::new();
+    }
+
+    let mut names = Vec::new();
+    let mut rest = args;
+    while let Some(start) = rest.find(['"', '\'', '`']) {
+        let quote = &rest[start..start + 1];
+        let value = &rest[start + 1..];
+        let Some(end) = value.find(quote) else {
+            break;
+        };
+        names.push(value[..end].to_string());
+        rest = &value[end + 1..];
+    }
+    names
+}
+
+/// Whether a template tag may read `config.extra`.
+///
+/// Other `config` fields do not count, but any other use of `config` (e.g. `config[key]` or
+/// `config | json_encode`) does, as it could reach the extra values.
+fn references_config_extra(tag: &str) -> bool {
+    let is_ident = |c: char| c.is_alphanumeric() || c == '_';
+    tag.match_indices("config").any(|(i, name)| {
+        if tag[..i].ends_with(|c: char| is_ident(c) || c == '.') {
+            return false;
+        }
+        let rest = &tag[i + name.len()..];
+        match rest.chars().next() {
+            Some(c) if is_ident(c) => false,
+            Some('.') => {
+                let field = &rest[1..];
+                let end = field.find(|c: char| !is_ident(c)).unwrap_or(field.len());
+                &field[..end] == "extra"
+            }
+            _ => true,
+        }
+    })
+}
+
+/// Splits the site configuration into its `[extra]` table and everything else.
+///
+/// # Returns
+/// The serialized configuration without `[extra]`, and the serialized `[extra]` table.
+fn split_config(config_content: &str) -> Result<(String, String)> {
+    let mut config: toml::Table = toml::from_str(config_content)
+        .map_err(|e| eyre!("{}: {}", "Failed to parse site configuration".bold(), e))?;
+    let extra = config.remove("extra");
+    let config = serde_json::to_string(&config)
+        .map_err(|e| eyre!("{}: {}", "Failed to serialize site configuration".bold(), e))?;
+    let extra = serde_json::to_string(&extra)
+        .map_err(|e| eyre!("{}: {}", "Failed to serialize site configuration".bold(), e))?;
+    Ok((config, extra))
+}
+
+/// Computes a global hash from the cache format, the macros and the config (without `[extra]`).
 fn compute_global_hash(site_root: &Path) -> Result<String> {
     let mut hasher = blake3::Hasher::new();
 
-    // Hash config file
+    // Hash the cache format and the norgolith version
+    hasher.update(format!("{}:{}", env!("CARGO_PKG_VERSION"), CACHE_FORMAT_VERSION).as_bytes());
+
+    // Hash config file, extra values are only used by templates
     let config_path = site_root.join("norgolith.toml");
     if config_path.exists() {
-        if let Ok(content) = std::fs::read(&config_path) {
-            hasher.update(&content);
+        if let Ok(content) = std::fs::read_to_string(&config_path) {
+            match split_config(&content) {
+                Ok((config, _)) => hasher.update(config.as_bytes()),
+                Err(_) => hasher.update(content.as_bytes()),
+            };
         }
     }
 
-    // Hash templates directory
-    let templates_dir = site_root.join("templates");
-    if templates_dir.exists() {
-        hash_dir(&templates_dir, &mut hasher)?;
-    }
-
-    // Hash theme templates directory
-    let theme_templates_dir = site_root.join("theme").join("templates");
-    if theme_templates_dir.exists() {
-        hash_dir(&theme_templates_dir, &mut hasher)?;
+    // Hash macros directory
+    let macros_dir = site_root.join(crate::converter::macros::MACROS_DIR);
+    if macros_dir.exists() {
+        hash_dir(&macros_dir, &mut hasher)?;
     }
 
     Ok(hasher.finalize().to_hex().to_string())
@@ -210,6 +781,130 @@ fn load_entries(cache_dir: &Path) -> Result<HashMap<PathBuf, CacheEntry>> {
     Ok(entries)
 }
 
+/// Summary of the cache of a site, see [`info`]
+#[derive(Debug)]
+pub struct CacheInfo {
+    /// The cache directory
+    pub dir: PathBuf,
+    /// Whether the cache directory exists
+    pub exists: bool,
+    /// Total size of the cache files, in bytes
+    pub size: u64,
+    /// Number of cached pages
+    pub entries: usize,
+    /// Number of cached image variants
+    pub images: usize,
+    /// Statistics of the last build, if any
+    pub last_build: Option<BuildStats>,
+}
+
+/// Summarizes the cache of a site.
+pub fn info(site_root: &Path) -> Result<CacheInfo> {
+    let dir = cache_dir_for_site(site_root)?;
+    let exists = dir.exists();
+    let (size, entries, images) = if exists {
+        let size = walkdir::WalkDir::new(&dir)
+            .into_iter()
+            .filter_map(|e| e.ok())
+            .filter_map(|e| e.metadata().ok())
+            .filter(|metadata| metadata.is_file())
+            .map(|metadata| metadata.len())
+            .sum();
+        let images = walkdir::WalkDir::new(dir.join(IMAGES_DIR))
+            .into_iter()
+            .filter_map(|e| e.ok())
+            .filter(|e| e.file_type().is_file())
+            .count();
+        (size, load_entries(&dir)?.len(), images)
+    } else {
+        (0, 0, 0)
+    };
+    let last_build = std::fs::read_to_string(dir.join(LAST_BUILD_FILE))
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok());
+
+    Ok(CacheInfo {
+        dir,
+        exists,
+        size,
+        entries,
+        images,
+        last_build,
+    })
+}
+
+/// Removes the whole cache of a site, including processed images and the recorded outputs.
+///
+/// A `.lith-cache/` directory is emptied but kept, so the site keeps using it.
+///
+/// # Returns
+/// The removed cache directory, `None` when there was no cache.
+pub fn clear(site_root: &Path) -> Result<Option<PathBuf>> {
+    let dir = cache_dir_for_site(site_root)?;
+    if !dir.exists() {
+        return Ok(None);
+    }
+
+    let result = if dir.ends_with(PROJECT_CACHE_DIR) {
+        std::fs::read_dir(&dir).and_then(|entries| {
+            entries.filter_map(|e| e.ok()).try_for_each(|entry| {
+                if entry.path().is_dir() {
+                    std::fs::remove_dir_all(entry.path())
+                } else {
+                    std::fs::remove_file(entry.path())
+                }
+            })
+        })
+    } else {
+        std::fs::remove_dir_all(&dir)
+    };
+    result.map_err(|e| {
+        eyre!(
+            "{} {}: {}",
+            "Failed to remove cache directory".bold(),
+            dir.display(),
+            e
+        )
+    })?;
+    Ok(Some(dir))
+}
+
+/// Removes the cached entries of content files that do not exist anymore.
+///
+/// # Returns
+/// The number of removed entries.
+pub fn prune(site_root: &Path) -> Result<usize> {
+    let dir = cache_dir_for_site(site_root)?;
+    let content_dir = site_root.join("content");
+    let mut removed = 0;
+    for rel_path in load_entries(&dir)?.into_keys() {
+        if content_dir.join(&rel_path).with_extension("norg").exists() {
+            continue;
+        }
+        let cache_path = dir.join(&rel_path).with_extension("json");
+        std::fs::remove_file(&cache_path).map_err(|e| {
+            eyre!(
+                "{} {}: {}",
+                "Failed to remove cache entry".bold(),
+                cache_path.display(),
+                e
+            )
+        })?;
+        debug!(path = %rel_path.display(), "Pruned cache entry");
+        removed += 1;
+
+        // Remove the directories left empty, up to the cache directory
+        let mut parent = cache_path.parent();
+        while let Some(current) = parent.filter(|p| *p != dir.as_path()) {
+            if std::fs::remove_dir(current).is_err() {
+                break;
+            }
+            parent = current.parent();
+        }
+    }
+    Ok(removed)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -227,4 +922,178 @@ mod tests {
         let h2 = blake3_hash("world");
         assert_ne!(h1, h2);
     }
+
+    #[test]
+    fn test_record_outputs() {
+        let mut cache = BuildCache {
+            cache_dir: PathBuf::new(),
+            entries: HashMap::new(),
+            global_hash: String::new(),
+            site_inputs: String::new(),
+            outputs: BTreeMap::new(),
+            converted: 0,
+        };
+        let inputs = PageInputs {
+            source: cache.source_hash("* Hello"),
+            ..Default::default()
+        };
+        let page = PathBuf::from("about/index.html");
+        let removed = PathBuf::from("old/index.html");
+
+        let orphans = cache.record_outputs(BTreeMap::from([
+            (page.clone(), Some(inputs.clone())),
+            (removed.clone(), None),
+        ]));
+        assert!(orphans.is_empty());
+        assert!(cache.is_output_fresh(&page, &inputs));
+        let changed = PageInputs {
+            source: cache.source_hash("* Hello world"),
+            ..Default::default()
+        };
+        assert!(!cache.is_output_fresh(&page, &changed));
+
+        let orphans = cache.record_outputs(BTreeMap::from([(page.clone(), Some(changed))]));
+        assert_eq!(orphans, vec![removed]);
+    }
+
+    #[test]
+    fn test_dependencies_hash() {
+        let metadata: toml::Value = toml::from_str(
+            r#"images = [{ src = "/a.png", hash = "a" }, { src = "/b.png", hash = "b" }]"#,
+        )
+        .unwrap();
+        let replaced: toml::Value = toml::from_str(
+            r#"images = [{ src = "/a.png", hash = "c" }, { src = "/b.png", hash = "b" }]"#,
+        )
+        .unwrap();
+
+        let hash = dependencies_hash(&metadata, "images");
+        assert!(!hash.is_empty());
+        assert_ne!(hash, dependencies_hash(&replaced, "images"));
+        assert_eq!(dependencies_hash(&metadata, "links"), "");
+    }
+
+    #[test]
+    fn test_entry_html_and_toc() {
+        let mut cache = BuildCache {
+            cache_dir: PathBuf::new(),
+            entries: HashMap::new(),
+            global_hash: String::new(),
+            site_inputs: String::new(),
+            outputs: BTreeMap::new(),
+            converted: 0,
+        };
+        let metadata = serde_json::json!({
+            "title": "Hello",
+            "raw": "<h1>Hello</h1>",
+            "toc": [{ "level": 1, "title": "Hello" }],
+        });
+        cache.insert(Path::new("index"), "* Hello", metadata.clone(), Vec::new());
+
+        let entry = &cache.entries[Path::new("index")];
+        assert_eq!(entry.html, "<h1>Hello</h1>");
+        assert!(entry.metadata.get("raw").is_none());
+        assert_eq!(cache.get(Path::new("index"), "* Hello"), Some(metadata));
+        assert_eq!(cache.get(Path::new("index"), "* Hello world"), None);
+    }
+
+    #[test]
+    fn test_prune() {
+        let site = tempfile::tempdir().unwrap();
+        let cache_dir = site.path().join(PROJECT_CACHE_DIR);
+        std::fs::create_dir_all(cache_dir.join("posts")).unwrap();
+        std::fs::create_dir_all(site.path().join("content")).unwrap();
+        std::fs::write(site.path().join("content/index.norg"), "* Home").unwrap();
+        assert_eq!(cache_dir_for_site(site.path()).unwrap(), cache_dir);
+
+        let entry = serde_json::to_string(&CacheEntry {
+            content_hash: String::new(),
+            metadata: serde_json::Value::Null,
+            html: String::new(),
+            toc: None,
+            diagnostics: Vec::new(),
+        })
+        .unwrap();
+        std::fs::write(cache_dir.join("index.json"), &entry).unwrap();
+        std::fs::write(cache_dir.join("posts/removed.json"), &entry).unwrap();
+
+        assert_eq!(prune(site.path()).unwrap(), 1);
+        assert!(cache_dir.join("index.json").exists());
+        assert!(!cache_dir.join("posts").exists());
+        assert_eq!(info(site.path()).unwrap().entries, 1);
+
+        assert_eq!(clear(site.path()).unwrap(), Some(cache_dir.clone()));
+        assert!(cache_dir.exists());
+        assert_eq!(info(site.path()).unwrap().entries, 0);
+    }
+
+    #[test]
+    fn test_template_tags() {
+        let source = "<p>{{ config.title }}</p>{%- include \"nav.html\" -%}{# comment #}";
+        assert_eq!(
+            template_tags(source),
+            vec![" config.title ", "- include \"nav.html\" -"]
+        );
+        assert_eq!(
+            included_templates("- include [\"a.html\", 'b.html'] ignore missing -"),
+            vec!["a.html", "b.html"]
+        );
+        assert!(included_templates(" includes ").is_empty());
+    }
+
+    #[test]
+    fn test_references_config_extra() {
+        assert!(references_config_extra(" config.extra.author "));
+        assert!(references_config_extra(" config[\"extra\"] "));
+        assert!(references_config_extra(" config | json_encode "));
+        assert!(!references_config_extra(" config.title "));
+        assert!(!references_config_extra(" page.config.extra "));
+        assert!(!references_config_extra(" site_config "));
+    }
+
+    #[test]
+    fn test_template_deps() {
+        let dir = tempfile::tempdir().unwrap();
+        let write = |name: &str, content: &str| {
+            let path = dir.path().join(name);
+            std::fs::write(&path, content).unwrap();
+            (path, Some(name.to_string()))
+        };
+        let files = vec![
+            write("base.html", "<title>{{ config.title }}</title>{% block body %}{% endblock %}"),
+            write("nav.html", "<nav>{{ config.extra.menu }}</nav>"),
+            write(
+                "default.html",
+                "{% extends \"base.html\" %}{% block body %}{{ content }}{% endblock %}",
+            ),
+            write(
+                "post.html",
+                "{% extends \"base.html\" %}{% block body %}{% include \"nav.html\" %}{% endblock %}",
+            ),
+        ];
+        let mut tera = Tera::default();
+        tera.add_template_files(files).unwrap();
+
+        let deps = TemplateDeps::new(&tera);
+        let default = deps.get("default.html");
+        let post = deps.get("post.html");
+        assert!(!default.uses_config_extra);
+        assert!(post.uses_config_extra);
+        assert_ne!(default.hash, post.hash);
+
+        // Changing the included template only changes the pages that include it
+        write("nav.html", "<nav></nav>");
+        let mut tera = Tera::default();
+        tera.add_template_files(vec![
+            (dir.path().join("base.html"), Some("base.html")),
+            (dir.path().join("nav.html"), Some("nav.html")),
+            (dir.path().join("default.html"), Some("default.html")),
+            (dir.path().join("post.html"), Some("post.html")),
+        ])
+        .unwrap();
+        let changed = TemplateDeps::new(&tera);
+        assert_eq!(changed.get("default.html"), default);
+        assert_ne!(changed.get("post.html").hash, post.hash);
+        assert!(!changed.get("post.html").uses_config_extra);
+    }
 }
@@ -1,5 +1,5 @@
 use std::env::set_current_dir;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use clap::{builder::PossibleValue, Parser, Subcommand};
 use eyre::{bail, Result};
@@ -66,14 +66,22 @@ enum Commands {
         #[arg(long = "no-drafts")]
         _no_drafts: bool,
 
-        // TODO: add SocketAddr parsing if host is a String, similar to Vite
         #[arg(
             short = 'e',
+            long,
+            value_name = "ADDRESS",
+            num_args = 0..=1,
+            default_missing_value = "0.0.0.0",
+            help = "Expose site to LAN network, optionally on the given address (e.g. 192.168.1.10:4000 or [::]:4000)"
+        )]
+        host: Option<String>,
+
+        #[arg(
             long,
             default_value_t = false,
-            help = "Expose site to LAN network"
+            help = "Serve over HTTPS with a self-signed certificate"
         )]
-        host: bool,
+        https: bool,
 
         #[arg(
             short = 'o',
@@ -131,12 +139,69 @@ enum Commands {
 
         #[arg(long = "no-minify")]
         _no_minify: bool,
+
+        #[arg(
+            long,
+            default_value_t = false,
+            help = "Fail the build if any page has unsupported or malformed Norg syntax"
+        )]
+        strict: bool,
+
+        #[arg(
+            long,
+            default_value_t = false,
+            help = "Remove the previous build and render every page again"
+        )]
+        clean: bool,
+
+        #[arg(
+            long,
+            help = "Write a machine-readable build report",
+            value_parser = [
+                PossibleValue::new("json").help("JSON report, build-report.json by default")
+            ]
+        )]
+        report: Option<String>,
+
+        #[arg(
+            long = "report-file",
+            value_name = "PATH",
+            help = "Path of the build report, implies --report json"
+        )]
+        report_file: Option<PathBuf>,
+    },
+    /// List the files that changed between two builds
+    Diff {
+        /// Manifest of the previous build, e.g. a copy of 'public/.lith-manifest.json'
+        old: PathBuf,
+
+        #[arg(
+            long,
+            value_name = "PATH",
+            help = "Manifest of the new build (default: the one in the public directory)"
+        )]
+        new: Option<PathBuf>,
+    },
+    /// Check the internal links of a site without building it
+    CheckLinks {
+        #[arg(
+            short = 'd',
+            long,
+            default_value_t = false,
+            help = "Check draft content and allow linking to it"
+        )]
+        drafts: bool,
     },
     /// Plugin management
     Plugin {
         #[command(subcommand)]
         subcommand: cmd::PluginCommands,
     },
+    /// Build cache management
+    Cache {
+        #[command(subcommand)]
+        subcommand: cmd::CacheCommands,
+    },
     /// Preview from build result
     Preview {
         #[arg(short = 'p', long, default_value_t = 3030, help = "Port to be used")]
@@ -184,13 +249,28 @@ pub async fn start() -> Result<()> {
             drafts: _,
             _no_drafts,
             host,
+            https,
             open,
-        } => run_dev_server(port, !_no_drafts, open, host).await?,
+        } => run_dev_server(port, !_no_drafts, open, host.as_deref(), https).await?,
         Commands::Build {
             minify: _,
             _no_minify,
-        } => build_site(!_no_minify).await?,
+            strict,
+            clean,
+            report,
+            report_file,
+        } => {
+            // JSON is the only report format for now
+            let report = (report.is_some() || report_file.is_some()).then(|| cmd::ReportOptions {
+                format: cmd::ReportFormat::Json,
+                file: report_file,
+            });
+            build_site(!_no_minify, strict, clean, report).await?
+        }
+        Commands::Diff { old, new } => diff_builds(&old, new.as_deref())?,
+        Commands::CheckLinks { drafts } => check_links(drafts)?,
         Commands::Plugin { subcommand } => plugin_handle(&subcommand)?,
+        Commands::Cache { subcommand } => cache_handle(&subcommand)?,
         Commands::New {
             kind,
             name,
@@ -223,11 +303,43 @@ async fn init_site(name: String, prompt: bool) -> Result<()> {
 ///
 /// # Arguments:
 ///   * minify: Whether to minify the produced artifacts. Defaults to `true`.
+///   * strict: Whether conversion diagnostics should make the build fail. Defaults to `false`.
+///   * clean: Whether to discard the previous build instead of only updating the changed
+///     outputs. Defaults to `false`.
+///   * report: Where and how to write the machine-readable build report, if any.
+///
+/// # Returns:
+///   A `Result<()>` indicating success or error.
+async fn build_site(
+    minify: bool,
+    strict: bool,
+    clean: bool,
+    report: Option<cmd::ReportOptions>,
+) -> Result<()> {
+    cmd::build(minify, strict, clean, report)
+}
+
+/// Checks the internal links of a Norgolith site.
+///
+/// # Arguments:
+///   * drafts: Whether to check draft content. Defaults to `false`.
+///
+/// # Returns:
+///   A `Result<()>` indicating success or error. Broken links are reported as an error.
+fn check_links(drafts: bool) -> Result<()> {
+    cmd::check_links(drafts)
+}
+
+/// Lists the files that changed between two builds.
+///
+/// # Arguments:
+///   * old: The manifest of the previous build.
+///   * new: The manifest of the new build, the current site one if `None`.
 ///
 /// # Returns:
 ///   A `Result<()>` indicating success or error.
-async fn build_site(minify: bool) -> Result<()> {
-    cmd::build(minify)
+fn diff_builds(old: &Path, new: Option<&Path>) -> Result<()> {
+    cmd::diff(old, new)
 }
 
 async fn preview(port: u16, open: bool, host: bool) -> Result<()> {
@@ -240,14 +352,21 @@ async fn preview(port: u16, open: bool, host: bool) -> Result<()> {
 ///   * port: The port number to use for the server.
 ///   * drafts: Whether to serve draft content.
 ///   * open: Whether to open the development server in the system web browser.
-///   * host: Whether to expose local server to LAN network.
+///   * host: The address to bind to, e.g. `0.0.0.0` to expose local server to LAN network.
+///   * https: Whether to serve over HTTPS with a self-signed certificate.
 ///
 /// # Returns:
 ///   A `Result<()>` indicating success or error. On error, the context message
 ///   will provide information on why the development server could not be initialized.
-async fn run_dev_server(port: u16, drafts: bool, open: bool, host: bool) -> Result<()> {
-    let listener = net::bind_available(port, host)?;
-    cmd::dev(listener, port, drafts, open, host).await
+async fn run_dev_server(
+    port: u16,
+    drafts: bool,
+    open: bool,
+    host: Option<&str>,
+    https: bool,
+) -> Result<()> {
+    let listener = net::bind_available(net::bind_address(host, port)?)?;
+    cmd::dev(listener, drafts, open, https).await
 }
 
 async fn theme_handle(subcommand: &cmd::ThemeCommands) -> Result<()> {
@@ -258,6 +377,10 @@ fn plugin_handle(subcommand: &cmd::PluginCommands) -> Result<()> {
     cmd::plugin(subcommand)
 }
 
+fn cache_handle(subcommand: &cmd::CacheCommands) -> Result<()> {
+    cmd::cache(subcommand)
+}
+
 /// Creates a new asset with the given kind and name.
 ///
 /// # Arguments
@@ -344,7 +467,7 @@ mod tests {
 
         std::env::set_current_dir(path)?;
 
-        let result = run_dev_server(port, false, false, false).await;
+        let result = run_dev_server(port, false, false, None, false).await;
         assert!(result.is_err());
         assert!(result
             .unwrap_err()
@@ -1,12 +1,12 @@
 use std::{
+    collections::BTreeMap,
     path::{Path, PathBuf},
-    sync::{OnceLock},
-    time::Instant,
+    sync::{Arc, OnceLock},
+    time::{Duration, Instant},
 };
 
 use colored::{ColoredString, Colorize};
 use eyre::{bail, eyre, Result, WrapErr};
-use lightningcss::stylesheet::{MinifyOptions, ParserOptions, PrinterOptions, StyleSheet};
 use rss::Channel;
 use tera::{Context, Tera};
 use tracing::{debug, error, instrument, warn};
@@ -17,7 +17,24 @@ fn href_root_re() -> &'static regex::Regex {
     RE.get_or_init(|| regex::Regex::new(r#"href="(/|&#x2F;)"#).expect("valid regex"))
 }
 
-use crate::{cache::BuildCache, config, fs, plugin, shared};
+use crate::{
+    assets::{
+        css::{self, CssCompiler},
+        fingerprint::Fingerprints,
+        js::{JsBundler, JsBundles},
+    },
+    cache::{BuildCache, PageInputs, RenderInputs, TemplateDeps},
+    config,
+    converter::{
+        diagnostics::Diagnostic, highlight, html::ConvertOptions, links::LinkIndex,
+        shortcodes::Shortcodes,
+    },
+    fs, plugin, shared,
+    tera_functions::AssetUrl,
+};
+use super::manifest::Manifest;
+use super::report::{self, BuildReport, CacheReport, FileReport, PageReport, ReportOptions};
+use super::redirects;
 use super::seo;
 
 /// Represents the directory structure of a Norgolith site.
@@ -58,6 +75,62 @@ impl SitePaths {
     }
 }
 
+/// Files written to the public directory by a build, relative to it
+///
+/// Content pages also record their inputs so the next build can skip them when unchanged.
+#[derive(Debug, Default)]
+struct BuildOutputs(BTreeMap<PathBuf, Option<PageInputs>>);
+
+impl BuildOutputs {
+    fn insert(&mut self, public_dir: &Path, path: &Path, inputs: Option<PageInputs>) {
+        let rel_path = path.strip_prefix(public_dir).unwrap_or(path);
+        self.0.insert(rel_path.to_path_buf(), inputs);
+    }
+
+    fn extend(&mut self, public_dir: &Path, paths: &[PathBuf]) {
+        for path in paths {
+            self.insert(public_dir, path, None);
+        }
+    }
+}
+
+/// Removes the outputs of the previous build that were not produced again, e.g. the pages of
+/// removed sources, along with the directories left empty.
+///
+/// # Arguments
+/// * `public_dir` - build target directory of the site
+/// * `orphans` - The orphaned outputs, relative to `public_dir`
+///
+/// # Returns
+/// The number of removed files.
+#[instrument(skip(public_dir, orphans))]
+fn remove_orphaned_outputs(public_dir: &Path, orphans: &[PathBuf]) -> Result<usize> {
+    let mut count = 0usize;
+    for orphan in orphans {
+        let path = public_dir.join(orphan);
+        if !path.is_file() {
+            continue;
+        }
+        debug!(path = %path.display(), "Removing orphaned output");
+        std::fs::remove_file(&path).wrap_err(format!(
+            "{}: {}",
+            "Failed to remove orphaned output".bold(),
+            path.display()
+        ))?;
+        count += 1;
+
+        // Remove the directories left empty, up to the public directory
+        let mut dir = path.parent();
+        while let Some(parent) = dir.filter(|dir| *dir != public_dir) {
+            if std::fs::remove_dir(parent).is_err() {
+                break;
+            }
+            dir = parent.parent();
+        }
+    }
+    Ok(count)
+}
+
 /// Prepares the build directory by cleaning existing artifacts
 ///
 /// # Arguments
@@ -134,11 +207,12 @@ fn collect_xml_templates(tera: &Tera) -> Vec<String> {
 /// Subdirectories are created as needed. RSS validation is attempted per file;
 /// failures emit a warning rather than aborting, so non-RSS formats (Atom,
 /// sitemaps, etc.) are also supported.
-#[instrument(level = "debug", skip(tera, shared_context, public_dir))]
+#[instrument(level = "debug", skip(tera, shared_context, public_dir, fingerprints))]
 fn generate_xml_feeds(
     tera: &Tera,
     shared_context: &Context,
     public_dir: &Path,
+    fingerprints: Option<&Fingerprints>,
 ) -> Result<(usize, Vec<String>)> {
     let xml_templates = collect_xml_templates(tera);
     let count = xml_templates.len();
@@ -153,6 +227,7 @@ fn generate_xml_feeds(
         let rendered = tera
             .render(template_name, &context)
             .map_err(|e| eyre!("{}: {}", "Failed to render XML template".bold(), e))?;
+        let rendered = fingerprint_asset_urls(rendered, fingerprints);
 
         if template_name.contains("rss") && template_name.ends_with(".xml") {
             if let Err(e) = Channel::read_from(rendered.as_bytes()) {
@@ -188,19 +263,37 @@ fn generate_xml_feeds(
 /// * `tera` - Template engine instance
 /// * `paths` - Site directory paths
 /// * `site_config` - Site configuration
+/// * `convert_options` - Norg conversion options, including the site link index
+/// * `render_inputs` - Inputs shared by every page, see [`build_content_entry`]
+/// * `fingerprints` - Fingerprinted asset names, when assets are fingerprinted
+/// * `skip_unchanged` - Whether pages whose inputs did not change since the last build are kept
+/// * `outputs` - Records the pages of the build, along with their inputs
 /// * `minify` - Enable minification of output
+///
+/// # Returns
+/// The rendered pages to write, by public path, their permalinks, the conversion diagnostics of
+/// every page (including cached ones) and the timings of every page. Nothing is written to the
+/// public directory yet, see [`write_contents`].
 #[allow(clippy::too_many_arguments)]
-#[instrument(level = "debug", skip(tera, paths, site_config, shared_context, cache, plugin_mgr))]
+#[instrument(
+    level = "debug",
+    skip(tera, paths, site_config, convert_options, render_inputs, fingerprints, outputs, shared_context, cache, plugin_mgr)
+)]
 fn build_contents(
     tera: &Tera,
     paths: &SitePaths,
     posts: &[toml::Value],
     site_config: &config::SiteConfig,
+    convert_options: &ConvertOptions,
+    render_inputs: &RenderInputs,
+    fingerprints: Option<&Fingerprints>,
+    skip_unchanged: bool,
+    outputs: &mut BuildOutputs,
     shared_context: &Context,
     cache: &mut BuildCache,
     minify: bool,
     plugin_mgr: &plugin::PluginManager,
-) -> Result<(usize, Vec<String>, BuildTimings)> {
+) -> Result<(Vec<(PathBuf, String)>, Vec<String>, Vec<Diagnostic>, BuildTimings)> {
     use rayon::prelude::*;
 
     let entries: Vec<_> = WalkDir::new(&paths.content)
@@ -216,33 +309,55 @@ fn build_contents(
         .collect();
 
     // Parallel processing with rayon, buffer rendered content in memory
-    let results: Vec<BuildResult> = entries
+    let results: Vec<(BuildResult, Duration)> = entries
         .par_iter()
         .map(|entry| {
             let path = entry.path();
-            build_content_entry(
+            let start = Instant::now();
+            let result = build_content_entry(
                 path,
                 tera,
                 paths,
                 site_config,
+                convert_options,
+                render_inputs,
+                fingerprints,
+                skip_unchanged,
                 minify,
                 shared_context,
                 cache,
                 plugin_mgr,
-            )
+            );
+            (result, start.elapsed())
         })
         .collect();
 
     // Collect results and handle errors
     let mut buffered_writes = Vec::new();
     let mut permalinks = Vec::new();
-    for result in results {
+    let mut diagnostics = Vec::new();
+    let mut pages = Vec::new();
+    for (result, duration) in results {
         match result {
-            Ok(Some((public_path, content, permalink, cache_entry))) => {
-                buffered_writes.push((public_path, content));
-                permalinks.push(permalink);
-                if let Some((key, content_str, metadata)) = cache_entry {
-                    cache.insert(&key, &content_str, metadata);
+            Ok(Some(page)) => {
+                pages.push(PageReport {
+                    source: page.source,
+                    output: page
+                        .public_path
+                        .strip_prefix(&paths.public)
+                        .unwrap_or(&page.public_path)
+                        .to_path_buf(),
+                    duration_ms: duration.as_secs_f64() * 1000.0,
+                    rendered: page.rendered.is_some(),
+                });
+                outputs.insert(&paths.public, &page.public_path, Some(page.inputs));
+                if let Some(rendered) = page.rendered {
+                    buffered_writes.push((page.public_path, rendered));
+                }
+                permalinks.push(page.permalink);
+                diagnostics.extend(page.diagnostics);
+                if let Some((key, content_str, metadata, page_diagnostics)) = page.cache_insert {
+                    cache.insert(&key, &content_str, metadata, page_diagnostics);
                 }
             }
             Ok(None) => {} // draft or missing
@@ -250,43 +365,77 @@ fn build_contents(
         }
     }
 
-    // Sequential writes, single I/O path, no contention
-    let write_start = Instant::now();
+    let mut timings = BuildTimings::new();
+    timings.pages = pages;
+
+    Ok((buffered_writes, permalinks, diagnostics, timings))
+}
+
+/// Writes the pages rendered by [`build_contents`] to the public directory
+///
+/// Writes are sequential, a single I/O path avoids contention.
+///
+/// # Arguments
+/// * `writes` - The rendered pages, by public path
+///
+/// # Returns
+/// The number of written pages, pages whose output did not change are not counted.
+fn write_contents(writes: &[(PathBuf, String)]) -> Result<usize> {
     let mut built_count = 0usize;
-    for (public_path, content) in &buffered_writes {
+    for (public_path, content) in writes {
         if write_public_file(public_path, content)? {
             built_count += 1;
         }
     }
-    let write_ms = write_start.elapsed().as_millis();
-
-    let mut timings = BuildTimings::new();
-    timings.page_write_ms = write_ms;
-    timings.page_count = built_count;
+    Ok(built_count)
+}
 
-    Ok((built_count, permalinks, timings))
+/// (cache_key, content, metadata, diagnostics) for cache insertion
+type CacheInsert = (PathBuf, String, serde_json::Value, Vec<Diagnostic>);
+
+/// A rendered content page waiting to be written to the public directory
+struct BuiltPage {
+    /// Source file, relative to the content directory
+    source: PathBuf,
+    public_path: PathBuf,
+    /// `None` when the page is unchanged since the last build
+    rendered: Option<String>,
+    inputs: PageInputs,
+    permalink: String,
+    cache_insert: Option<CacheInsert>,
+    diagnostics: Vec<Diagnostic>,
 }
 
-/// (cache_key, content, metadata) for cache insertion
-type CacheInsert = (PathBuf, String, serde_json::Value);
 /// Result of building a single content entry
-type BuildResult = Result<Option<(PathBuf, String, String, Option<CacheInsert>)>>;
+type BuildResult = Result<Option<BuiltPage>>;
 
 /// Processes a single build entry (HTML file with metadata)
 ///
 /// Handles template rendering, metadata validation, and output path determination.
 /// Skips draft content and applies minification when enabled.
-/// Returns `(public_path, rendered_content)` for deferred writing.
+/// Returns the rendered page and its conversion diagnostics for deferred writing.
+///
+/// The inputs of the page are its source and the content of its images, combined with
+/// `render_inputs` (the templates of its layout, posts list, configuration). When
+/// `skip_unchanged` is set and the last build rendered the page from the same inputs, the page
+/// is not rendered again.
+///
+/// When `fingerprints` is set, the asset references of the page are rewritten to the
+/// fingerprinted asset names.
 #[allow(clippy::too_many_arguments)]
 #[instrument(
     level = "debug",
-    skip(tera, paths, site_config, shared_context, cache, plugin_mgr)
+    skip(tera, paths, site_config, convert_options, render_inputs, fingerprints, shared_context, cache, plugin_mgr)
 )]
 fn build_content_entry(
     path: &Path,
     tera: &Tera,
     paths: &SitePaths,
     site_config: &config::SiteConfig,
+    convert_options: &ConvertOptions,
+    render_inputs: &RenderInputs,
+    fingerprints: Option<&Fingerprints>,
+    skip_unchanged: bool,
     minify: bool,
     shared_context: &Context,
     cache: &BuildCache,
@@ -327,26 +476,60 @@ fn build_content_entry(
         return Ok(None);
     }
 
-    // Cache get (read-only, misses will be inserted later)
+    let public_path = determine_public_path(&paths.public, rel_path)?;
+    let layout = metadata
+        .get("layout")
+        .and_then(|v| v.as_str())
+        .unwrap_or("default");
     let cache_key = rel_path.with_extension("");
-    let cached = cache.get(&cache_key, &content);
 
-    // Load (parse_tree + HTML on miss, deserialization on hit)
-    let (mut metadata, cache_insert) = if let Some(cached) = cached {
+    // Load (parse_tree + HTML on miss, deserialization on hit). Cached pages whose images
+    // changed are misses
+    let cached = shared::cached_conversion(cache, &cache_key, &content, convert_options);
+    let (mut metadata, diagnostics, cache_insert) = if let Some(cached) = cached {
         match serde_json::from_value::<toml::Value>(cached.clone()) {
-            Ok(md) => (md, None),
+            Ok(md) => (md, cache.diagnostics(&cache_key), None),
             Err(_) => {
-                let md = shared::load_metadata_from_content(&content, rel_path, &site_config.root_url);
+                let (md, diagnostics) =
+                    shared::load_metadata_from_content(&content, rel_path, convert_options);
                 let cache_val = serde_json::to_value(&md).unwrap_or_default();
-                (md, Some((cache_key, content.clone(), cache_val)))
+                let insert = (cache_key, content.clone(), cache_val, diagnostics.clone());
+                (md, diagnostics, Some(insert))
             }
         }
     } else {
-        let md = shared::load_metadata_from_content(&content, rel_path, &site_config.root_url);
+        let (md, diagnostics) =
+            shared::load_metadata_from_content(&content, rel_path, convert_options);
         let cache_val = serde_json::to_value(&md).unwrap_or_default();
-        (md, Some((cache_key, content.clone(), cache_val)))
+        let insert = (cache_key, content.clone(), cache_val, diagnostics.clone());
+        (md, diagnostics, Some(insert))
     };
 
+    // Skip pages rendered from the same inputs by the last build
+    let inputs = render_inputs.page_inputs(
+        cache.source_hash(&content),
+        &metadata,
+        &format!("{}.html", layout),
+    );
+    let output = public_path.strip_prefix(&paths.public).unwrap_or(&public_path);
+    if skip_unchanged && public_path.exists() && cache.is_output_fresh(output, &inputs) {
+        debug!(path = %rel_path.display(), "Page unchanged, skipping");
+        let permalink = metadata
+            .get("permalink")
+            .and_then(|v| v.as_str())
+            .unwrap_or("/")
+            .to_string();
+        return Ok(Some(BuiltPage {
+            source: rel_path.to_path_buf(),
+            public_path,
+            rendered: None,
+            inputs,
+            permalink,
+            cache_insert,
+            diagnostics,
+        }));
+    }
+
     // post_convert hook: modify HTML after Norg conversion, before Tera
     if plugin_mgr.has_hook(plugin::HOOK_POST_CONVERT) {
         if let Some(html) = metadata.get("raw").and_then(|v| v.as_str()) {
@@ -380,9 +563,6 @@ fn build_content_entry(
         }
     }
 
-    // Determine output path
-    let public_path = determine_public_path(&paths.public, rel_path)?;
-
     // Template render
     let mut rendered = shared::render_norg_page(tera, &metadata, shared_context)?;
 
@@ -421,6 +601,9 @@ fn build_content_entry(
         .replace_all(&rendered, format!("href=\"{}/", site_config.root_url))
         .into_owned();
 
+    // Asset fingerprints
+    let rendered = fingerprint_asset_urls(rendered, fingerprints);
+
     // Minify
     let rendered = if minify && !rendered.is_empty() {
         minify_html_content(rendered)?
@@ -435,30 +618,44 @@ fn build_content_entry(
         .unwrap_or("/")
         .to_string();
 
-    Ok(Some((public_path, rendered, permalink, cache_insert)))
+    Ok(Some(BuiltPage {
+        source: rel_path.to_path_buf(),
+        public_path,
+        rendered: Some(rendered),
+        inputs,
+        permalink,
+        cache_insert,
+        diagnostics,
+    }))
 }
 
 /// Generates category listing pages
+///
+/// # Returns
+/// The paths of the written pages.
 pub fn build_category_pages(
     tera: &Tera,
     public_dir: &Path,
     posts: &[toml::Value],
     config: &config::SiteConfig,
     collections: &shared::PrecomputedCollections,
-) -> Result<usize> {
+    fingerprints: Option<&Fingerprints>,
+) -> Result<Vec<PathBuf>> {
     let categories = shared::collect_all_posts_categories(posts);
     let categories_dir = public_dir.join(&config.categories_dir);
 
     // Generate category pages only if the site has posts
     if posts.is_empty() {
-        return Ok(0);
+        return Ok(Vec::new());
     }
 
     let content = shared::render_category_index(tera, posts, config, collections)?;
+    let content = fingerprint_asset_urls(content, fingerprints);
 
     std::fs::create_dir_all(&categories_dir)?;
-    std::fs::write(categories_dir.join("index.html"), content)?;
-    let mut page_count = 1usize;
+    let index_path = categories_dir.join("index.html");
+    std::fs::write(&index_path, content)?;
+    let mut pages = vec![index_path];
 
     // Generate individual category pages
     for category in categories {
@@ -473,24 +670,65 @@ pub fn build_category_pages(
             .collect();
 
         let content = shared::render_category_page(tera, &category, &cat_posts, config)?;
+        let content = fingerprint_asset_urls(content, fingerprints);
 
         let cat_dir = categories_dir.join(&category);
         std::fs::create_dir_all(&cat_dir)?;
 
-        std::fs::write(cat_dir.join("index.html"), content)?;
-        page_count += 1;
+        let page_path = cat_dir.join("index.html");
+        std::fs::write(&page_path, content)?;
+        pages.push(page_path);
+    }
+
+    Ok(pages)
+}
+
+/// Writes the syntax highlighting theme stylesheet used by the `builtin` highlighter engine.
+///
+/// Only needed when the engine emits class names, inline styles are self-contained.
+///
+/// # Returns
+/// * `Result<bool>` - `Ok(true)` if the stylesheet was written.
+fn write_highlight_css(public_dir: &Path, site_config: &config::SiteConfig) -> Result<bool> {
+    let Some(highlighter) = site_config.highlighter.as_ref().filter(|h| h.is_builtin()) else {
+        return Ok(false);
+    };
+    if highlighter.style != config::HighlightStyle::Classes {
+        return Ok(false);
     }
 
-    Ok(page_count)
+    let theme = highlighter
+        .theme
+        .as_deref()
+        .unwrap_or(highlight::DEFAULT_THEME);
+    let css = highlight::theme_css(theme)?;
+    let css_path = public_dir.join(highlight::CSS_PATH);
+    if let Some(parent) = css_path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(&css_path, css).wrap_err(format!(
+        "{}: {}",
+        "Failed to write highlighter stylesheet".bold(),
+        css_path.display()
+    ))?;
+    Ok(true)
 }
 
 /// Renders 404.html and 500.html error pages into the public directory.
 ///
 /// Uses Tera to render templates with site context. Skips silently if a template
 /// is not registered (e.g., after user removes it and re-inits Tera).
-#[instrument(level = "debug", skip(tera, shared_context, public_dir))]
-fn build_error_pages(tera: &Tera, shared_context: &Context, public_dir: &Path) -> Result<usize> {
-    let mut count = 0usize;
+///
+/// # Returns
+/// The paths of the written pages.
+#[instrument(level = "debug", skip(tera, shared_context, public_dir, fingerprints))]
+fn build_error_pages(
+    tera: &Tera,
+    shared_context: &Context,
+    public_dir: &Path,
+    fingerprints: Option<&Fingerprints>,
+) -> Result<Vec<PathBuf>> {
+    let mut pages = Vec::new();
     for name in &["404.html", "500.html"] {
         if !tera.get_template_names().any(|n| n == *name) {
             continue;
@@ -498,11 +736,13 @@ fn build_error_pages(tera: &Tera, shared_context: &Context, public_dir: &Path) -
         let rendered = tera
             .render(name, shared_context)
             .map_err(|e| eyre!("Failed to render {}: {}", name, e))?;
-        std::fs::write(public_dir.join(name), &rendered)
+        let rendered = fingerprint_asset_urls(rendered, fingerprints);
+        let page_path = public_dir.join(name);
+        std::fs::write(&page_path, &rendered)
             .wrap_err(format!("Failed to write {}", name))?;
-        count += 1;
+        pages.push(page_path);
     }
-    Ok(count)
+    Ok(pages)
 }
 
 /// Determines the final public path for an HTML file based on its name and location.
@@ -587,11 +827,80 @@ fn write_public_file(public_path: &Path, rendered: &str) -> Result<bool> {
     Ok(true)
 }
 
+/// Writes the HTML stubs of the redirects, along with the redirects and headers files of the
+/// hosts serving the site.
+///
+/// Stubs are not written over the files built at the same path, the hosts files still redirect
+/// them.
+///
+/// # Arguments
+/// * `public_dir` - build target directory of the site
+/// * `site_config` - The site configuration, holding the redirects and headers.
+/// * `links` - The index of the site pages, holding their aliases.
+/// * `outputs` - The files written by the build so far.
+///
+/// # Returns
+/// * `Result<Vec<PathBuf>>` - The written files.
+#[instrument(skip_all)]
+fn write_redirects(
+    public_dir: &Path,
+    site_config: &config::SiteConfig,
+    links: &LinkIndex,
+    outputs: &BuildOutputs,
+) -> Result<Vec<PathBuf>> {
+    let redirects = redirects::collect(site_config, links);
+    let mut written = Vec::new();
+    for redirect in &redirects {
+        let stub_path = redirects::stub_path(&redirect.from);
+        if outputs.0.contains_key(Path::new(&stub_path)) {
+            warn!(
+                "{} {} is a built page, its redirect stub is not written",
+                "Redirect:".yellow().bold(),
+                redirect.from
+            );
+            continue;
+        }
+        let public_path = public_dir.join(stub_path);
+        if let Some(parent) = public_path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        write_public_file(&public_path, &redirects::stub_html(&redirect.to))?;
+        written.push(public_path);
+    }
+
+    let headers = &site_config.headers;
+    let files = [
+        (
+            redirects::REDIRECTS_FILE,
+            !redirects.is_empty(),
+            redirects::netlify_redirects(&redirects),
+        ),
+        (
+            redirects::HEADERS_FILE,
+            !headers.is_empty(),
+            redirects::netlify_headers(headers),
+        ),
+        (
+            redirects::NGINX_FILE,
+            !redirects.is_empty() || !headers.is_empty(),
+            redirects::nginx_config(&redirects, headers),
+        ),
+    ];
+    for (name, enabled, content) in files {
+        if enabled {
+            let public_path = public_dir.join(name);
+            write_public_file(&public_path, &content)?;
+            written.push(public_path);
+        }
+    }
+    Ok(written)
+}
+
 /// Determines whether an asset should be minified based on its name and extension.
 ///
-/// This function checks if the asset is a JavaScript or CSS file and does not already
-/// have "min" in its name. Assets with "min" in their name or non-JS/CSS extensions
-/// are skipped for minification.
+/// This function checks if the asset is a JavaScript file and does not already
+/// have "min" in its name. Assets with "min" in their name or non-JS extensions
+/// are skipped for minification. Stylesheets are minified by their compiler.
 ///
 /// # Arguments
 /// * `src` - The path to the asset file.
@@ -602,7 +911,7 @@ fn write_public_file(public_path: &Path, rendered: &str) -> Result<bool> {
 fn should_minify_asset(src: &Path) -> bool {
     let file_stem = src.file_stem().and_then(|s| s.to_str()).unwrap_or_default();
     let file_ext = src.extension().and_then(|s| s.to_str()).unwrap_or_default();
-    !file_stem.ends_with(".min") && (file_ext == "js" || file_ext == "css")
+    !file_stem.ends_with(".min") && file_ext == "js"
 }
 
 /// Minifies HTML content using optimized settings for production builds.
@@ -666,25 +975,48 @@ fn minify_js_asset(src_path: &Path, dest_path: &Path) -> Result<()> {
     Ok(())
 }
 
-#[instrument(skip(src_path, dest_path))]
-fn minify_css_asset(src_path: &Path, dest_path: &Path) -> Result<()> {
-    let content = std::fs::read_to_string(src_path)?;
-
-    let mut stylesheet =
-        StyleSheet::parse(&content, ParserOptions::default()).map_err(|e| eyre!("{}", e))?;
-    stylesheet.minify(MinifyOptions::default())?;
-    let minified = stylesheet.to_css(PrinterOptions {
-        minify: true,
-        ..Default::default()
-    })?;
+/// Compiles a stylesheet (bundling its imports, compiling SCSS) and writes it to the public
+/// directory, with its asset references rewritten to the fingerprinted asset names if any.
+///
+/// # Arguments
+/// * `assets_dir` - The assets directory holding the stylesheet.
+/// * `rel_path` - The stylesheet path relative to `assets_dir`.
+/// * `dest_path` - The path where the compiled stylesheet should be saved.
+/// * `compiler` - The stylesheet compiler.
+/// * `fingerprints` - The fingerprinted asset names, when assets are fingerprinted.
+#[instrument(skip(assets_dir, dest_path, compiler, fingerprints))]
+fn compile_stylesheet(
+    assets_dir: &Path,
+    rel_path: &Path,
+    dest_path: &Path,
+    compiler: &CssCompiler,
+    fingerprints: Option<&Fingerprints>,
+) -> Result<()> {
+    let compiled = compiler.compile(assets_dir, rel_path)?;
+    for warning in &compiled.warnings {
+        warn!("{} {}", "Ignored invalid CSS:".yellow().bold(), warning);
+    }
+    let content = match fingerprints {
+        Some(fingerprints) => {
+            fingerprints.rewrite_css(&compiled.code, &css::output_path(rel_path))
+        }
+        None => compiled.code,
+    };
 
-    std::fs::write(dest_path, minified.code)
-        .wrap_err_with(|| {
-            format!("Failed to write minified CSS to {}", dest_path.display()).bold()
-        })?;
+    std::fs::write(dest_path, content).wrap_err_with(|| {
+        format!("Failed to write stylesheet to {}", dest_path.display()).bold()
+    })?;
     Ok(())
 }
 
+/// Rewrites the asset references of a rendered page to the fingerprinted asset names, if any.
+fn fingerprint_asset_urls(rendered: String, fingerprints: Option<&Fingerprints>) -> String {
+    match fingerprints {
+        Some(fingerprints) => fingerprints.rewrite_html(&rendered),
+        None => rendered,
+    }
+}
+
 #[instrument(skip(src_path, dest_path))]
 fn copy_binary_asset(src_path: &Path, dest_path: &Path) -> Result<()> {
     let content = std::fs::read(src_path)?;
@@ -707,7 +1039,7 @@ fn copy_binary_asset(src_path: &Path, dest_path: &Path) -> Result<()> {
 /// Copies an asset file with optional minification based on its type.
 ///
 /// This function handles the copying of assets, applying minification to JavaScript
-/// and CSS files when enabled. Other file types are copied without modification.
+/// files when enabled. Other file types are copied without modification.
 ///
 /// # Arguments
 /// * `src_path` - The path to the source asset file.
@@ -723,7 +1055,6 @@ fn copy_asset_file(src_path: &Path, dest_path: &Path, minify: bool) -> Result<()
 
         match file_ext {
             "js" => minify_js_asset(src_path, dest_path)?,
-            "css" => minify_css_asset(src_path, dest_path)?,
             _ => copy_binary_asset(src_path, dest_path)?,
         }
     } else {
@@ -742,13 +1073,25 @@ fn copy_asset_file(src_path: &Path, dest_path: &Path, minify: bool) -> Result<()
 /// # Arguments
 /// * `assets_dir` - The source directory containing the assets to copy.
 /// * `target_dir` - Target assets directory to paste in.
-/// * `minify` - Whether to minify supported assets (e.g., JS and CSS) during the copy process.
+/// * `minify` - Whether to minify supported assets (e.g., JS) during the copy process.
+/// * `compiler` - The stylesheet compiler, stylesheets are compiled instead of copied and SCSS
+///   partials are skipped.
+/// * `bundles` - The JavaScript bundles, the modules bundled into them are skipped.
+/// * `fingerprints` - The fingerprinted asset names, assets are copied with these names and
+///   stylesheets reference them when set.
 ///
 /// # Returns
-/// * `Result<()>` - `Ok(())` if all assets are copied successfully, otherwise an error.
-#[instrument(skip(assets_dir, target_dir, minify))]
-fn copy_assets(assets_dir: &Path, target_dir: &Path, minify: bool) -> Result<usize> {
-    let mut file_count = 0usize;
+/// * `Result<Vec<PathBuf>>` - The paths of the copied assets, otherwise an error.
+#[instrument(skip(assets_dir, target_dir, minify, compiler, bundles, fingerprints))]
+fn copy_assets(
+    assets_dir: &Path,
+    target_dir: &Path,
+    minify: bool,
+    compiler: &CssCompiler,
+    bundles: &JsBundles,
+    fingerprints: Option<&Fingerprints>,
+) -> Result<Vec<PathBuf>> {
+    let mut copied = Vec::new();
     for entry in WalkDir::new(assets_dir)
         .follow_links(true)
         .into_iter()
@@ -764,18 +1107,106 @@ fn copy_assets(assets_dir: &Path, target_dir: &Path, minify: bool) -> Result<usi
             warn!("Skipping asset outside assets directory: {}", entry.path().display());
             continue;
         };
-        let target_path = target_dir.join(rel_path);
         if entry.path().is_dir() {
+            let target_path = target_dir.join(rel_path);
             if !target_path.exists() {
                 std::fs::create_dir_all(target_path)?;
             }
+            continue;
+        }
+
+        // Partials are compiled into the stylesheets importing them, and modules into the
+        // JavaScript bundles
+        if css::is_partial(rel_path) || bundles.is_bundled(entry.path()) {
+            continue;
+        }
+
+        let output = css::output_path(rel_path);
+        let target_path = match fingerprints.and_then(|f| f.get(&output)) {
+            Some(name) => target_dir.join(name),
+            None => target_dir.join(&output),
+        };
+        if css::is_stylesheet(rel_path) {
+            compile_stylesheet(assets_dir, rel_path, &target_path, compiler, fingerprints)?;
         } else {
             copy_asset_file(entry.path(), &target_path, minify)?;
-            file_count += 1;
         }
+        copied.push(target_path);
+    }
+
+    Ok(copied)
+}
+
+/// Writes the JavaScript bundles to the public assets directory.
+///
+/// # Arguments
+/// * `bundles` - The JavaScript bundles.
+/// * `target_dir` - Target assets directory to write in.
+/// * `fingerprints` - The fingerprinted asset names, bundles are written with these names when
+///   set.
+///
+/// # Returns
+/// * `Result<Vec<PathBuf>>` - The paths of the written bundles, otherwise an error.
+#[instrument(skip(bundles, target_dir, fingerprints))]
+fn write_bundles(
+    bundles: &JsBundles,
+    target_dir: &Path,
+    fingerprints: Option<&Fingerprints>,
+) -> Result<Vec<PathBuf>> {
+    let mut written = Vec::new();
+    for (output, bundle) in bundles.iter() {
+        let target_path = match fingerprints.and_then(|f| f.get(output)) {
+            Some(name) => target_dir.join(name),
+            None => target_dir.join(output),
+        };
+        if let Some(parent) = target_path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&target_path, &bundle.code).wrap_err_with(|| {
+            format!("Failed to write bundle to {}", target_path.display()).bold()
+        })?;
+        written.push(target_path);
     }
+    Ok(written)
+}
 
-    Ok(file_count)
+/// Returns the source of a copied asset relative to the site root, site assets override the
+/// theme ones. Compiled stylesheets come from their SCSS source when there is no CSS one, and
+/// JavaScript bundles from their entry point.
+///
+/// # Arguments
+/// * `paths` - Site directory paths
+/// * `output` - The output path, relative to the public directory
+/// * `bundles` - The JavaScript bundles
+/// * `fingerprints` - The fingerprinted asset names, when assets are fingerprinted
+fn asset_source(
+    paths: &SitePaths,
+    output: &Path,
+    bundles: &JsBundles,
+    fingerprints: Option<&Fingerprints>,
+) -> Option<PathBuf> {
+    let rel_path = output.strip_prefix("assets").ok()?;
+    let rel_path = fingerprints
+        .and_then(|f| f.original(rel_path))
+        .map(Path::new)
+        .unwrap_or(rel_path);
+    if let Some(bundle) = bundles.get(rel_path) {
+        return match bundle.entry.strip_prefix(&paths.assets) {
+            Ok(entry) => Some(Path::new("assets").join(entry)),
+            Err(_) => bundle
+                .entry
+                .strip_prefix(&paths.theme_assets)
+                .ok()
+                .map(|entry| Path::new("theme/assets").join(entry)),
+        };
+    }
+    let (dir, source) = css::find_source(&[&paths.assets, &paths.theme_assets], rel_path)?;
+    let prefix = if dir == paths.assets {
+        "assets"
+    } else {
+        "theme/assets"
+    };
+    Some(Path::new(prefix).join(source))
 }
 
 #[derive(Debug)]
@@ -794,6 +1225,7 @@ struct BuildTimings {
     seo_ms: u128,
     assets_ms: u128,
     error_pages_ms: u128,
+    redirects_ms: u128,
     cache_save_ms: u128,
     // Per-page sub-timing (sums across all pages)
     page_file_ms: u128,
@@ -807,6 +1239,8 @@ struct BuildTimings {
     page_minify_ms: u128,
     page_write_ms: u128,
     page_count: usize,
+    /// Build duration of every content page
+    pages: Vec<PageReport>,
 }
 
 impl BuildTimings {
@@ -826,6 +1260,7 @@ impl BuildTimings {
             seo_ms: 0,
             assets_ms: 0,
             error_pages_ms: 0,
+            redirects_ms: 0,
             cache_save_ms: 0,
             page_file_ms: 0,
             page_meta_ms: 0,
@@ -838,9 +1273,32 @@ impl BuildTimings {
             page_minify_ms: 0,
             page_write_ms: 0,
             page_count: 0,
+            pages: Vec::new(),
         }
     }
 
+    /// Returns the duration of each build phase, as listed in the build report
+    fn phases(&self) -> BTreeMap<&'static str, u128> {
+        BTreeMap::from([
+            ("config", self.config_ms),
+            ("tera", self.tera_ms),
+            ("plugins", self.plugins_ms),
+            ("prepare_dir", self.prepare_dir_ms),
+            ("collect_posts", self.collect_posts_ms),
+            ("collections", self.collections_ms),
+            ("shared_context", self.shared_ctx_ms),
+            ("cache_open", self.cache_open_ms),
+            ("content", self.content_ms),
+            ("categories", self.categories_ms),
+            ("feeds", self.feeds_ms),
+            ("seo", self.seo_ms),
+            ("assets", self.assets_ms),
+            ("error_pages", self.error_pages_ms),
+            ("redirects", self.redirects_ms),
+            ("cache_save", self.cache_save_ms),
+        ])
+    }
+
     fn print_summary(&self, total_ms: u128) {
         let overhead = total_ms
             .saturating_sub(self.config_ms)
@@ -856,6 +1314,7 @@ impl BuildTimings {
             .saturating_sub(self.seo_ms)
             .saturating_sub(self.assets_ms)
             .saturating_sub(self.error_pages_ms)
+            .saturating_sub(self.redirects_ms)
             .saturating_sub(self.cache_save_ms);
 
         println!();
@@ -873,6 +1332,7 @@ impl BuildTimings {
         println!("  {:<30} {:>6}ms  ({:>4.1}%)", "SEO (sitemap+robots)", self.seo_ms, pct(self.seo_ms, total_ms));
         println!("  {:<30} {:>6}ms  ({:>4.1}%)", "Asset copy", self.assets_ms, pct(self.assets_ms, total_ms));
         println!("  {:<30} {:>6}ms  ({:>4.1}%)", "Error pages", self.error_pages_ms, pct(self.error_pages_ms, total_ms));
+        println!("  {:<30} {:>6}ms  ({:>4.1}%)", "Redirects", self.redirects_ms, pct(self.redirects_ms, total_ms));
         println!("  {:<30} {:>6}ms  ({:>4.1}%)", "Cache save", self.cache_save_ms, pct(self.cache_save_ms, total_ms));
         println!("  {:<30} {:>6}ms  ({:>4.1}%)", "Overhead/other", overhead, pct(overhead, total_ms));
         println!("  {}", "─".repeat(50));
@@ -920,8 +1380,11 @@ fn avg(ms: u128, n: usize) -> f64 {
 ///
 /// # Arguments
 /// * `minify` - Enable minification of HTML/CSS/JS outputs
-#[instrument(skip(minify))]
-pub fn build(minify: bool) -> Result<()> {
+/// * `strict` - Fail the build if any page produced conversion diagnostics
+/// * `clean` - Remove the previous build instead of only updating the changed outputs
+/// * `report` - Write a machine-readable report of the build
+#[instrument(skip(minify, strict, report))]
+pub fn build(minify: bool, strict: bool, clean: bool, report: Option<ReportOptions>) -> Result<()> {
     let Some(root) = fs::find_config_file()? else {
         bail!(
             "{}: not in a Norgolith site directory",
@@ -939,6 +1402,7 @@ pub fn build(minify: bool) -> Result<()> {
         }
     );
     let build_start = Instant::now();
+    let started_at = chrono::Utc::now();
     let mut timings = BuildTimings::new();
 
     // Load site configuration
@@ -963,7 +1427,38 @@ pub fn build(minify: bool) -> Result<()> {
     // Initialize Tera
     let t = Instant::now();
     debug!("Initializing template engine");
-    let tera = shared::init_tera(paths.templates.to_str().unwrap(), &paths.theme_templates)?;
+    let mut tera = shared::init_tera(paths.templates.to_str().unwrap(), &paths.theme_templates)?;
+    let css_compiler = CssCompiler::new(site_config.assets.as_ref(), minify, false)?;
+    let js_bundles = JsBundles::build(
+        &JsBundler::new(minify),
+        &[&paths.assets, &paths.theme_assets],
+        site_config
+            .assets
+            .as_ref()
+            .map_or(&[], |assets| assets.entries.as_slice()),
+    )?;
+    // Fingerprinted asset names, templates get them from `asset_url`
+    let fingerprints = if site_config
+        .assets
+        .as_ref()
+        .is_some_and(|assets| assets.fingerprint)
+    {
+        let fingerprints = Arc::new(Fingerprints::compute(
+            &[&paths.theme_assets, &paths.assets],
+            &css_compiler,
+            &js_bundles,
+        )?);
+        tera.register_function(
+            "asset_url",
+            AssetUrl {
+                fingerprints: Some(Arc::clone(&fingerprints)),
+            },
+        );
+        Some(fingerprints)
+    } else {
+        None
+    };
+    let fingerprints = fingerprints.as_deref();
     timings.tera_ms = t.elapsed().as_millis();
 
     // Load plugins and apply sandbox
@@ -1000,22 +1495,32 @@ pub fn build(minify: bool) -> Result<()> {
         }
     }
 
-    // Prepare build directory
+    // Open cache, posts and pages reuse the cached conversion of unchanged documents
     let t = Instant::now();
-    prepare_build_directory(&paths.public)?;
-    timings.prepare_dir_ms = t.elapsed().as_millis();
-
-    // Pre-create output directories for all content entries
-    let t = Instant::now();
-    precreate_output_dirs(&paths)?;
-    timings.prepare_dir_ms += t.elapsed().as_millis();
+    let mut cache = BuildCache::open(&root_dir)?;
+    timings.cache_open_ms = t.elapsed().as_millis();
 
-    // Collect post metadata
+    // Index pages and headings so internal links can be resolved, then collect post metadata
     let t = Instant::now();
+    let mut convert_options = shared::convert_options(&site_config, &site_config.root_url);
+    if let Some(macros) = shared::load_macros(&root_dir, &site_config)? {
+        convert_options = convert_options.with_macros(macros);
+    }
+    let template_deps = TemplateDeps::new(&tera);
+    if let Some(shortcodes) = Shortcodes::load(&tera, &template_deps) {
+        convert_options = convert_options.with_shortcodes(Arc::new(shortcodes));
+    }
+    if let Some(images) = shared::load_images(&root_dir, &site_config)? {
+        convert_options = convert_options.with_images(images);
+    }
+    let links = shared::build_link_index(&paths.content, &convert_options, false);
+    let convert_options = convert_options.with_links(Arc::new(links));
+    cache.set_site_inputs(convert_options.fingerprint());
     let posts: Vec<_> = shared::collect_all_posts_metadata(
         &paths.content,
-        &site_config.root_url,
+        &convert_options,
         &site_config.collections,
+        Some(&mut cache),
     )?
     .into_iter()
     .filter(|post| {
@@ -1040,29 +1545,97 @@ pub fn build(minify: bool) -> Result<()> {
     let shared_context = shared::build_shared_context(&posts, &site_config, &collections);
     timings.shared_ctx_ms = t.elapsed().as_millis();
 
-    // Open cache
-    let t = Instant::now();
-    let mut cache = BuildCache::open(&root_dir)?;
-    timings.cache_open_ms = t.elapsed().as_millis();
+    // Inputs shared by every page, combined with the source and layout of each page
+    let render_inputs = RenderInputs::new(
+        template_deps,
+        &posts,
+        &config_content,
+        minify,
+        &fingerprints.map(Fingerprints::fingerprint).unwrap_or_default(),
+    )?;
+    // Page hooks of plugins can change the output in ways that cannot be tracked
+    let skip_unchanged = !clean
+        && !plugin_mgr.has_hook(plugin::HOOK_POST_CONVERT)
+        && !plugin_mgr.has_hook(plugin::HOOK_POST_RENDER);
+    let mut outputs = BuildOutputs::default();
 
     // Build content
     let t = Instant::now();
-    let (page_count, permalinks, content_timings) = build_contents(&tera, &paths, &posts, &site_config, &shared_context, &mut cache, minify, &plugin_mgr)?;
-    timings.content_ms = t.elapsed().as_millis();
+    let (writes, permalinks, diagnostics, content_timings) = build_contents(
+        &tera,
+        &paths,
+        &posts,
+        &site_config,
+        &convert_options,
+        &render_inputs,
+        fingerprints,
+        skip_unchanged,
+        &mut outputs,
+        &shared_context,
+        &mut cache,
+        minify,
+        &plugin_mgr,
+    )?;
+    let convert_ms = t.elapsed().as_millis();
+    // Strict builds fail before touching the public directory, so the previous build is kept
+    // as-is instead of being partially replaced
+    if strict && !diagnostics.is_empty() {
+        shared::print_diagnostics_report(&diagnostics);
+        // Keep the cache so the next build does not need to convert every page again
+        if let Err(e) = cache.save() {
+            warn!("Failed to save build cache: {}", e);
+        }
+        bail!(
+            "{}: {} conversion diagnostics found (--strict)",
+            "Could not build the site".bold(),
+            diagnostics.len()
+        );
+    }
+
+    // Prepare build directory, incremental builds keep the previous outputs
+    let prepare_start = Instant::now();
+    if clean {
+        prepare_build_directory(&paths.public)?;
+    } else {
+        std::fs::create_dir_all(&paths.public).wrap_err(format!(
+            "{}: {}",
+            "Failed to create public directory".bold(),
+            paths.public.display()
+        ))?;
+    }
+    // Pre-create output directories for all content entries
+    precreate_output_dirs(&paths)?;
+    timings.prepare_dir_ms = prepare_start.elapsed().as_millis();
+
+    let write_start = Instant::now();
+    let page_count = write_contents(&writes)?;
+    timings.page_write_ms = write_start.elapsed().as_millis();
+    timings.content_ms = convert_ms + timings.page_write_ms;
     timings.page_count = page_count;
     // Copy per-page sub-timings from the concurrent build
-    timings.page_write_ms = content_timings.page_write_ms;
+    timings.pages = content_timings.pages;
+    let unchanged_count = outputs.0.len().saturating_sub(page_count);
     println!(
-        "  {} {}  {:<12}  {}",
+        "  {} {}  {:<12}  {}{}",
         "•".green(),
         format!("{:<12}", "Content").bold(),
         format!("{} pages", page_count),
-        shared::get_elapsed_time(t).dimmed()
+        shared::get_elapsed_time(t).dimmed(),
+        if unchanged_count > 0 {
+            format!(" ({} unchanged)", unchanged_count).dimmed()
+        } else {
+            ColoredString::from("")
+        }
     );
+    if !diagnostics.is_empty() {
+        shared::print_diagnostics_report(&diagnostics);
+    }
 
     // Category pages
     let t = Instant::now();
-    let cat_count = build_category_pages(&tera, &paths.public, &posts, &site_config, &collections)?;
+    let category_pages = build_category_pages(&tera, &paths.public, &posts, &site_config, &collections, fingerprints)?;
+    let cat_count = category_pages.len();
+    outputs.extend(&paths.public, &category_pages);
     timings.categories_ms = t.elapsed().as_millis();
     if cat_count > 0 {
         println!(
@@ -1076,7 +1649,10 @@ pub fn build(minify: bool) -> Result<()> {
 
     // XML feeds
     let t = Instant::now();
-    let (feed_count, feed_names) = generate_xml_feeds(&tera, &shared_context, &paths.public)?;
+    let (feed_count, feed_names) = generate_xml_feeds(&tera, &shared_context, &paths.public, fingerprints)?;
+    for feed_name in &feed_names {
+        outputs.insert(&paths.public, Path::new(feed_name), None);
+    }
     timings.feeds_ms = t.elapsed().as_millis();
     if feed_count > 0 {
         println!(
@@ -1156,6 +1732,7 @@ pub fn build(minify: bool) -> Result<()> {
             let output_path = paths.public.join("sitemap.xml");
             std::fs::write(&output_path, &xml)
                 .wrap_err("Failed to write sitemap.xml")?;
+            outputs.insert(&paths.public, &output_path, None);
             seo_count += 1;
         }
 
@@ -1170,6 +1747,7 @@ pub fn build(minify: bool) -> Result<()> {
                 let output_path = paths.public.join("robots.txt");
                 std::fs::write(&output_path, &content)
                     .wrap_err("Failed to write robots.txt")?;
+                outputs.insert(&paths.public, &output_path, None);
                 seo_count += 1;
             }
         }
@@ -1188,11 +1766,37 @@ pub fn build(minify: bool) -> Result<()> {
     // Assets
     let t = Instant::now();
     let public_assets_dir = paths.public.join("assets");
-    let mut asset_count = 0usize;
+    let mut assets = Vec::new();
     if paths.theme_assets.exists() {
-        asset_count += copy_assets(&paths.theme_assets, &public_assets_dir, minify)?;
+        assets.extend(copy_assets(
+            &paths.theme_assets,
+            &public_assets_dir,
+            minify,
+            &css_compiler,
+            &js_bundles,
+            fingerprints,
+        )?);
+    }
+    assets.extend(copy_assets(
+        &paths.assets,
+        &public_assets_dir,
+        minify,
+        &css_compiler,
+        &js_bundles,
+        fingerprints,
+    )?);
+    assets.extend(write_bundles(&js_bundles, &public_assets_dir, fingerprints)?);
+    if let Some(images) = &convert_options.images {
+        assets.extend(images.publish(&paths.public)?);
     }
-    asset_count += copy_assets(&paths.assets, &public_assets_dir, minify)?;
+    if write_highlight_css(&paths.public, &site_config)? {
+        assets.push(paths.public.join(highlight::CSS_PATH));
+    }
+    // Theme assets overridden by the site ones are copied twice
+    assets.sort();
+    assets.dedup();
+    let asset_count = assets.len();
+    outputs.extend(&paths.public, &assets);
     timings.assets_ms = t.elapsed().as_millis();
     println!(
         "  {} {}  {:<12}  {}",
@@ -1204,7 +1808,9 @@ pub fn build(minify: bool) -> Result<()> {
 
     // Error pages (404.html, 500.html)
     let t = Instant::now();
-    let error_page_count = build_error_pages(&tera, &shared_context, &paths.public)?;
+    let error_pages = build_error_pages(&tera, &shared_context, &paths.public, fingerprints)?;
+    let error_page_count = error_pages.len();
+    outputs.extend(&paths.public, &error_pages);
     timings.error_pages_ms = t.elapsed().as_millis();
     if error_page_count > 0 {
         println!(
@@ -1216,6 +1822,38 @@ pub fn build(minify: bool) -> Result<()> {
         );
     }
 
+    // Redirects and custom headers
+    let t = Instant::now();
+    let redirect_files = match &convert_options.links {
+        Some(links) => write_redirects(&paths.public, &site_config, links, &outputs)?,
+        None => Vec::new(),
+    };
+    let redirect_file_count = redirect_files.len();
+    outputs.extend(&paths.public, &redirect_files);
+    timings.redirects_ms = t.elapsed().as_millis();
+    if redirect_file_count > 0 {
+        println!(
+            "  {} {}  {:<12}  {}",
+            "•".green(),
+            format!("{:<12}", "Redirects").bold(),
+            format!("{} files", redirect_file_count),
+            shared::get_elapsed_time(t).dimmed()
+        );
+    }
+
+    // Orphaned outputs
+    let generated: Vec<PathBuf> = outputs.0.keys().cloned().collect();
+    let orphans = cache.record_outputs(outputs.0);
+    let orphan_count = remove_orphaned_outputs(&paths.public, &orphans)?;
+    if orphan_count > 0 {
+        println!(
+            "  {} {}  {:<12}",
+            "•".green(),
+            format!("{:<12}", "Removed").bold(),
+            format!("{} files", orphan_count),
+        );
+    }
+
     // post_build hook
     if plugin_mgr.has_hook(plugin::HOOK_POST_BUILD) {
         let config_json = serde_json::to_string(&site_config)
@@ -1234,6 +1872,24 @@ pub fn build(minify: bool) -> Result<()> {
         }
     }
 
+    // Output manifest, after the plugins had a chance to change the outputs
+    let sources: BTreeMap<&Path, PathBuf> = timings
+        .pages
+        .iter()
+        .map(|page| (page.output.as_path(), Path::new("content").join(&page.source)))
+        .collect();
+    let manifest = Manifest::from_outputs(
+        &paths.public,
+        generated.into_iter().map(|path| {
+            let source = sources
+                .get(path.as_path())
+                .cloned()
+                .or_else(|| asset_source(&paths, &path, &js_bundles, fingerprints));
+            (path, source)
+        }),
+    );
+    manifest.write(&paths.public)?;
+
     println!();
     let total_ms = build_start.elapsed().as_millis();
     println!(
@@ -1269,5 +1925,43 @@ pub fn build(minify: bool) -> Result<()> {
         timings.print_summary(total_ms);
     }
 
+    // Machine-readable report
+    if let Some(options) = report {
+        let stats = cache.stats();
+        let mut build_report = BuildReport::new(started_at, total_ms);
+        build_report.phases = timings.phases();
+        build_report.pages = timings.pages;
+        build_report.plugins = plugin_timings
+            .into_iter()
+            .map(|(name, duration)| (name, duration.as_millis()))
+            .collect();
+        build_report.cache = CacheReport {
+            pages: stats.pages,
+            hits: stats.pages.saturating_sub(stats.converted),
+            misses: stats.converted,
+            unchanged: unchanged_count,
+        };
+        build_report.warnings = diagnostics;
+        build_report.files = manifest
+            .files
+            .into_iter()
+            .map(|(path, entry)| FileReport {
+                path,
+                size: entry.size,
+                hash: entry.hash,
+            })
+            .collect();
+
+        let path = options
+            .file
+            .unwrap_or_else(|| root_dir.join(report::DEFAULT_REPORT_FILE));
+        build_report.write(options.format, &path)?;
+        println!(
+            "{} Build report written to {}",
+            "✓".green().bold(),
+            path.display()
+        );
+    }
+
     Ok(())
 }
@@ -0,0 +1,121 @@
+use std::path::{Path, PathBuf};
+
+use clap::Subcommand;
+use colored::Colorize;
+use eyre::{bail, Result};
+
+use crate::{cache, fs};
+
+#[derive(Subcommand, Clone)]
+pub enum CacheCommands {
+    /// Show the location, size and hit ratio of the build cache
+    Info,
+    /// Remove the whole build cache
+    Clear,
+    /// Remove the cached pages of deleted content files
+    Prune,
+}
+
+pub fn handle(subcommand: &CacheCommands) -> Result<()> {
+    let site_root = find_site_root()?;
+    match subcommand {
+        CacheCommands::Info => show_info(&site_root),
+        CacheCommands::Clear => clear_cache(&site_root),
+        CacheCommands::Prune => prune_cache(&site_root),
+    }
+}
+
+fn find_site_root() -> Result<PathBuf> {
+    let Some(root) = fs::find_config_file()? else {
+        bail!(
+            "{}: not in a Norgolith site directory",
+            "Could not manage the cache".bold()
+        );
+    };
+    Ok(root.parent().unwrap().to_path_buf())
+}
+
+fn show_info(site_root: &Path) -> Result<()> {
+    let info = cache::info(site_root)?;
+
+    println!("{}", "Build cache".bold());
+    println!("   location:   {}", info.dir.display());
+    if !info.exists {
+        println!("   {}", "empty, run `lith build` to fill it".dimmed());
+        return Ok(());
+    }
+    println!("   size:       {}", format_size(info.size));
+    println!("   pages:      {}", info.entries);
+    println!("   images:     {}", info.images);
+    match info.last_build.as_ref().and_then(|stats| {
+        stats
+            .hit_ratio()
+            .map(|ratio| (ratio, stats.pages, stats.converted))
+    }) {
+        Some((ratio, pages, converted)) => println!(
+            "   hit ratio:  {:.0}% {}",
+            ratio * 100.0,
+            format!(
+                "({} of {} pages converted by the last build)",
+                converted, pages
+            )
+            .dimmed()
+        ),
+        None => println!("   hit ratio:  {}", "no build yet".dimmed()),
+    }
+    Ok(())
+}
+
+fn clear_cache(site_root: &Path) -> Result<()> {
+    match cache::clear(site_root)? {
+        Some(dir) => println!(
+            "{} Removed the build cache at {}",
+            "✓".green().bold(),
+            dir.display()
+        ),
+        None => println!("{}", "The build cache is already empty.".dimmed()),
+    }
+    Ok(())
+}
+
+fn prune_cache(site_root: &Path) -> Result<()> {
+    let removed = cache::prune(site_root)?;
+    if removed == 0 {
+        println!("{}", "No cached pages to prune.".dimmed());
+    } else {
+        println!(
+            "{} Pruned {} cached page(s) of deleted content",
+            "✓".green().bold(),
+            removed
+        );
+    }
+    Ok(())
+}
+
+/// Formats a size in bytes for humans, e.g. `1.5 MiB`
+fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} {}", bytes, UNITS[0])
+    } else {
+        format!("{:.1} {}", size, UNITS[unit])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_size() {
+        assert_eq!(format_size(512), "512 B");
+        assert_eq!(format_size(1536), "1.5 KiB");
+        assert_eq!(format_size(3 * 1024 * 1024), "3.0 MiB");
+    }
+}
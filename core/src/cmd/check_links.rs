@@ -0,0 +1,154 @@
+use std::{sync::Arc, time::Instant};
+
+use colored::Colorize;
+use eyre::{bail, Result, WrapErr};
+use rayon::prelude::*;
+use tracing::debug;
+use walkdir::WalkDir;
+
+use crate::{
+    cache::TemplateDeps,
+    config,
+    converter::{
+        diagnostics::{Diagnostic, DiagnosticKind},
+        shortcodes::Shortcodes,
+    },
+    fs, shared,
+};
+
+/// Checks every internal link of the site without building it.
+///
+/// Converts all the content pages against the site link index and reports links whose target
+/// file or heading does not exist, as well as link kinds that cannot be resolved.
+///
+/// # Arguments
+/// * `drafts` - Whether to check draft pages and allow linking to them
+///
+/// # Returns
+/// An error if the site configuration could not be loaded or any broken link was found.
+pub fn check_links(drafts: bool) -> Result<()> {
+    let Some(root) = fs::find_config_file()? else {
+        bail!(
+            "{}: not in a Norgolith site directory",
+            "Could not check links".bold()
+        );
+    };
+
+    let config_content = std::fs::read_to_string(&root).wrap_err("Failed to read config file")?;
+    let site_config: config::SiteConfig =
+        toml::from_str(&config_content).wrap_err("Failed to parse site configuration")?;
+    let root_dir = root.parent().unwrap();
+    let content_dir = root_dir.join("content");
+
+    println!("{} Checking links...", "→".cyan().bold());
+    let start = Instant::now();
+
+    let mut convert_options = shared::convert_options(&site_config, &site_config.root_url);
+    if let Some(macros) = shared::load_macros(root_dir, &site_config)? {
+        convert_options = convert_options.with_macros(macros);
+    }
+    // Links inside ranged shortcodes are checked too
+    let templates_dir = root_dir.join("templates");
+    let theme_templates_dir = root_dir.join("theme/templates");
+    let tera = shared::init_tera(&templates_dir.to_string_lossy(), &theme_templates_dir)?;
+    if let Some(shortcodes) = Shortcodes::load(&tera, &TemplateDeps::new(&tera)) {
+        convert_options = convert_options.with_shortcodes(Arc::new(shortcodes));
+    }
+    let links = shared::build_link_index(&content_dir, &convert_options, drafts);
+    debug!("Indexed {} pages", links.len());
+    let page_count = links.len();
+    let convert_options = convert_options.with_links(Arc::new(links));
+
+    let entries: Vec<_> = WalkDir::new(&content_dir)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.path().extension().is_some_and(|ext| ext == "norg"))
+        .collect();
+
+    let diagnostics: Vec<Diagnostic> = entries
+        .par_iter()
+        .filter_map(|entry| {
+            let rel_path = entry.path().strip_prefix(&content_dir).ok()?;
+            let content = std::fs::read_to_string(entry.path()).ok()?;
+            let metadata =
+                shared::extract_metadata_from_content(&content, rel_path, &site_config.root_url);
+            let is_draft = metadata
+                .get("draft")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false);
+            if is_draft && !drafts {
+                return None;
+            }
+
+            let (_, diagnostics) =
+                shared::load_metadata_from_content(&content, rel_path, &convert_options);
+            Some(diagnostics)
+        })
+        .flatten()
+        .filter(|diagnostic| {
+            matches!(
+                diagnostic.kind,
+                DiagnosticKind::BrokenLink | DiagnosticKind::UnsupportedLink
+            )
+        })
+        .collect();
+
+    if !diagnostics.is_empty() {
+        shared::print_diagnostics_report(&diagnostics);
+        bail!(
+            "{}: {} broken links found",
+            "Link check failed".bold(),
+            diagnostics.len()
+        );
+    }
+
+    println!(
+        "{} All links are valid ({} pages checked) {}",
+        "✓".green().bold(),
+        page_count,
+        shared::get_elapsed_time(start).dimmed()
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use serial_test::serial;
+    use tempfile::tempdir;
+
+    use super::*;
+
+    #[test]
+    #[serial]
+    fn test_check_links_reports_broken_links() -> Result<()> {
+        let dir = tempdir()?;
+        let root = dir.path();
+        std::fs::create_dir_all(root.join("content"))?;
+        std::fs::create_dir_all(root.join("templates"))?;
+        std::fs::write(
+            root.join("norgolith.toml"),
+            "rootUrl = \"http://localhost:3030\"\nlanguage = \"en-US\"\ntitle = \"Test\"\nauthor = \"Norgolith\"\n",
+        )?;
+        std::fs::write(
+            root.join("content/index.norg"),
+            "@document.meta\ntitle: Home\ndraft: false\n@end\n\n{:about:}[About] and {:missing:}[Missing]\n",
+        )?;
+        std::fs::write(
+            root.join("content/about.norg"),
+            "@document.meta\ntitle: About\ndraft: false\n@end\n\nHello\n",
+        )?;
+
+        let origin = std::env::current_dir()?;
+        std::env::set_current_dir(root)?;
+        let result = check_links(false);
+        std::fs::remove_file(root.join("content/index.norg"))?;
+        let fixed = check_links(false);
+        std::env::set_current_dir(origin)?;
+
+        let error = result.unwrap_err().to_string();
+        assert!(error.contains("1 broken links found"), "{}", error);
+        assert!(fixed.is_ok());
+
+        Ok(())
+    }
+}
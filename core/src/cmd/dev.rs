@@ -1,16 +1,16 @@
 use std::collections::HashMap;
 use std::convert::Infallible;
-use std::error::Error;
-use std::net::{IpAddr, Ipv4Addr, TcpListener as StdTcpListener};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, TcpListener as StdTcpListener};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::Duration;
 
 use chrono::Utc;
 use colored::Colorize;
-use eyre::{bail, eyre, Result};
+use eyre::{bail, eyre, Result, WrapErr};
 use futures_util::{SinkExt, Stream, StreamExt};
-use hyper::header::{CACHE_CONTROL, EXPIRES, PRAGMA};
+use hyper::header::{HeaderName, HeaderValue, CACHE_CONTROL, EXPIRES, LOCATION, PRAGMA};
+use hyper::server::accept::{self, Accept};
 use hyper::service::{make_service_fn, service_fn};
 use hyper::{header::CONTENT_TYPE, Body, Request, Response, Server, StatusCode};
 use notify::{RecommendedWatcher, RecursiveMode};
@@ -18,16 +18,39 @@ use notify_debouncer_full::{new_debouncer, DebounceEventResult, Debouncer, Recom
 use tera::{Context, Tera};
 use tokio::sync::broadcast;
 use tokio::{
-    net::{TcpListener, TcpStream},
+    io::{AsyncRead, AsyncWrite},
+    net::TcpListener,
     runtime::Handle,
     sync::RwLock,
 };
+use tokio_rustls::TlsAcceptor;
 use tokio_stream::wrappers::ReceiverStream;
 use tokio_tungstenite::accept_async;
 use tracing::{debug, error, info, instrument, warn};
 use walkdir::WalkDir;
 
-use crate::{config, fs, plugin, shared};
+use crate::{
+    assets::{
+        css::{self, CssCompiler},
+        js::{self, JsBundler},
+    },
+    cache::TemplateDeps,
+    config,
+    converter::{
+        diagnostics::Diagnostic,
+        highlight,
+        html::ConvertOptions,
+        images::ImagePipeline,
+        links::LinkIndex,
+        macros::Macros,
+        shortcodes::Shortcodes,
+    },
+    fs, plugin, shared, tls,
+};
+
+use super::error_overlay::ErrorReport;
+use super::proxy::Proxy;
+use super::redirects::{self, Redirect};
 
 /// Represents the directory structure of a Norgolith site.
 ///
@@ -42,6 +65,7 @@ struct SitePaths {
     templates: PathBuf,
     theme_assets: PathBuf,
     theme_templates: PathBuf,
+    macros: PathBuf,
 }
 
 impl SitePaths {
@@ -66,6 +90,7 @@ impl SitePaths {
             theme_assets: root.join("theme/assets"),
             templates: root.join("templates"),
             theme_templates: root.join("theme/templates"),
+            macros: root.join(crate::converter::macros::MACROS_DIR),
         };
         debug!(?paths, "Configured site directories");
         paths
@@ -79,7 +104,8 @@ impl SitePaths {
 /// It is used to manage the server's runtime state and facilitate communication
 /// between components.
 struct ServerState {
-    reload_tx: Arc<broadcast::Sender<()>>,
+    /// Sends the reload messages to the LiveReload clients
+    reload_tx: Arc<broadcast::Sender<ReloadMessage>>,
     tera: Arc<RwLock<Tera>>,
     config: Arc<RwLock<config::SiteConfig>>,
     paths: SitePaths,
@@ -89,9 +115,107 @@ struct ServerState {
     cache: Arc<RwLock<crate::cache::BuildCache>>,
     rendered_pages: Arc<RwLock<HashMap<String, String>>>,
     plugin_mgr: Arc<plugin::PluginManager>,
+    links: Arc<RwLock<Arc<LinkIndex>>>,
+    macros: Arc<RwLock<Option<Arc<Macros>>>>,
+    shortcodes: Arc<RwLock<Option<Arc<Shortcodes>>>>,
+    images: Arc<RwLock<Option<Arc<ImagePipeline>>>>,
+    /// Forwards the `[dev.proxy]` path prefixes to their upstream servers
+    proxy: Arc<RwLock<Arc<Proxy>>>,
+    /// Redirects of the config and of the page aliases, sorted by path
+    redirects: Arc<RwLock<Arc<Vec<Redirect>>>>,
+    /// The error shown by the clients until the next successful rebuild, sent to the clients
+    /// connecting in the meantime
+    last_error: std::sync::Mutex<Option<Arc<ErrorReport>>>,
 }
 
 impl ServerState {
+    /// Returns the conversion options for the given config, using the current link index,
+    /// macros, shortcodes and image pipeline.
+    async fn convert_options(&self, config: &config::SiteConfig) -> ConvertOptions {
+        let links = self.links.read().await.clone();
+        let mut options = shared::convert_options(config, &self.routes_url).with_links(links);
+        if let Some(macros) = self.macros.read().await.clone() {
+            options = options.with_macros(macros);
+        }
+        if let Some(shortcodes) = self.shortcodes.read().await.clone() {
+            options = options.with_shortcodes(shortcodes);
+        }
+        if let Some(images) = self.images.read().await.clone() {
+            options = options.with_images(images);
+        }
+        options
+    }
+
+    /// Tells the cache about the current macros, shortcodes and image settings, so pages depending on them
+    /// are converted again.
+    async fn refresh_site_inputs(&self) {
+        let config = self.config.read().await.clone();
+        let fingerprint = self.convert_options(&config).await.fingerprint();
+        self.cache.write().await.set_site_inputs(fingerprint);
+    }
+
+    /// Rebuilds the link index from the content directory.
+    ///
+    /// Called when content changes, as pages and headings may have been added, renamed or
+    /// removed. Cached pages linking to them are converted again as their link targets do not
+    /// match the new index anymore, and the redirects of the page aliases are collected again.
+    #[instrument(level = "debug", skip(self))]
+    async fn reload_links(&self) {
+        let config = self.config.read().await.clone();
+        let options = shared::convert_options(&config, &self.routes_url);
+        let links = shared::build_link_index(&self.paths.content, &options, self.build_drafts);
+        debug!("Link index rebuilt with {} pages", links.len());
+        *self.redirects.write().await = Arc::new(redirects::collect(&config, &links));
+        *self.links.write().await = Arc::new(links);
+    }
+
+    /// Reloads the user-defined macros from the config and the `macros` directory.
+    #[instrument(level = "debug", skip(self))]
+    async fn reload_macros(&self) -> Result<()> {
+        debug!("Reloading macros");
+        let root_dir = self.paths.config_file.parent().unwrap_or(Path::new("."));
+        let macros = shared::load_macros(root_dir, &*self.config.read().await)?;
+        *self.macros.write().await = macros;
+        self.refresh_site_inputs().await;
+        info!("Macros reloaded successfully");
+        Ok(())
+    }
+
+    /// Recreates the responsive image pipeline.
+    ///
+    /// Called when the config or the assets change.
+    ///
+    /// # Returns
+    /// * `Result<bool>` - Whether the images or their configuration changed, in which case the
+    ///   pages using them must be rendered again.
+    #[instrument(level = "debug", skip(self))]
+    async fn reload_images(&self) -> Result<bool> {
+        let root_dir = self.paths.config_file.parent().unwrap_or(Path::new("."));
+        let images = shared::load_images(root_dir, &*self.config.read().await)?;
+        let current = self.images.read().await.clone();
+        // The fingerprint only covers the settings, replaced images are found by content
+        let changed = current.as_ref().map(|i| i.fingerprint())
+            != images.as_ref().map(|i| i.fingerprint())
+            || current.is_some_and(|i| i.is_outdated());
+        if changed {
+            debug!("Image pipeline reloaded");
+            *self.images.write().await = images;
+            self.refresh_site_inputs().await;
+        }
+        Ok(changed)
+    }
+
+    /// Recreates the proxy of the `[dev.proxy]` routes.
+    ///
+    /// Called when the config changes.
+    #[instrument(level = "debug", skip(self))]
+    async fn reload_proxy(&self) -> Result<()> {
+        let config = self.config.read().await.clone();
+        let routes = config.dev.map(|dev| dev.proxy).unwrap_or_default();
+        *self.proxy.write().await = Arc::new(Proxy::new(&routes)?);
+        Ok(())
+    }
+
     /// Reloads the Tera templates.
     ///
     /// This function triggers a full reload of the Tera templates. It is called when
@@ -111,6 +235,11 @@ impl ServerState {
             self.paths.templates.to_str().unwrap(),
             &self.paths.theme_templates,
         )?;
+        // Shortcode templates are converted into the pages, so they must follow the new templates
+        let shortcodes = Shortcodes::load(&new_tera, &TemplateDeps::new(&new_tera));
+        *self.shortcodes.write().await = shortcodes.map(Arc::new);
+        self.refresh_site_inputs().await;
+
         let mut tera = self.tera.write().await;
         *tera = new_tera;
 
@@ -119,7 +248,7 @@ impl ServerState {
         debug!("There are {} templates loaded", templates.len());
 
         // Reload the page
-        self.send_reload()?;
+        self.send_reload(ReloadMessage::Full)?;
         Ok(())
     }
 
@@ -127,26 +256,36 @@ impl ServerState {
     async fn reload_config(&self) -> Result<()> {
         debug!("Reloading config");
         let config_content = tokio::fs::read_to_string(&self.paths.config_file).await?;
-        let new_config: config::SiteConfig = toml::from_str(&config_content)?;
-
-        // Re-collect posts, collections config may have changed
-        let new_posts = shared::collect_all_posts_metadata(
-            &self.paths.content,
-            &self.routes_url,
-            &new_config.collections,
-        )?;
+        let new_config: config::SiteConfig =
+            toml::from_str(&config_content).wrap_err("Failed to parse norgolith.toml")?;
+        let collections = new_config.collections.clone();
 
         {
             let mut config = self.config.write().await;
             *config = new_config;
         }
+        // Inline macros, images settings and the heading slug strategy may have changed
+        self.reload_macros().await?;
+        self.reload_images().await?;
+        self.reload_links().await;
+        self.reload_proxy().await?;
+
+        // Re-collect posts, collections config may have changed
+        let config = self.config.read().await.clone();
+        let convert_options = self.convert_options(&config).await;
+        let new_posts = shared::collect_all_posts_metadata(
+            &self.paths.content,
+            &convert_options,
+            &collections,
+            Some(&mut *self.cache.write().await),
+        )?;
         {
             let mut posts = self.posts.write().await;
             *posts = new_posts;
         }
 
         info!("Config reloaded successfully");
-        self.send_reload()?;
+        self.send_reload(ReloadMessage::Full)?;
         Ok(())
     }
 
@@ -154,17 +293,23 @@ impl ServerState {
     ///
     /// Called when content, templates, or config change. Re-renders all pages
     /// and replaces the cache wholesale.
+    ///
+    /// # Returns
+    /// * `Option<Vec<String>>` - The URL paths of the pages whose output changed, `None` if
+    ///   the pages could not be rendered.
     #[instrument(level = "debug", skip(self))]
-    async fn rebuild_rendered_pages(&self) {
+    async fn rebuild_rendered_pages(&self) -> Option<Vec<String>> {
         let tera = self.tera.read().await;
         let config = self.config.read().await.clone();
         let posts = self.posts.read().await.clone();
         let cache = self.cache.read().await;
+        let convert_options = self.convert_options(&config).await;
 
         match render_all_pages(
             &tera,
             &self.paths,
             &config,
+            &convert_options,
             &self.routes_url,
             &posts,
             &cache,
@@ -172,32 +317,48 @@ impl ServerState {
         ) {
             Ok(new_pages) => {
                 let mut pages = self.rendered_pages.write().await;
+                let changed = changed_pages(&pages, &new_pages);
                 *pages = new_pages;
                 info!("Rendered pages cache rebuilt");
+                Some(changed)
+            }
+            Err(e) => {
+                error!("Failed to rebuild rendered pages: {:#}", e);
+                self.report_error(&e);
+                None
             }
-            Err(e) => error!("Failed to rebuild rendered pages: {}", e),
         }
     }
 
     /// Sends a reload signal to connected WebSocket clients.
     ///
-    /// This function sends a signal to all connected WebSocket clients to trigger
-    /// a page reload. It is used when changes to assets, templates, or content are
-    /// detected. If the signal fails to send, an error is returned.
+    /// This function sends a signal to all connected WebSocket clients to swap a
+    /// stylesheet, reload the tabs viewing a page or reload every tab. It is used when
+    /// changes to assets, templates, or content are detected. If the signal fails to
+    /// send, an error is returned.
+    ///
+    /// # Arguments
+    /// * `message` - The reload to perform.
     ///
     /// # Returns
     /// * `Result<()>` - `Ok(())` if the signal is sent successfully, otherwise
     ///   an error is returned.
     #[instrument(skip(self))]
-    fn send_reload(&self) -> Result<()> {
+    fn send_reload(&self, message: ReloadMessage) -> Result<()> {
         debug!("Sending reload signal to clients");
+        // Reloads follow successful rebuilds, dismissing the last error
+        if !matches!(message, ReloadMessage::Error(_)) {
+            if let Ok(mut last_error) = self.last_error.lock() {
+                *last_error = None;
+            }
+        }
         if self.reload_tx.receiver_count() == 0 {
             debug!("No active receivers, skipping reload signal");
             return Ok(());
         }
 
         self.reload_tx
-            .send(())
+            .send(message)
             .map(|_| {
                 debug!(
                     "Reload signal sent to {} clients",
@@ -206,6 +367,34 @@ impl ServerState {
             })
             .map_err(|e| eyre!("Failed to send reload signal: {}", e))
     }
+
+    /// Shows an error to the connected WebSocket clients until the next successful rebuild.
+    ///
+    /// # Arguments
+    /// * `error` - The error to report, its file, position and template are looked up in the
+    ///   site directories.
+    fn report_error(&self, error: &eyre::Report) {
+        let root = self
+            .paths
+            .config_file
+            .parent()
+            .unwrap_or_else(|| Path::new("."));
+        let report = Arc::new(ErrorReport::new(
+            error,
+            root,
+            &[
+                &self.paths.templates,
+                &self.paths.theme_templates,
+                &self.paths.content,
+            ],
+        ));
+        if let Ok(mut last_error) = self.last_error.lock() {
+            *last_error = Some(Arc::clone(&report));
+        }
+        if let Err(e) = self.send_reload(ReloadMessage::Error(report)) {
+            error!("Error report signal error: {}", e);
+        }
+    }
 }
 
 /// Represents actions to be taken based on file changes.
@@ -217,20 +406,82 @@ impl ServerState {
 struct FileActions {
     reload_templates: bool,
     reload_assets: bool,
+    /// URL paths of the changed stylesheets, injected without reloading the pages
+    reload_stylesheets: Vec<String>,
     reload_content: bool,
     reload_config: bool,
+    reload_macros: bool,
 }
 
-/// LiveReload script to be injected into HTML pages.
-///
-/// The LiveReload script version in use is v4.0.2
+/// LiveReload client script to be injected into HTML pages.
 const LIVE_RELOAD_SCRIPT: &str = include_str!("../resources/assets/livereload.js");
 /// Port for the LiveReload WebSocket server
 const LIVE_RELOAD_PORT: u16 = 35729;
 /// WebSocket hello message for LiveReload protocol
 const WS_HELLO_MESSAGE: &str = r#"{"command":"hello","protocols":["http://livereload.com/protocols/official-7"],"serverName":"norgolith"}"#;
-/// WebSocket reload message for LiveReload protocol
-const WS_RELOAD_MESSAGE: &str = r#"{"command":"reload","path":"/"}"#;
+
+/// Reload messages sent to the LiveReload clients
+#[derive(Debug, Clone, PartialEq)]
+enum ReloadMessage {
+    /// Reloads every open page, e.g. after a template or config change
+    Full,
+    /// Reloads the pages viewing a permalink, e.g. `/posts/hello`
+    Page(String),
+    /// Swaps a stylesheet in place, keeping the page scroll position and state. The URL path
+    /// of the stylesheet, e.g. `/assets/css/style.css`
+    Css(String),
+    /// Shows an error overlay until the next reload
+    Error(Arc<ErrorReport>),
+}
+
+impl ReloadMessage {
+    /// Serializes the message as a LiveReload protocol `reload` command, other LiveReload
+    /// clients ignore its `kind` and reload the page or the stylesheets. Errors are sent as an
+    /// `error` command, ignored by other clients
+    fn to_json(&self) -> String {
+        let (kind, path) = match self {
+            ReloadMessage::Full => ("full", "/"),
+            ReloadMessage::Page(path) => ("page", path.as_str()),
+            ReloadMessage::Css(path) => ("css", path.as_str()),
+            ReloadMessage::Error(report) => {
+                return serde_json::json!({
+                    "command": "error",
+                    "error": report,
+                })
+                .to_string();
+            }
+        };
+        serde_json::json!({
+            "command": "reload",
+            "kind": kind,
+            "path": path,
+            "liveCSS": matches!(self, ReloadMessage::Css(_)),
+        })
+        .to_string()
+    }
+}
+
+/// Returns the URL paths of the pages added, removed or changed between two renders
+fn changed_pages(old: &HashMap<String, String>, new: &HashMap<String, String>) -> Vec<String> {
+    let mut changed: Vec<String> = new
+        .iter()
+        .filter(|(url_path, body)| old.get(*url_path) != Some(*body))
+        .map(|(url_path, _)| url_path.clone())
+        .chain(old.keys().filter(|url_path| !new.contains_key(*url_path)).cloned())
+        .collect();
+    changed.sort();
+    changed
+}
+
+/// Returns the permalink of a rendered page URL path, e.g. `/posts` for `/posts/index`
+fn page_permalink(url_path: &str) -> String {
+    match url_path.strip_suffix("/index") {
+        Some("") => "/",
+        Some(dir) => dir,
+        None => url_path,
+    }
+    .to_string()
+}
 
 /// Checks if a file system event is relevant for triggering a reload.
 ///
@@ -370,18 +621,22 @@ async fn process_debounced_events(result: DebounceEventResult, state: Arc<Server
 #[instrument(level = "debug", skip(actions, state))]
 async fn execute_actions(actions: FileActions, state: Arc<ServerState>) {
     debug!(
-        "Executing actions: templates={}, assets={}, reload={}, config={}",
+        "Executing actions: templates={}, assets={}, reload={}, config={}, macros={}",
         actions.reload_templates,
         actions.reload_assets,
         actions.reload_content,
         actions.reload_config,
+        actions.reload_macros,
     );
 
     // Config reload supersedes content/template/asset reloads since it re-collects posts too
     if actions.reload_config {
         match state.reload_config().await {
             Ok(_) => {}
-            Err(e) => error!("Config reload failed: {}", e),
+            Err(e) => {
+                error!("Config reload failed: {:#}", e);
+                state.report_error(&e);
+            }
         }
         state.rebuild_rendered_pages().await;
         return;
@@ -389,42 +644,98 @@ async fn execute_actions(actions: FileActions, state: Arc<ServerState>) {
 
     // Handle asset reloads
     if actions.reload_assets {
-        if let Err(e) = state.send_reload() {
+        match state.reload_images().await {
+            // Pages embed the size and variants of their images
+            Ok(true) => {
+                state.rebuild_rendered_pages().await;
+            }
+            Ok(false) => {}
+            Err(e) => {
+                error!("Image pipeline reload failed: {:#}", e);
+                state.report_error(&e);
+            }
+        }
+        if let Err(e) = state.send_reload(ReloadMessage::Full) {
             error!("Asset reload error: {}", e);
         }
+    } else {
+        for path in &actions.reload_stylesheets {
+            if let Err(e) = state.send_reload(ReloadMessage::Css(path.clone())) {
+                error!("Stylesheet reload error: {}", e);
+            }
+        }
     }
 
     // Handle template reloads
     if actions.reload_templates {
         match state.reload_templates().await {
             Ok(_) => {
-                state.rebuild_rendered_pages().await;
-                if let Err(e) = state.send_reload() {
-                    error!("Template reload signal error: {}", e);
+                if state.rebuild_rendered_pages().await.is_some() {
+                    if let Err(e) = state.send_reload(ReloadMessage::Full) {
+                        error!("Template reload signal error: {}", e);
+                    }
+                }
+            }
+            Err(e) => {
+                error!("Template reload failed: {:#}", e);
+                state.report_error(&e);
+            }
+        }
+    }
+
+    if actions.reload_macros {
+        match state.reload_macros().await {
+            Ok(_) => {
+                if state.rebuild_rendered_pages().await.is_some() {
+                    if let Err(e) = state.send_reload(ReloadMessage::Full) {
+                        error!("Macro reload signal error: {}", e);
+                    }
                 }
             }
-            Err(e) => error!("Template reload failed: {}", e),
+            Err(e) => {
+                error!("Macro reload failed: {:#}", e);
+                state.report_error(&e);
+            }
         }
     }
 
     if actions.reload_content {
-        let collections = state.config.read().await.collections.clone();
-        match shared::collect_all_posts_metadata(
+        state.reload_links().await;
+        let config = state.config.read().await.clone();
+        let convert_options = state.convert_options(&config).await;
+        let new_posts = shared::collect_all_posts_metadata(
             &state.paths.content,
-            &state.routes_url,
-            &collections,
-        ) {
+            &convert_options,
+            &config.collections,
+            Some(&mut *state.cache.write().await),
+        );
+        match new_posts {
             Ok(new_posts) => {
                 let mut posts_lock = state.posts.write().await;
                 *posts_lock = new_posts;
             }
-            Err(e) => error!("Failed to update pages metadata: {}", e),
+            Err(e) => {
+                error!("Failed to update pages metadata: {:#}", e);
+                state.report_error(&e);
+                return;
+            }
         }
 
-        state.rebuild_rendered_pages().await;
-
-        if let Err(e) = state.send_reload() {
-            error!("Reload signal error: {}", e);
+        // Only the tabs viewing the changed pages are reloaded. Changes with no rendered
+        // output (e.g. images in the content directory) reload every tab
+        let messages = match state.rebuild_rendered_pages().await {
+            Some(changed) if !changed.is_empty() => changed
+                .iter()
+                .map(|url_path| ReloadMessage::Page(page_permalink(url_path)))
+                .collect(),
+            Some(_) => vec![ReloadMessage::Full],
+            // The error is shown instead
+            None => Vec::new(),
+        };
+        for message in messages {
+            if let Err(e) = state.send_reload(message) {
+                error!("Reload signal error: {}", e);
+            }
         }
     }
 }
@@ -541,6 +852,14 @@ async fn handle_single_event(
         return;
     }
 
+    if path.starts_with(&state.paths.macros) {
+        if let Ok(rel) = path.strip_prefix(&state.paths.macros) {
+            info!("Macro modified: {}", rel.display());
+        }
+        actions.reload_macros = true;
+        return;
+    }
+
     if is_template_change(event).await {
         if let Ok(rel) = path.strip_prefix(&state.paths.theme_templates) {
             info!("Template modified: {}", rel.display());
@@ -552,12 +871,19 @@ async fn handle_single_event(
     }
 
     if is_asset_change(event).await {
-        if let Ok(rel) = path.strip_prefix(&state.paths.theme_assets) {
-            info!("Asset modified: {}", rel.display());
-            actions.reload_assets = true;
-        } else if let Ok(rel) = path.strip_prefix(&state.paths.assets) {
+        let rel = path
+            .strip_prefix(&state.paths.theme_assets)
+            .or_else(|_| path.strip_prefix(&state.paths.assets));
+        if let Ok(rel) = rel {
             info!("Asset modified: {}", rel.display());
-            actions.reload_assets = true;
+            if css::is_stylesheet(rel) || css::is_partial(rel) {
+                // Partials match no stylesheet, so clients reload all of them
+                actions
+                    .reload_stylesheets
+                    .push(format!("/assets/{}", css::output_path(rel).display()));
+            } else {
+                actions.reload_assets = true;
+            }
         }
     }
 
@@ -572,6 +898,44 @@ async fn handle_single_event(
     }
 }
 
+/// Serves the stylesheet of the `builtin` syntax highlighter.
+///
+/// The stylesheet is generated on the fly from the configured theme, mirroring the file that
+/// `lith build` writes to the public directory. Falls back to a regular asset lookup when the
+/// builtin engine is not in use.
+///
+/// # Arguments
+/// * `request_path` - The path of the requested stylesheet.
+/// * `state` - The shared server state.
+#[instrument(skip(request_path, state))]
+async fn handle_highlight_css(
+    request_path: &str,
+    state: &Arc<ServerState>,
+) -> Result<Response<Body>> {
+    let highlighter = state.config.read().await.highlighter.clone();
+    let Some(highlighter) = highlighter
+        .filter(|h| h.is_builtin() && h.style == config::HighlightStyle::Classes)
+    else {
+        // Not generated, so it can only be a regular asset
+        return handle_asset(request_path, &state.paths, state).await;
+    };
+
+    let theme = highlighter
+        .theme
+        .as_deref()
+        .unwrap_or(highlight::DEFAULT_THEME);
+    Ok(Response::builder()
+        .header(CONTENT_TYPE, "text/css")
+        .status(StatusCode::OK)
+        .header(
+            CACHE_CONTROL,
+            "no-store, no-cache, must-revalidate, proxy-revalidate",
+        )
+        .header(PRAGMA, "no-cache")
+        .header(EXPIRES, 0)
+        .body(Body::from(highlight::theme_css(theme)?))?)
+}
+
 /// Handles requests for static assets.
 ///
 /// This function serves static assets from the assets directory or the theme assets directory
@@ -594,26 +958,42 @@ async fn handle_asset(
     let asset_path = request_path.trim_start_matches("/assets/");
     debug!(path = %asset_path, "Handling asset request");
 
+    // Stylesheets are compiled and JavaScript entry points bundled on every request
+    let compiled = match read_stylesheet(asset_path, state).await? {
+        Some(stylesheet) => Some(stylesheet),
+        None => read_bundle(asset_path, state).await?,
+    };
+
     let site_path = paths.assets.join(asset_path);
 
     debug!(site_assets = %site_path.display(), "Checking site assets path");
-    let (content, mime_type) = match read_asset(&site_path).await {
-        Ok(asset) => {
-            debug!("Asset found in site directory");
-            asset
-        }
-        Err(_) => {
-            // Fallback to theme assets
-            debug!("Asset not found in site directory, checking theme assets");
-            let theme_path = paths.theme_assets.join(asset_path);
-            match read_asset(&theme_path).await {
-                Ok(asset) => {
-                    debug!("Asset found in theme directory");
-                    asset
-                }
-                Err(_) => {
-                    error!(asset_path = %request_path, "Asset not found in site or theme directories");
-                    return Ok(handle_not_found(state));
+    let (content, mime_type) = if let Some(compiled) = compiled {
+        compiled
+    } else {
+        match read_asset(&site_path).await {
+            Ok(asset) => {
+                debug!("Asset found in site directory");
+                asset
+            }
+            Err(_) => {
+                // Fallback to theme assets
+                debug!("Asset not found in site directory, checking theme assets");
+                let theme_path = paths.theme_assets.join(asset_path);
+                match read_asset(&theme_path).await {
+                    Ok(asset) => {
+                        debug!("Asset found in theme directory");
+                        asset
+                    }
+                    Err(_) => match read_image_variant(request_path, state).await {
+                        Some(asset) => {
+                            debug!("Asset found in image variants");
+                            asset
+                        }
+                        None => {
+                            error!(asset_path = %request_path, "Asset not found in site or theme directories");
+                            return Ok(handle_not_found(state));
+                        }
+                    },
                 }
             }
         }
@@ -630,6 +1010,134 @@ async fn handle_asset(
         .body(Body::from(content))?)
 }
 
+/// Compiles a stylesheet of the site (bundling its imports, compiling SCSS), or returns its
+/// source map for `.css.map` requests. The invalid rules left out of the stylesheet are shown
+/// in the error overlay.
+///
+/// # Arguments
+/// * `asset_path` - The requested path, relative to the assets directory.
+/// * `state` - The shared server state.
+///
+/// # Returns
+/// * `Result<Option<(Vec<u8>, String)>>` - The content and MIME type, `None` when the request
+///   is not a stylesheet of the site.
+async fn read_stylesheet(
+    asset_path: &str,
+    state: &Arc<ServerState>,
+) -> Result<Option<(Vec<u8>, String)>> {
+    let (css_path, is_map) = match asset_path.strip_suffix(".map") {
+        Some(css_path) => (css_path, true),
+        None => (asset_path, false),
+    };
+    if !css_path.ends_with(".css") {
+        return Ok(None);
+    }
+    let Some((assets_dir, rel_path)) = css::find_source(
+        &[&state.paths.assets, &state.paths.theme_assets],
+        Path::new(css_path),
+    ) else {
+        return Ok(None);
+    };
+
+    let compiler = CssCompiler::new(state.config.read().await.assets.as_ref(), false, true)?;
+    let assets_dir = assets_dir.to_path_buf();
+    let compiled = tokio::task::spawn_blocking(move || compiler.compile(&assets_dir, &rel_path))
+        .await??;
+    if is_map {
+        return Ok(compiled
+            .map
+            .map(|map| (map.into_bytes(), "application/json".to_string())));
+    }
+
+    if let Some(first) = compiled.warnings.first() {
+        for warning in &compiled.warnings {
+            warn!("{} {}", "Ignored invalid CSS:".yellow().bold(), warning);
+        }
+        // The stylesheet is still served, without the invalid rules
+        state.report_error(&eyre!("{}", first).wrap_err(format!(
+            "{} {} ({} issues)",
+            "Ignored invalid CSS in".bold(),
+            css_path,
+            compiled.warnings.len()
+        )));
+    }
+
+    let mut code = compiled.code;
+    if compiled.map.is_some() {
+        let file_name = Path::new(css_path)
+            .file_name()
+            .map(|name| name.to_string_lossy())
+            .unwrap_or_default();
+        code.push_str(&format!("\n/*# sourceMappingURL={}.map */\n", file_name));
+    }
+    Ok(Some((code.into_bytes(), "text/css".to_string())))
+}
+
+/// Bundles a JavaScript entry point of the site, unminified.
+///
+/// # Arguments
+/// * `asset_path` - The requested path, relative to the assets directory.
+/// * `state` - The shared server state.
+///
+/// # Returns
+/// * `Result<Option<(Vec<u8>, String)>>` - The content and MIME type, `None` when the request
+///   is not the output of an entry point.
+async fn read_bundle(
+    asset_path: &str,
+    state: &Arc<ServerState>,
+) -> Result<Option<(Vec<u8>, String)>> {
+    let entry = match state.config.read().await.assets.as_ref() {
+        Some(assets) => js::find_output_entry(
+            &[&state.paths.assets, &state.paths.theme_assets],
+            &assets.entries,
+            Path::new(asset_path),
+        ),
+        None => None,
+    };
+    let Some(entry) = entry else {
+        return Ok(None);
+    };
+
+    let bundle =
+        tokio::task::spawn_blocking(move || JsBundler::new(false).bundle(&entry)).await??;
+    Ok(Some((
+        bundle.code.into_bytes(),
+        "text/javascript".to_string(),
+    )))
+}
+
+/// Reads a variant generated by the responsive image pipeline, generating it if needed.
+async fn read_image_variant(
+    request_path: &str,
+    state: &Arc<ServerState>,
+) -> Option<(Vec<u8>, String)> {
+    let images = state.images.read().await.clone()?;
+    let url = request_path.to_string();
+    // Resizing and encoding images is CPU bound
+    let file = tokio::task::spawn_blocking(move || images.variant_file(&url))
+        .await
+        .ok()??;
+    read_asset(&file).await.ok()
+}
+
+/// Builds the fallback 500 page, used when the site has no `500.html` template.
+///
+/// # Arguments
+/// * `message` - The error message, with its causes.
+fn internal_error_page(message: &str) -> Response<Body> {
+    let mut body = format!(
+        "<!DOCTYPE html><html><head><title>500 Internal Server Error</title></head><body><h1>500 Internal Server Error</h1><pre>{}</pre></body></html>",
+        html_escape::encode_text(message)
+    );
+    // Reloaded once the error is fixed
+    inject_livereload_script(&mut body);
+    Response::builder()
+        .status(StatusCode::INTERNAL_SERVER_ERROR)
+        .header(CONTENT_TYPE, "text/html; charset=utf-8")
+        .body(Body::from(body))
+        .unwrap()
+}
+
 fn handle_not_found(state: &ServerState) -> Response<Body> {
     let tera = state.tera.try_read().ok();
     let config = state.config.try_read().ok();
@@ -743,6 +1251,19 @@ async fn handle_content(request_path: &str, state: Arc<ServerState>) -> Result<R
     }
 }
 
+/// Logs the conversion diagnostics of a freshly converted page as warnings.
+fn warn_diagnostics(diagnostics: &[Diagnostic]) {
+    for diagnostic in diagnostics {
+        warn!(
+            "{} {}:{} {}",
+            format!("{}:", diagnostic.kind).yellow().bold(),
+            diagnostic.file.display(),
+            diagnostic.span,
+            diagnostic.node
+        );
+    }
+}
+
 /// Handles requests for HTML content, rendering it with Tera templates.
 ///
 /// This function renders HTML content using Tera templates and injects the LiveReload script
@@ -790,6 +1311,8 @@ async fn handle_norg_content(path: PathBuf, state: Arc<ServerState>) -> Result<R
         return Ok(handle_not_found(&state));
     }
 
+    let config = state.config.read().await.clone();
+    let convert_options = state.convert_options(&config).await;
     let cache_key = rel_path.with_extension("");
     let metadata = {
         let cache_guard = state.cache.read().await;
@@ -798,18 +1321,24 @@ async fn handle_norg_content(path: PathBuf, state: Arc<ServerState>) -> Result<R
     let metadata = if let Some(cached) = metadata {
         match serde_json::from_value(cached.clone()) {
             Ok(md) => md,
-            Err(_) => shared::load_metadata_from_content(&content, &rel_path, &state.routes_url),
+            Err(_) => {
+                let (md, diagnostics) =
+                    shared::load_metadata_from_content(&content, &rel_path, &convert_options);
+                warn_diagnostics(&diagnostics);
+                md
+            }
         }
     } else {
-        let md = shared::load_metadata_from_content(&content, &rel_path, &state.routes_url);
+        let (md, diagnostics) =
+            shared::load_metadata_from_content(&content, &rel_path, &convert_options);
+        warn_diagnostics(&diagnostics);
         if let Ok(json_val) = serde_json::to_value(&md) {
             let mut cache_guard = state.cache.write().await;
-            cache_guard.insert(&cache_key, &content, json_val);
+            cache_guard.insert(&cache_key, &content, json_val, diagnostics);
         }
         md
     };
 
-    let config = state.config.read().await.clone();
     let posts = state.posts.read().await.clone();
     let collections = shared::precompute_collection_subsets(&posts, &config);
     let shared_context = shared::build_shared_context(&posts, &config, &collections);
@@ -833,10 +1362,13 @@ async fn handle_norg_content(path: PathBuf, state: Arc<ServerState>) -> Result<R
 /// to the client upon connection and listens for reload signals to send reload messages.
 ///
 /// # Arguments
-/// * `stream` - The TCP stream for the WebSocket connection.
-/// * `reload_tx` - The broadcast sender for reload signals.
-#[instrument(skip(stream, reload_tx))]
-async fn handle_websocket(stream: TcpStream, reload_tx: Arc<broadcast::Sender<()>>) {
+/// * `stream` - The TCP (or TLS) stream for the WebSocket connection.
+/// * `state` - The shared server state, holding the reload signals sender and the last error.
+#[instrument(skip(stream, state))]
+async fn handle_websocket<S>(stream: S, state: Arc<ServerState>)
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
     let mut ws_stream = match accept_async(stream).await {
         Ok(ws) => {
             debug!("New WebSocket connection");
@@ -848,7 +1380,7 @@ async fn handle_websocket(stream: TcpStream, reload_tx: Arc<broadcast::Sender<()
         }
     };
 
-    let mut rx = reload_tx.subscribe();
+    let mut rx = state.reload_tx.subscribe();
     if let Err(e) = ws_stream
         .send(tokio_tungstenite::tungstenite::Message::Text(
             WS_HELLO_MESSAGE.into(),
@@ -859,10 +1391,25 @@ async fn handle_websocket(stream: TcpStream, reload_tx: Arc<broadcast::Sender<()
         return;
     }
 
+    // Pages loaded after an error show it too, e.g. the 500 page
+    let last_error = state.last_error.lock().ok().and_then(|e| e.clone());
+    if let Some(report) = last_error {
+        let message = ReloadMessage::Error(report).to_json();
+        if let Err(e) = ws_stream
+            .send(tokio_tungstenite::tungstenite::Message::Text(message.into()))
+            .await
+        {
+            error!("Failed to send error message: {}", e);
+            return;
+        }
+    }
+
     loop {
         tokio::select! {
-            _ = rx.recv() => {
-                if let Err(e) = ws_stream.send(tokio_tungstenite::tungstenite::Message::Text(WS_RELOAD_MESSAGE.into())).await {
+            message = rx.recv() => {
+                // Lagging clients missed some messages, so they reload everything
+                let message = message.unwrap_or(ReloadMessage::Full);
+                if let Err(e) = ws_stream.send(tokio_tungstenite::tungstenite::Message::Text(message.to_json().into())).await {
                     error!("WebSocket send error: {}", e);
                     break;
                 }
@@ -907,18 +1454,9 @@ async fn handle_category_index(state: &Arc<ServerState>) -> Result<Response<Body
     context.insert("categories", &categories.into_iter().collect::<Vec<_>>());
 
     let tera = state.tera.read().await;
-    let mut body = tera.render("categories.html", &context).map_err(|e| {
-        if e.source().is_some() {
-            let internal_err = e.source().unwrap();
-            eyre!(
-                "{}: {}",
-                "Failed to render 'categories.html' template".bold(),
-                internal_err
-            )
-        } else {
-            eyre!("{}", "Failed to render 'categories.html' template".bold())
-        }
-    })?;
+    let mut body = tera
+        .render("categories.html", &context)
+        .wrap_err_with(|| "Failed to render 'categories.html' template".bold())?;
     body = body.replace(
         &config.root_url.replace("://", ":&#x2F;&#x2F;"),
         &state.routes_url,
@@ -972,18 +1510,9 @@ async fn handle_category(path: &str, state: &Arc<ServerState>) -> Result<Respons
     );
 
     let tera = state.tera.read().await;
-    let mut body = tera.render("category.html", &context).map_err(|e| {
-        if e.source().is_some() {
-            let internal_err = e.source().unwrap();
-            eyre!(
-                "{}: {}",
-                "Failed to render 'category.html' template".bold(),
-                internal_err
-            )
-        } else {
-            eyre!("{}", "Failed to render 'category.html' template".bold())
-        }
-    })?;
+    let mut body = tera
+        .render("category.html", &context)
+        .wrap_err_with(|| "Failed to render 'category.html' template".bold())?;
 
     body = body.replace(
         &config.root_url.replace("://", ":&#x2F;&#x2F;"),
@@ -1001,7 +1530,7 @@ async fn handle_category(path: &str, state: &Arc<ServerState>) -> Result<Respons
 ///
 /// This function processes incoming HTTP requests and routes them to the appropriate
 /// handler based on the request path. It serves LiveReload scripts, static assets, and
-/// dynamic content.
+/// dynamic content, and forwards the `[dev.proxy]` path prefixes to their upstream servers.
 ///
 /// # Arguments
 /// * `req` - The incoming HTTP request.
@@ -1010,11 +1539,26 @@ async fn handle_category(path: &str, state: &Arc<ServerState>) -> Result<Respons
 /// # Returns
 /// * `Result<Response<Body>>` - A `Response` containing the result of the request handling.
 async fn handle_request(req: Request<Body>, state: Arc<ServerState>) -> Result<Response<Body>> {
+    // Proxied path prefixes take precedence over the site files
+    let proxy = state.proxy.read().await.clone();
+    if let Some(upstream) = proxy.upstream_uri(req.uri()) {
+        let scheme = state.routes_url.split("://").next().unwrap_or("http");
+        return Ok(proxy.forward(req, upstream, scheme).await);
+    }
+
     let request_path = req.uri().path();
     debug!(path = %request_path, "Handling request");
 
-    let categories_dir = state.config.read().await.categories_dir.clone();
-    match request_path {
+    let (categories_dir, custom_headers) = {
+        let config = state.config.read().await;
+        let custom_headers: Vec<(String, String)> =
+            redirects::headers_for(&config.headers, request_path)
+                .into_iter()
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .collect();
+        (config.categories_dir.clone(), custom_headers)
+    };
+    let mut response = match request_path {
         "/livereload.js" => Ok(Response::builder()
             .header(CONTENT_TYPE, "text/javascript")
             .body(LIVE_RELOAD_SCRIPT.into())?),
@@ -1022,10 +1566,37 @@ async fn handle_request(req: Request<Body>, state: Arc<ServerState>) -> Result<R
         path if path.starts_with(&format!("/{}/", categories_dir)) => {
             handle_category(path, &state).await
         }
+        path if path == format!("/{}", highlight::CSS_PATH) => {
+            handle_highlight_css(path, &state).await
+        }
         path if path.starts_with("/assets/") => handle_asset(path, &state.paths, &state).await,
         path if path.ends_with(".xml") => handle_xml_feed(path, &state).await,
-        _ => handle_content(request_path, state).await,
+        _ => handle_content(request_path, state.clone()).await,
+    }?;
+
+    // Pages take precedence over the redirects of their path, like on most hosts
+    if response.status() == StatusCode::NOT_FOUND {
+        if let Some(redirect) = redirects::find(&state.redirects.read().await, request_path) {
+            debug!(from = %redirect.from, to = %redirect.to, "Redirecting");
+            response = Response::builder()
+                .status(redirect.status)
+                .header(LOCATION, &redirect.to)
+                .body(Body::empty())?;
+        }
     }
+
+    for (name, value) in custom_headers {
+        match (
+            HeaderName::from_bytes(name.as_bytes()),
+            HeaderValue::from_str(&value),
+        ) {
+            (Ok(name), Ok(value)) => {
+                response.headers_mut().insert(name, value);
+            }
+            _ => warn!("{} '{}: {}'", "Invalid custom header".bold(), name, value),
+        }
+    }
+    Ok(response)
 }
 
 /// Handles HTTP requests and logs the results.
@@ -1056,9 +1627,11 @@ async fn handle_server_request(
     let response = match handle_request(req, state.clone()).await {
         Ok(res) => res,
         Err(e) => {
-            error!("{}", e);
+            error!("{:#}", e);
+            // The error is also shown in the open pages, until the next successful rebuild
+            state.report_error(&e);
             // Remove ANSI codes from error string as the colored crate clear method is stupid enough not to do anything
-            let e_str = e.to_string().replace("\x1b[1m", "").replace("\x1b[0m", "");
+            let e_str = format!("{:#}", e).replace("\x1b[1m", "").replace("\x1b[0m", "");
             let response = {
                 let tera = state.tera.try_read();
                 let config = state.config.try_read();
@@ -1077,38 +1650,22 @@ async fn handle_server_request(
                             });
                         let mut context = shared_context;
                         context.insert("error_message", &e_str);
-                        if let Ok(rendered) = tera.render("500.html", &context) {
+                        if let Ok(mut rendered) = tera.render("500.html", &context) {
+                            // Reloaded once the error is fixed
+                            inject_livereload_script(&mut rendered);
                             Response::builder()
                                 .status(StatusCode::INTERNAL_SERVER_ERROR)
                                 .header(CONTENT_TYPE, "text/html; charset=utf-8")
                                 .body(Body::from(rendered))
                                 .unwrap()
                         } else {
-                            Response::builder()
-                                .status(StatusCode::INTERNAL_SERVER_ERROR)
-                                .body(Body::from(format!(
-                                    "500 Internal Server Error\n\n{}",
-                                    e_str
-                                )))
-                                .unwrap()
+                            internal_error_page(&e_str)
                         }
                     } else {
-                        Response::builder()
-                            .status(StatusCode::INTERNAL_SERVER_ERROR)
-                            .body(Body::from(format!(
-                                "500 Internal Server Error\n\n{}",
-                                e_str
-                            )))
-                            .unwrap()
+                        internal_error_page(&e_str)
                     }
                 } else {
-                    Response::builder()
-                        .status(StatusCode::INTERNAL_SERVER_ERROR)
-                        .body(Body::from(format!(
-                            "500 Internal Server Error\n\n{}",
-                            e_str
-                        )))
-                        .unwrap()
+                    internal_error_page(&e_str)
                 }
             };
             response
@@ -1157,6 +1714,7 @@ fn render_all_pages(
     tera: &Tera,
     paths: &SitePaths,
     config: &config::SiteConfig,
+    convert_options: &ConvertOptions,
     routes_url: &str,
     posts: &[toml::Value],
     cache: &crate::cache::BuildCache,
@@ -1195,12 +1753,20 @@ fn render_all_pages(
 
         // Full load with HTML conversion (reuse build_cache if available)
         let cache_key = rel_path.with_extension("");
-        let mut metadata = if let Some(cached) = cache.get(&cache_key, &content) {
+        let mut metadata = if let Some(cached) =
+            shared::cached_conversion(cache, &cache_key, &content, convert_options)
+        {
             serde_json::from_value(cached).unwrap_or_else(|_| {
-                shared::load_metadata_from_content(&content, rel_path, routes_url)
+                let (md, diagnostics) =
+                    shared::load_metadata_from_content(&content, rel_path, convert_options);
+                warn_diagnostics(&diagnostics);
+                md
             })
         } else {
-            shared::load_metadata_from_content(&content, rel_path, routes_url)
+            let (md, diagnostics) =
+                shared::load_metadata_from_content(&content, rel_path, convert_options);
+            warn_diagnostics(&diagnostics);
+            md
         };
 
         // post_convert hook: modify HTML after Norg conversion, before Tera
@@ -1236,7 +1802,13 @@ fn render_all_pages(
             }
         }
 
-        let mut body = shared::render_norg_page(tera, &metadata, &shared_context)?;
+        let mut body = shared::render_norg_page(tera, &metadata, &shared_context)
+            .wrap_err_with(|| {
+                format!(
+                    "Failed to render {}",
+                    Path::new("content").join(rel_path).display()
+                )
+            })?;
 
         // post_render hook: modify final HTML after Tera, before URL rewrite
         if plugin_mgr.has_hook(plugin::HOOK_POST_RENDER) {
@@ -1363,6 +1935,9 @@ async fn setup_server_state(
         bail!("Site configuration has validation errors");
     }
 
+    let proxy_routes = site_config.dev.as_ref().map(|dev| dev.proxy.clone());
+    let proxy = Proxy::new(&proxy_routes.unwrap_or_default())?;
+
     let root_dir = root.parent().unwrap().to_path_buf();
     let mut paths = SitePaths::new(root_dir.clone());
 
@@ -1383,16 +1958,42 @@ async fn setup_server_state(
     if let Ok(real) = tokio::fs::canonicalize(&paths.theme_templates).await {
         paths.theme_templates = real;
     }
+    if let Ok(real) = tokio::fs::canonicalize(&paths.macros).await {
+        paths.macros = real;
+    }
 
     let tera = shared::init_tera(paths.templates.to_str().unwrap(), &paths.theme_templates)?;
 
     let (reload_tx, _) = broadcast::channel(16);
 
-    let posts =
-        shared::collect_all_posts_metadata(&paths.content, &routes_url, &site_config.collections)?;
-
-    // Open build cache for incremental renders
-    let cache = crate::cache::BuildCache::open(&root_dir)?;
+    // Index pages and headings so internal links can be resolved
+    let macros = shared::load_macros(&root_dir, &site_config)?;
+    let mut convert_options = shared::convert_options(&site_config, &routes_url);
+    if let Some(macros) = &macros {
+        convert_options = convert_options.with_macros(macros.clone());
+    }
+    let shortcodes = Shortcodes::load(&tera, &TemplateDeps::new(&tera)).map(Arc::new);
+    if let Some(shortcodes) = &shortcodes {
+        convert_options = convert_options.with_shortcodes(shortcodes.clone());
+    }
+    let images = shared::load_images(&root_dir, &site_config)?;
+    if let Some(images) = &images {
+        convert_options = convert_options.with_images(images.clone());
+    }
+    let links = Arc::new(shared::build_link_index(&paths.content, &convert_options, drafts));
+    let redirects = redirects::collect(&site_config, &links);
+    let convert_options = convert_options.with_links(links.clone());
+
+    // Open build cache for incremental renders, posts reuse it too
+    let mut cache = crate::cache::BuildCache::open(&root_dir)?;
+    cache.set_site_inputs(convert_options.fingerprint());
+
+    let posts = shared::collect_all_posts_metadata(
+        &paths.content,
+        &convert_options,
+        &site_config.collections,
+        Some(&mut cache),
+    )?;
 
     // Load plugins, apply sandbox, run pre_build hook
     let plugin_mgr = plugin::PluginManager::load(&root_dir);
@@ -1423,6 +2024,7 @@ async fn setup_server_state(
         &tera,
         &paths,
         &site_config,
+        &convert_options,
         &routes_url,
         &posts,
         &cache,
@@ -1442,6 +2044,13 @@ async fn setup_server_state(
         cache: Arc::new(RwLock::new(cache)),
         rendered_pages: Arc::new(RwLock::new(rendered_pages)),
         plugin_mgr: Arc::new(plugin_mgr),
+        links: Arc::new(RwLock::new(links)),
+        macros: Arc::new(RwLock::new(macros)),
+        shortcodes: Arc::new(RwLock::new(shortcodes)),
+        images: Arc::new(RwLock::new(images)),
+        proxy: Arc::new(RwLock::new(Arc::new(proxy))),
+        redirects: Arc::new(RwLock::new(Arc::new(redirects))),
+        last_error: std::sync::Mutex::new(None),
     }))
 }
 
@@ -1496,10 +2105,93 @@ async fn setup_file_watcher(
     if state.paths.theme_templates.exists() {
         debouncer.watch(&state.paths.theme_templates, RecursiveMode::Recursive)?;
     }
+    if state.paths.macros.exists() {
+        debouncer.watch(&state.paths.macros, RecursiveMode::Recursive)?;
+    }
 
     Ok((debouncer, ReceiverStream::new(debouncer_rx)))
 }
 
+/// Returns the host of an IP address as written in URLs, i.e. with brackets for IPv6.
+fn url_host(ip: IpAddr) -> String {
+    match ip {
+        IpAddr::V4(ip) => ip.to_string(),
+        IpAddr::V6(ip) => format!("[{}]", ip),
+    }
+}
+
+/// Accepts the TLS connections of a listener, completing their handshakes in the background
+/// so a slow client does not block the others.
+///
+/// # Arguments
+/// * `listener` - The listener accepting the TCP connections.
+/// * `acceptor` - The TLS acceptor holding the server certificate.
+///
+/// # Returns
+/// * `impl Accept` - The established TLS connections, to be served by hyper.
+fn tls_incoming(
+    listener: TcpListener,
+    acceptor: TlsAcceptor,
+) -> impl Accept<Conn = tokio_rustls::server::TlsStream<tokio::net::TcpStream>, Error = std::io::Error>
+{
+    let (conn_tx, conn_rx) = tokio::sync::mpsc::channel(32);
+    tokio::spawn(async move {
+        loop {
+            let stream = match listener.accept().await {
+                Ok((stream, _)) => stream,
+                Err(e) => {
+                    debug!("Failed to accept connection: {}", e);
+                    continue;
+                }
+            };
+            let acceptor = acceptor.clone();
+            let conn_tx = conn_tx.clone();
+            tokio::spawn(async move {
+                match acceptor.accept(stream).await {
+                    Ok(stream) => {
+                        let _ = conn_tx.send(Ok(stream)).await;
+                    }
+                    Err(e) => debug!("TLS handshake failed: {}", e),
+                }
+            });
+        }
+    });
+    accept::from_stream(ReceiverStream::new(conn_rx))
+}
+
+/// Serves the site over the given connections until the shutdown signal is received.
+///
+/// # Arguments
+/// * `incoming` - The connections to serve, either plain TCP or TLS ones.
+/// * `state` - The shared server state.
+/// * `shutdown_rx` - The graceful shutdown signal.
+async fn serve<I>(
+    incoming: I,
+    state: Arc<ServerState>,
+    shutdown_rx: tokio::sync::oneshot::Receiver<()>,
+) -> Result<()>
+where
+    I: Accept,
+    I::Error: Into<Box<dyn std::error::Error + Send + Sync>>,
+    I::Conn: AsyncRead + AsyncWrite + Unpin + Send + 'static,
+{
+    let make_svc = make_service_fn(move |_| {
+        let state = state.clone();
+        async move {
+            Ok::<_, Infallible>(service_fn(move |req| {
+                handle_server_request(req, state.clone())
+            }))
+        }
+    });
+    Server::builder(incoming)
+        .serve(make_svc)
+        .with_graceful_shutdown(async {
+            let _ = shutdown_rx.await;
+        })
+        .await
+        .map_err(|e| eyre!("{}: {}", "Server error".bold(), e))
+}
+
 /// Starts the development server.
 ///
 /// This function initializes and runs the development server, including the HTTP server,
@@ -1508,20 +2200,15 @@ async fn setup_file_watcher(
 /// changes and triggers reloads or rebuilds as necessary.
 ///
 /// # Arguments
-/// * `port` - The port on which the server will run.
+/// * `listener` - The listener bound to the address the server will run on.
 /// * `drafts` - Whether to serve draft content.
 /// * `open` - Whether to open the site in the browser after starting the server.
+/// * `https` - Whether to serve over HTTPS with a self-signed certificate.
 ///
 /// # Returns
 /// * `Result<()>` - `Ok(())` if the server runs successfully, otherwise an error.
-#[instrument(skip(port, drafts, open, host))]
-pub async fn dev(
-    listener: StdTcpListener,
-    port: u16,
-    drafts: bool,
-    open: bool,
-    host: bool,
-) -> Result<()> {
+#[instrument(skip(listener, drafts, open, https))]
+pub async fn dev(listener: StdTcpListener, drafts: bool, open: bool, https: bool) -> Result<()> {
     println!("{} Starting development server...", "→".cyan().bold());
 
     let root = fs::find_config_file()?;
@@ -1534,12 +2221,47 @@ pub async fn dev(
 
     debug!(path = %root.display(), "Found site root");
 
-    // Early set the development URL to the site routes
+    let addr = listener.local_addr()?;
+    let port = addr.port();
+    let scheme = if https { "https" } else { "http" };
     let local_ip = local_ip_address::local_ip().unwrap_or(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)));
-    let routes_url = if host {
-        format!("http://{}:{}", local_ip, port)
+    // Localhost only reaches the server when it is bound to the loopback or to every interface
+    let is_local = addr.ip().is_unspecified()
+        || addr.ip() == IpAddr::V4(Ipv4Addr::LOCALHOST)
+        || addr.ip() == IpAddr::V6(Ipv6Addr::LOCALHOST);
+    // The address other devices reach the server at, if it is exposed
+    let network_ip = if addr.ip().is_unspecified() {
+        Some(local_ip)
+    } else if addr.ip().is_loopback() {
+        None
     } else {
-        format!("http://localhost:{}", port)
+        Some(addr.ip())
+    };
+    let local_host = if is_local {
+        "localhost".to_string()
+    } else {
+        url_host(addr.ip())
+    };
+
+    // Early set the development URL to the site routes
+    let routes_url = match network_ip {
+        Some(ip) => format!("{}://{}:{}", scheme, url_host(ip), port),
+        None => format!("{}://{}:{}", scheme, local_host, port),
+    };
+    let tls_acceptor = if https {
+        let mut names = vec![
+            "localhost".to_string(),
+            Ipv4Addr::LOCALHOST.to_string(),
+            Ipv6Addr::LOCALHOST.to_string(),
+        ];
+        names.extend(network_ip.map(|ip| ip.to_string()));
+        if !is_local {
+            names.push(addr.ip().to_string());
+        }
+        names.dedup();
+        Some(TlsAcceptor::from(tls::server_config(&names)?))
+    } else {
+        None
     };
     let state = setup_server_state(root, drafts, routes_url).await?;
     let server_start = std::time::Instant::now();
@@ -1549,10 +2271,11 @@ pub async fn dev(
     // any "channel closed" errors are prevented from happening
     let _guard_receiver = state.reload_tx.subscribe();
 
-    // WebSocket server
-    let reload_tx = state.reload_tx.clone();
+    // WebSocket server, listening on the same interface as the HTTP server
+    let ws_state = state.clone();
+    let ws_acceptor = tls_acceptor.clone();
     tokio::spawn(async move {
-        let listener = match TcpListener::bind(format!("127.0.0.1:{}", LIVE_RELOAD_PORT)).await {
+        let listener = match TcpListener::bind((addr.ip(), LIVE_RELOAD_PORT)).await {
             Ok(l) => l,
             Err(e) => {
                 error!(
@@ -1563,7 +2286,20 @@ pub async fn dev(
             }
         };
         while let Ok((stream, _)) = listener.accept().await {
-            tokio::spawn(handle_websocket(stream, reload_tx.clone()));
+            let state = ws_state.clone();
+            match ws_acceptor.clone() {
+                Some(acceptor) => {
+                    tokio::spawn(async move {
+                        match acceptor.accept(stream).await {
+                            Ok(stream) => handle_websocket(stream, state).await,
+                            Err(e) => debug!("WebSocket TLS handshake failed: {}", e),
+                        }
+                    });
+                }
+                None => {
+                    tokio::spawn(handle_websocket(stream, state));
+                }
+            }
         }
     });
 
@@ -1581,16 +2317,8 @@ pub async fn dev(
     });
 
     // HTTP server
-    let state_clone = Arc::clone(&state);
-    let make_svc = make_service_fn(move |_| {
-        let state = state_clone.clone();
-        async move {
-            Ok::<_, Infallible>(service_fn(move |req| {
-                handle_server_request(req, state.clone())
-            }))
-        }
-    });
     listener.set_nonblocking(true)?;
+    let listener = TcpListener::from_std(listener)?;
     // Graceful shutdown via Ctrl-D (stdin EOF)
     let (shutdown_tx, shutdown_rx) = tokio::sync::oneshot::channel::<()>();
     tokio::spawn(async move {
@@ -1608,24 +2336,44 @@ pub async fn dev(
         }
     });
 
-    let server = Server::from_tcp(listener)?
-        .serve(make_svc)
-        .with_graceful_shutdown(async {
-            let _ = shutdown_rx.await;
-        });
+    let state_clone = Arc::clone(&state);
+    let server = tokio::spawn(async move {
+        match tls_acceptor {
+            Some(acceptor) => {
+                serve(tls_incoming(listener, acceptor), state_clone, shutdown_rx).await
+            }
+            None => {
+                let incoming = hyper::server::conn::AddrIncoming::from_listener(listener)?;
+                serve(incoming, state_clone, shutdown_rx).await
+            }
+        }
+    });
 
+    let local_url = format!("{}://{}:{}/", scheme, local_host, port);
     let localhost_address = format!(
         "{} {}   {}",
         "•".green(),
         "Local:".bold(),
-        format!("http://localhost:{}/", port.to_string().cyan().bold()).blue()
+        format!(
+            "{}://{}:{}/",
+            scheme,
+            local_host,
+            port.to_string().cyan().bold()
+        )
+        .blue()
     );
-    let lan_address = if host {
+    let lan_address = if let Some(ip) = network_ip {
         format!(
             "{} {} {}",
             "•".green(),
             "Network:".bold(),
-            format!("http://{}:{}/", local_ip, port.to_string().cyan().bold()).blue()
+            format!(
+                "{}://{}:{}/",
+                scheme,
+                url_host(ip),
+                port.to_string().cyan().bold()
+            )
+            .blue()
         )
     } else {
         format!(
@@ -1646,7 +2394,7 @@ pub async fn dev(
     );
 
     if open {
-        match open::that_detached(format!("http://localhost:{}/", port)) {
+        match open::that_detached(&local_url) {
             Ok(()) => {
                 info!("Opening the development server page using your browser ...");
             }
@@ -1658,9 +2406,7 @@ pub async fn dev(
         };
     }
 
-    if let Err(e) = server.await {
-        bail!("{}: {}", "Server error".bold(), e);
-    }
+    server.await??;
 
     println!("\n{} Development server stopped.", "→".cyan().bold());
     Ok(())
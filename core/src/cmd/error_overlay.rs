@@ -0,0 +1,240 @@
+//! Structured reports of the development server errors, shown as an in-page overlay by the
+//! live reload client.
+//!
+//! Errors only carry messages, so the file, position and template of an error are found in the
+//! messages of its chain, innermost first (e.g. Tera's ` --> 3:5` or TOML's
+//! `at line 3, column 5`).
+
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use serde::Serialize;
+
+/// Number of source lines shown before and after the error line
+const EXCERPT_CONTEXT: usize = 3;
+
+/// Extensions of the site files an error may come from
+const SOURCE_EXTENSIONS: &str = "norg|html|xml|toml|css|scss|js|mjs|ts|tsx";
+
+fn ansi_re() -> &'static regex::Regex {
+    static RE: OnceLock<regex::Regex> = OnceLock::new();
+    RE.get_or_init(|| regex::Regex::new(r"\x1b\[[0-9;]*m").expect("valid regex"))
+}
+
+fn template_re() -> &'static regex::Regex {
+    static RE: OnceLock<regex::Regex> = OnceLock::new();
+    RE.get_or_init(|| regex::Regex::new(r"'([^'\s]+\.(?:html|xml))'").expect("valid regex"))
+}
+
+fn file_re() -> &'static regex::Regex {
+    static RE: OnceLock<regex::Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        regex::Regex::new(&format!(
+            r"([\w./\\-]+\.(?:{}))(?::(\d+)(?::(\d+))?)?\b",
+            SOURCE_EXTENSIONS
+        ))
+        .expect("valid regex")
+    })
+}
+
+fn position_re() -> &'static regex::Regex {
+    static RE: OnceLock<regex::Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        regex::Regex::new(r"-->\s*(\d+):(\d+)|line (\d+), column (\d+)").expect("valid regex")
+    })
+}
+
+/// An error reported to the live reload clients
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ErrorReport {
+    /// The outermost error message
+    pub message: String,
+    /// The error chain, outermost first, including `message`
+    pub chain: Vec<String>,
+    /// The file the error comes from, relative to the site root
+    pub file: Option<String>,
+    /// 1-based line of the error in `file`
+    pub line: Option<usize>,
+    /// 1-based column of the error in `file`
+    pub column: Option<usize>,
+    /// The template being rendered or parsed
+    pub template: Option<String>,
+    /// Source lines around `line`
+    pub excerpt: Option<Excerpt>,
+}
+
+/// Lines of a source file
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Excerpt {
+    /// 1-based line number of the first line
+    pub start_line: usize,
+    pub lines: Vec<String>,
+}
+
+impl ErrorReport {
+    /// Builds the report of an error
+    ///
+    /// # Arguments
+    /// * `error` - The error to report.
+    /// * `root` - The site root directory, files are reported relative to it.
+    /// * `source_dirs` - The directories the files named by the error messages are searched in,
+    ///   e.g. the templates and content directories.
+    pub fn new(error: &eyre::Report, root: &Path, source_dirs: &[&Path]) -> Self {
+        let mut chain: Vec<String> = Vec::new();
+        for cause in error.chain() {
+            let cause = ansi_re()
+                .replace_all(&cause.to_string(), "")
+                .trim()
+                .to_string();
+            // Wrapped errors often repeat the message of their source
+            if !cause.is_empty() && chain.last() != Some(&cause) {
+                chain.push(cause);
+            }
+        }
+
+        let template = chain
+            .iter()
+            .rev()
+            .find_map(|cause| template_re().captures(cause))
+            .map(|captures| captures[1].to_string());
+
+        // The innermost file that exists, along with the position it is mentioned with
+        let mut file = None;
+        let mut position = None;
+        for cause in chain.iter().rev() {
+            if let Some(found) = file_re()
+                .captures_iter(cause)
+                .filter_map(|captures| {
+                    let path = find_file(&captures[1], root, source_dirs)?;
+                    let line = captures.get(2).and_then(|m| m.as_str().parse().ok());
+                    let column = captures.get(3).and_then(|m| m.as_str().parse().ok());
+                    Some((path, line, column))
+                })
+                .last()
+            {
+                if let (Some(line), column) = (found.1, found.2) {
+                    position = Some((line, column));
+                }
+                file = Some(found.0);
+                break;
+            }
+        }
+        let file = file.or_else(|| {
+            template
+                .as_deref()
+                .and_then(|template| find_file(template, root, source_dirs))
+        });
+        let position = position.or_else(|| {
+            chain
+                .iter()
+                .rev()
+                .find_map(|cause| position_re().captures(cause))
+                .and_then(|captures| {
+                    let line = captures.get(1).or(captures.get(3))?.as_str().parse().ok()?;
+                    let column = captures
+                        .get(2)
+                        .or(captures.get(4))
+                        .and_then(|m| m.as_str().parse().ok());
+                    Some((line, column))
+                })
+        });
+
+        let excerpt = match (&file, position) {
+            (Some(file), Some((line, _))) => excerpt(file, line),
+            _ => None,
+        };
+        Self {
+            message: chain.first().cloned().unwrap_or_default(),
+            chain,
+            file: file.map(|file| {
+                file.strip_prefix(root)
+                    .unwrap_or(&file)
+                    .display()
+                    .to_string()
+            }),
+            line: position.map(|(line, _)| line),
+            column: position.and_then(|(_, column)| column),
+            template,
+            excerpt,
+        }
+    }
+}
+
+/// Finds a file named by an error message, either an absolute path or a path relative to the
+/// site root or to one of the source directories
+///
+/// Excerpts of the file are sent to every live reload client, so only files inside the site
+/// root are accepted.
+fn find_file(name: &str, root: &Path, source_dirs: &[&Path]) -> Option<PathBuf> {
+    let canonical_root = root.canonicalize().ok()?;
+    std::iter::once(root)
+        .chain(source_dirs.iter().copied())
+        // Joining an absolute path replaces the directory
+        .filter_map(|dir| dir.join(name).canonicalize().ok())
+        .filter(|path| path.is_file())
+        .find_map(|path| {
+            path.strip_prefix(&canonical_root)
+                .ok()
+                .map(|rel_path| root.join(rel_path))
+        })
+}
+
+fn excerpt(file: &Path, line: usize) -> Option<Excerpt> {
+    let content = std::fs::read_to_string(file).ok()?;
+    let start_line = line.saturating_sub(EXCERPT_CONTEXT).max(1);
+    let lines: Vec<String> = content
+        .lines()
+        .skip(start_line - 1)
+        .take(line + EXCERPT_CONTEXT + 1 - start_line)
+        .map(str::to_string)
+        .collect();
+    (!lines.is_empty()).then_some(Excerpt { start_line, lines })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_report() {
+        let root = tempfile::tempdir().unwrap();
+        let templates = root.path().join("templates");
+        std::fs::create_dir_all(&templates).unwrap();
+        std::fs::write(
+            templates.join("post.html"),
+            "<html>\n<body>\n{{ content | safe }\n</body>\n</html>\n",
+        )
+        .unwrap();
+        std::fs::write(root.path().join("norgolith.toml"), "title = \n").unwrap();
+
+        let error = eyre::eyre!(" --> 3:18\n  |\n3 | {{{{ content | safe }}\n  = expected `}}}}`")
+            .wrap_err("Failed to parse 'post.html'")
+            .wrap_err("\x1b[1mFailed to reload templates\x1b[0m");
+        let report = ErrorReport::new(&error, root.path(), &[&templates]);
+        assert_eq!(report.message, "Failed to reload templates");
+        assert_eq!(report.chain.len(), 3);
+        assert_eq!(report.template.as_deref(), Some("post.html"));
+        assert_eq!(report.file.as_deref(), Some("templates/post.html"));
+        assert_eq!((report.line, report.column), (Some(3), Some(18)));
+        let excerpt = report.excerpt.unwrap();
+        assert_eq!(excerpt.start_line, 1);
+        assert_eq!(excerpt.lines.len(), 5);
+
+        let error = eyre::eyre!("TOML parse error at line 1, column 9")
+            .wrap_err("Failed to parse norgolith.toml");
+        let report = ErrorReport::new(&error, root.path(), &[&templates]);
+        assert_eq!(report.file.as_deref(), Some("norgolith.toml"));
+        assert_eq!((report.line, report.column), (Some(1), Some(9)));
+        assert!(report.template.is_none());
+
+        // Files outside of the site root are never read
+        let outside = tempfile::Builder::new().suffix(".toml").tempfile().unwrap();
+        std::fs::write(outside.path(), "secret\n").unwrap();
+        let error = eyre::eyre!("Failed to read {}:1:1", outside.path().display());
+        let report = ErrorReport::new(&error, root.path(), &[&templates]);
+        assert!(report.file.is_none());
+        assert!(report.excerpt.is_none());
+        assert!(find_file("../norgolith.toml", root.path(), &[&templates]).is_some());
+        assert!(find_file("../../etc/passwd", root.path(), &[&templates]).is_none());
+    }
+}
@@ -28,7 +28,12 @@ async fn create_config(root: &str, root_url: &str, language: &str, title: &str)
         # Code blocks highlighting
         [highlighter]
         enable = false
-        # engine = 'prism' # Can be 'prism' or 'hljs'. Defaults to 'prism'
+        # engine = 'prism' # Can be 'prism', 'hljs' or 'builtin' (build-time). Defaults to 'prism'
+        # theme = 'base16-ocean.dark' # Theme used by the 'builtin' engine
+
+        # Math rendering for '@math' blocks and inline '$...$' math
+        # [math]
+        # renderer = 'mathml' # Can be 'mathml' (build-time) or 'katex' (client-side). Defaults to 'mathml'
 
         # RSS feed
         [rss]
@@ -0,0 +1,229 @@
+//! Build output manifests, written to `public/.lith-manifest.json` by `lith build`.
+//!
+//! The manifest maps every generated file to its hash, size and source file, so deploy scripts
+//! can upload only what changed between two builds with `lith diff`.
+
+use std::collections::BTreeMap;
+use std::io::IsTerminal;
+use std::path::{Path, PathBuf};
+
+use colored::Colorize;
+use eyre::{bail, eyre, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::fs;
+
+/// Name of the manifest file, inside the public directory
+pub const MANIFEST_FILE: &str = ".lith-manifest.json";
+
+/// Version of the manifest format, bump it on breaking changes
+const MANIFEST_VERSION: u32 = 1;
+
+/// A generated file
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ManifestEntry {
+    /// blake3 hash of the file content
+    pub hash: String,
+    /// Size in bytes
+    pub size: u64,
+    /// Source file relative to the site root, `None` for generated files (e.g. feeds)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<PathBuf>,
+}
+
+/// The files of a build, keyed by their path relative to the public directory
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Manifest {
+    pub version: u32,
+    pub files: BTreeMap<PathBuf, ManifestEntry>,
+}
+
+/// Differences between two manifests
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct ManifestDiff {
+    pub added: Vec<PathBuf>,
+    pub changed: Vec<PathBuf>,
+    pub removed: Vec<PathBuf>,
+}
+
+impl Manifest {
+    /// Creates the manifest of the generated files, skipping the ones that do not exist.
+    ///
+    /// # Arguments
+    /// * `public_dir` - The build target directory.
+    /// * `outputs` - The generated files relative to `public_dir`, along with their source.
+    pub fn from_outputs(
+        public_dir: &Path,
+        outputs: impl IntoIterator<Item = (PathBuf, Option<PathBuf>)>,
+    ) -> Self {
+        let files = outputs
+            .into_iter()
+            .filter_map(|(path, source)| {
+                let content = std::fs::read(public_dir.join(&path)).ok()?;
+                let entry = ManifestEntry {
+                    hash: blake3::hash(&content).to_hex().to_string(),
+                    size: content.len() as u64,
+                    source,
+                };
+                Some((path, entry))
+            })
+            .collect();
+        Self {
+            version: MANIFEST_VERSION,
+            files,
+        }
+    }
+
+    /// Reads a manifest file
+    pub fn read(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path).map_err(|e| {
+            eyre!(
+                "{} {}: {}",
+                "Failed to read manifest".bold(),
+                path.display(),
+                e
+            )
+        })?;
+        let manifest: Self = serde_json::from_str(&content).map_err(|e| {
+            eyre!(
+                "{} {}: {}",
+                "Failed to parse manifest".bold(),
+                path.display(),
+                e
+            )
+        })?;
+        if manifest.version != MANIFEST_VERSION {
+            bail!(
+                "{} {}: unsupported version {}",
+                "Failed to read manifest".bold(),
+                path.display(),
+                manifest.version
+            );
+        }
+        Ok(manifest)
+    }
+
+    /// Writes the manifest to the public directory
+    pub fn write(&self, public_dir: &Path) -> Result<()> {
+        let path = public_dir.join(MANIFEST_FILE);
+        let json = serde_json::to_string_pretty(self)
+            .map_err(|e| eyre!("{}: {}", "Failed to serialize manifest".bold(), e))?;
+        std::fs::write(&path, json).map_err(|e| {
+            eyre!(
+                "{} {}: {}",
+                "Failed to write manifest".bold(),
+                path.display(),
+                e
+            )
+        })
+    }
+
+    /// Lists the files added, changed and removed since the `old` manifest
+    pub fn diff(&self, old: &Manifest) -> ManifestDiff {
+        let mut diff = ManifestDiff::default();
+        for (path, entry) in &self.files {
+            match old.files.get(path) {
+                None => diff.added.push(path.clone()),
+                Some(old_entry) if old_entry.hash != entry.hash => diff.changed.push(path.clone()),
+                Some(_) => {}
+            }
+        }
+        diff.removed = old
+            .files
+            .keys()
+            .filter(|path| !self.files.contains_key(*path))
+            .cloned()
+            .collect();
+        diff
+    }
+}
+
+/// Lists the files that differ between two builds.
+///
+/// # Arguments
+/// * `old` - The manifest of the previous build.
+/// * `new` - The manifest of the new build, the one in the public directory of the current site
+///   when `None`.
+pub fn diff(old: &Path, new: Option<&Path>) -> Result<()> {
+    let new = match new {
+        Some(new) => new.to_path_buf(),
+        None => {
+            let Some(root) = fs::find_config_file()? else {
+                bail!(
+                    "{}: not in a Norgolith site directory, pass the new manifest path",
+                    "Could not diff the builds".bold()
+                );
+            };
+            root.parent().unwrap().join("public").join(MANIFEST_FILE)
+        }
+    };
+    let diff = Manifest::read(&new)?.diff(&Manifest::read(old)?);
+
+    // Plain output when piped into a deploy script
+    if !std::io::stdout().is_terminal() {
+        colored::control::set_override(false);
+    }
+    for path in &diff.added {
+        println!("{} {}", "+".green().bold(), path.display());
+    }
+    for path in &diff.changed {
+        println!("{} {}", "~".yellow().bold(), path.display());
+    }
+    for path in &diff.removed {
+        println!("{} {}", "-".red().bold(), path.display());
+    }
+    eprintln!(
+        "{}",
+        format!(
+            "{} added, {} changed, {} removed",
+            diff.added.len(),
+            diff.changed.len(),
+            diff.removed.len()
+        )
+        .dimmed()
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_manifest_diff() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("index.html"), "<h1>Home</h1>").unwrap();
+        std::fs::write(dir.path().join("about.html"), "<h1>About</h1>").unwrap();
+        std::fs::write(dir.path().join("feed.xml"), "<rss></rss>").unwrap();
+        let old = Manifest::from_outputs(
+            dir.path(),
+            [
+                (
+                    PathBuf::from("index.html"),
+                    Some(PathBuf::from("content/index.norg")),
+                ),
+                (PathBuf::from("about.html"), None),
+                (PathBuf::from("feed.xml"), None),
+                (PathBuf::from("missing.html"), None),
+            ],
+        );
+        assert_eq!(old.files.len(), 3);
+        assert_eq!(old.files[Path::new("about.html")].size, 14);
+
+        std::fs::write(dir.path().join("index.html"), "<h1>Welcome</h1>").unwrap();
+        std::fs::write(dir.path().join("posts.html"), "<h1>Posts</h1>").unwrap();
+        let new = Manifest::from_outputs(
+            dir.path(),
+            ["index.html", "feed.xml", "posts.html"].map(|path| (PathBuf::from(path), None)),
+        );
+
+        assert_eq!(
+            new.diff(&old),
+            ManifestDiff {
+                added: vec![PathBuf::from("posts.html")],
+                changed: vec![PathBuf::from("index.html")],
+                removed: vec![PathBuf::from("about.html")],
+            }
+        );
+    }
+}
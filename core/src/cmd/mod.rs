@@ -1,18 +1,30 @@
 mod build;
+mod cache;
+mod check_links;
 mod dev;
+mod error_overlay;
 mod init;
+mod manifest;
 mod new;
 mod plugin;
 mod preview;
+mod proxy;
+mod redirects;
+mod report;
 mod seo;
 mod theme;
 
 pub use build::build;
+pub use cache::handle as cache;
+pub use cache::CacheCommands;
+pub use check_links::check_links;
 pub use dev::dev;
 pub use init::init;
+pub use manifest::diff;
 pub use new::new;
 pub use plugin::handle as plugin;
 pub use plugin::PluginCommands;
 pub use preview::preview;
+pub use report::{ReportFormat, ReportOptions};
 pub use theme::handle as theme;
 pub use theme::ThemeCommands;
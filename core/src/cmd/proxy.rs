@@ -0,0 +1,291 @@
+//! Forwarding of the development server requests to backend servers, configured by the
+//! `[dev.proxy]` table of `norgolith.toml`.
+//!
+//! The request path is kept as-is and appended to the upstream URL, e.g. with
+//! `"/api" = "http://localhost:8000"`, `/api/users?page=2` is forwarded to
+//! `http://localhost:8000/api/users?page=2`. WebSocket upgrades are forwarded too.
+
+use std::collections::BTreeMap;
+
+use colored::Colorize;
+use eyre::{eyre, Result};
+use hyper::client::HttpConnector;
+use hyper::header::{HeaderMap, HeaderName, HeaderValue, CONNECTION, CONTENT_TYPE, HOST, UPGRADE};
+use hyper::{Body, Client, Request, Response, StatusCode, Uri, Version};
+use tracing::{debug, warn};
+
+/// Headers only meaningful for a single connection, which are not forwarded
+const HOP_BY_HOP_HEADERS: [&str; 8] = [
+    "connection",
+    "keep-alive",
+    "proxy-authenticate",
+    "proxy-authorization",
+    "te",
+    "trailer",
+    "transfer-encoding",
+    "upgrade",
+];
+
+/// A path prefix forwarded to an upstream server
+#[derive(Debug)]
+struct Route {
+    prefix: String,
+    upstream: Uri,
+}
+
+impl Route {
+    /// Whether a request path is under the route prefix, `/api` matches `/api` and `/api/users`
+    /// but not `/apis`.
+    fn matches(&self, path: &str) -> bool {
+        path.strip_prefix(self.prefix.as_str()).is_some_and(|rest| {
+            rest.is_empty() || rest.starts_with('/') || self.prefix.ends_with('/')
+        })
+    }
+}
+
+/// The development server proxy
+#[derive(Debug)]
+pub struct Proxy {
+    /// Routes sorted by descending prefix length, so the most specific prefix wins
+    routes: Vec<Route>,
+    client: Client<HttpConnector>,
+}
+
+impl Proxy {
+    /// Creates the proxy of the `[dev.proxy]` routes.
+    ///
+    /// # Arguments
+    /// * `routes` - The path prefixes mapped to their upstream URLs.
+    ///
+    /// # Returns
+    /// * `Result<Self>` - The proxy, otherwise an error if an upstream URL is invalid.
+    pub fn new(routes: &BTreeMap<String, String>) -> Result<Self> {
+        let mut routes = routes
+            .iter()
+            .map(|(prefix, upstream)| {
+                let uri: Uri = upstream.parse().map_err(|e| {
+                    eyre!(
+                        "{}: invalid upstream '{}' for '{}': {}",
+                        "Invalid dev.proxy configuration".bold(),
+                        upstream,
+                        prefix,
+                        e
+                    )
+                })?;
+                if uri.scheme_str() != Some("http") || uri.authority().is_none() {
+                    return Err(eyre!(
+                        "{}: upstream '{}' for '{}' must be an http:// URL",
+                        "Invalid dev.proxy configuration".bold(),
+                        upstream,
+                        prefix
+                    ));
+                }
+                Ok(Route {
+                    prefix: prefix.clone(),
+                    upstream: uri,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        routes.sort_by(|a, b| b.prefix.len().cmp(&a.prefix.len()));
+
+        Ok(Self {
+            routes,
+            client: Client::builder().build_http(),
+        })
+    }
+
+    /// Returns the upstream URL a request is forwarded to, if its path is proxied.
+    ///
+    /// # Arguments
+    /// * `uri` - The request URI.
+    pub fn upstream_uri(&self, uri: &Uri) -> Option<Uri> {
+        let route = self.routes.iter().find(|route| route.matches(uri.path()))?;
+        let base = route.upstream.path().trim_end_matches('/');
+        let path_and_query = uri.path_and_query().map_or("/", |p| p.as_str());
+        Uri::builder()
+            .scheme("http")
+            .authority(route.upstream.authority()?.clone())
+            .path_and_query(format!("{}{}", base, path_and_query))
+            .build()
+            .ok()
+    }
+
+    /// Forwards a request to its upstream server.
+    ///
+    /// Upstream servers which cannot be reached get a `502 Bad Gateway` response, they are not
+    /// site errors.
+    ///
+    /// # Arguments
+    /// * `req` - The request to forward.
+    /// * `upstream` - The upstream URL, as returned by [`Proxy::upstream_uri`].
+    /// * `scheme` - The scheme the request was received with, sent as `X-Forwarded-Proto`.
+    pub async fn forward(
+        &self,
+        mut req: Request<Body>,
+        upstream: Uri,
+        scheme: &str,
+    ) -> Response<Body> {
+        let is_upgrade = wants_upgrade(req.headers());
+        let client_upgrade = is_upgrade.then(|| hyper::upgrade::on(&mut req));
+
+        let (mut parts, body) = req.into_parts();
+        let original_host = parts.headers.get(HOST).cloned().or_else(|| {
+            parts
+                .uri
+                .authority()
+                .and_then(|a| HeaderValue::from_str(a.as_str()).ok())
+        });
+        remove_hop_by_hop_headers(&mut parts.headers, is_upgrade);
+        if let Some(authority) = upstream.authority() {
+            if let Ok(host) = HeaderValue::from_str(authority.as_str()) {
+                parts.headers.insert(HOST, host);
+            }
+        }
+        if let Some(host) = original_host {
+            parts
+                .headers
+                .insert(HeaderName::from_static("x-forwarded-host"), host);
+        }
+        if let Ok(scheme) = HeaderValue::from_str(scheme) {
+            parts
+                .headers
+                .insert(HeaderName::from_static("x-forwarded-proto"), scheme);
+        }
+        parts.uri = upstream.clone();
+        // Browsers may talk HTTP/2 to the development server, upstream servers are HTTP/1.1
+        parts.version = Version::HTTP_11;
+
+        debug!(%upstream, "Forwarding request");
+        let mut res = match self.client.request(Request::from_parts(parts, body)).await {
+            Ok(res) => res,
+            Err(e) => {
+                warn!(
+                    "{}: {}: {}",
+                    "Could not reach proxy upstream".bold(),
+                    upstream,
+                    e
+                );
+                return Response::builder()
+                    .status(StatusCode::BAD_GATEWAY)
+                    .header(CONTENT_TYPE, "text/plain; charset=utf-8")
+                    .body(Body::from(format!(
+                        "Could not reach proxy upstream {}: {}",
+                        upstream, e
+                    )))
+                    .unwrap();
+            }
+        };
+
+        match client_upgrade {
+            Some(client_upgrade) if res.status() == StatusCode::SWITCHING_PROTOCOLS => {
+                let upstream_upgrade = hyper::upgrade::on(&mut res);
+                tokio::spawn(async move {
+                    match tokio::try_join!(client_upgrade, upstream_upgrade) {
+                        Ok((mut client, mut upstream)) => {
+                            if let Err(e) =
+                                tokio::io::copy_bidirectional(&mut client, &mut upstream).await
+                            {
+                                debug!("Proxied connection closed: {}", e);
+                            }
+                        }
+                        Err(e) => warn!("{}: {}", "Proxy upgrade failed".bold(), e),
+                    }
+                });
+            }
+            _ => remove_hop_by_hop_headers(res.headers_mut(), false),
+        }
+        res
+    }
+}
+
+/// Whether a request asks to switch protocols, e.g. to a WebSocket
+fn wants_upgrade(headers: &HeaderMap) -> bool {
+    headers.contains_key(UPGRADE)
+        && headers
+            .get_all(CONNECTION)
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .flat_map(|value| value.split(','))
+            .any(|token| token.trim().eq_ignore_ascii_case("upgrade"))
+}
+
+/// Removes the hop-by-hop headers, along with the ones listed by `Connection`. The `Connection`
+/// and `Upgrade` headers are kept for protocol upgrades.
+fn remove_hop_by_hop_headers(headers: &mut HeaderMap, keep_upgrade: bool) {
+    let listed: Vec<String> = headers
+        .get_all(CONNECTION)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .map(|token| token.trim().to_ascii_lowercase())
+        .filter(|token| !token.is_empty() && token != "upgrade")
+        .collect();
+    for name in listed
+        .iter()
+        .map(String::as_str)
+        .chain(HOP_BY_HOP_HEADERS)
+        .filter(|name| !(keep_upgrade && (*name == "connection" || *name == "upgrade")))
+    {
+        headers.remove(name);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_upstream_uri() {
+        let routes = BTreeMap::from([
+            ("/api".to_string(), "http://localhost:8000".to_string()),
+            (
+                "/api/v2".to_string(),
+                "http://127.0.0.1:9000/backend/".to_string(),
+            ),
+        ]);
+        let proxy = Proxy::new(&routes).unwrap();
+        let upstream = |uri: &str| {
+            proxy
+                .upstream_uri(&uri.parse().unwrap())
+                .map(|u| u.to_string())
+        };
+
+        assert_eq!(
+            upstream("/api").as_deref(),
+            Some("http://localhost:8000/api")
+        );
+        assert_eq!(
+            upstream("/api/users?page=2").as_deref(),
+            Some("http://localhost:8000/api/users?page=2")
+        );
+        assert_eq!(
+            upstream("/api/v2/users").as_deref(),
+            Some("http://127.0.0.1:9000/backend/api/v2/users")
+        );
+        assert_eq!(upstream("/apis"), None);
+        assert_eq!(upstream("/posts/api"), None);
+
+        let routes = BTreeMap::from([("/api".to_string(), "https://example.com".to_string())]);
+        assert!(Proxy::new(&routes).is_err());
+    }
+
+    #[test]
+    fn test_remove_hop_by_hop_headers() {
+        let mut headers = HeaderMap::new();
+        headers.insert(CONNECTION, HeaderValue::from_static("Upgrade, X-Private"));
+        headers.insert(UPGRADE, HeaderValue::from_static("websocket"));
+        headers.insert("x-private", HeaderValue::from_static("1"));
+        headers.insert("keep-alive", HeaderValue::from_static("timeout=5"));
+        headers.insert("accept", HeaderValue::from_static("*/*"));
+        assert!(wants_upgrade(&headers));
+
+        let mut upgrade = headers.clone();
+        remove_hop_by_hop_headers(&mut upgrade, true);
+        assert!(upgrade.contains_key(CONNECTION) && upgrade.contains_key(UPGRADE));
+        assert!(!upgrade.contains_key("x-private") && !upgrade.contains_key("keep-alive"));
+
+        remove_hop_by_hop_headers(&mut headers, false);
+        assert_eq!(headers.len(), 1);
+        assert!(headers.contains_key("accept"));
+    }
+}
@@ -0,0 +1,373 @@
+//! Redirects of moved pages and custom response headers.
+//!
+//! Redirects are declared by the `[[redirects]]` tables of `norgolith.toml` and by the
+//! `aliases` metadata field of pages, headers by the `[[headers]]` tables. `lith dev` applies
+//! them to its responses, `lith build` publishes the redirects as HTML stubs and both as
+//! configuration files of the hosts serving the site.
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use crate::config::{HeadersConfig, SiteConfig};
+use crate::converter::links::LinkIndex;
+
+/// Redirects file of Netlify and Cloudflare Pages
+pub const REDIRECTS_FILE: &str = "_redirects";
+/// Headers file of Netlify and Cloudflare Pages
+pub const HEADERS_FILE: &str = "_headers";
+/// nginx configuration snippet, to be included in the `server` block of the site
+pub const NGINX_FILE: &str = "_nginx.conf";
+
+/// A redirect from a former path of a page
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Redirect {
+    pub from: String,
+    pub to: String,
+    pub status: u16,
+}
+
+/// Collects the redirects of the site configuration and of the page aliases, sorted by path.
+///
+/// Configured redirects take precedence over aliases of the same path.
+///
+/// # Arguments
+/// * `config` - The site configuration.
+/// * `links` - The index of the site pages, holding their aliases.
+pub fn collect(config: &SiteConfig, links: &LinkIndex) -> Vec<Redirect> {
+    let mut redirects: BTreeMap<String, Redirect> = BTreeMap::new();
+    for page in links.pages() {
+        for alias in &page.aliases {
+            let from = normalize_path(alias);
+            redirects.insert(
+                from.clone(),
+                Redirect {
+                    from,
+                    to: url_path(&page.permalink).to_string(),
+                    status: 301,
+                },
+            );
+        }
+    }
+    for redirect in &config.redirects {
+        let from = normalize_path(&redirect.from);
+        redirects.insert(
+            from.clone(),
+            Redirect {
+                from,
+                to: redirect.to.clone(),
+                status: redirect.status,
+            },
+        );
+    }
+    redirects.into_values().collect()
+}
+
+/// Finds the redirect of a request path, ignoring trailing slashes and `index.html`.
+///
+/// # Arguments
+/// * `redirects` - The redirects, as returned by [`collect`].
+/// * `path` - The request path.
+pub fn find<'a>(redirects: &'a [Redirect], path: &str) -> Option<&'a Redirect> {
+    let path = normalize_path(path);
+    redirects
+        .binary_search_by(|redirect| redirect.from.as_str().cmp(&path))
+        .ok()
+        .map(|index| &redirects[index])
+}
+
+/// Returns the custom headers of a path, later rules overriding the earlier ones.
+///
+/// # Arguments
+/// * `rules` - The `[[headers]]` rules of the site configuration.
+/// * `path` - The request path.
+pub fn headers_for<'a>(rules: &'a [HeadersConfig], path: &str) -> Vec<(&'a str, &'a str)> {
+    merge_headers(
+        rules
+            .iter()
+            .filter(|rule| match rule.path.strip_suffix('*') {
+                Some(prefix) => path.starts_with(prefix),
+                None => normalize_path(&rule.path) == normalize_path(path),
+            }),
+    )
+}
+
+/// Returns the page of a redirect stub, which redirects browsers with a meta refresh.
+///
+/// # Arguments
+/// * `to` - The path or URL redirected to.
+pub fn stub_html(to: &str) -> String {
+    let href = html_escape::encode_double_quoted_attribute(to);
+    format!(
+        r#"<!DOCTYPE html>
+<html>
+<head>
+<meta charset="utf-8">
+<title>Redirecting&hellip;</title>
+<link rel="canonical" href="{href}">
+<meta name="robots" content="noindex">
+<meta http-equiv="refresh" content="0; url={href}">
+</head>
+<body>
+<p>Redirecting to <a href="{href}">{text}</a>.</p>
+</body>
+</html>
+"#,
+        href = href,
+        text = html_escape::encode_text(to)
+    )
+}
+
+/// Returns the path of the redirect stub of a path, relative to the public directory, e.g.
+/// `old-post/index.html` for `/old-post`.
+pub fn stub_path(from: &str) -> String {
+    let from = from.trim_start_matches('/');
+    if from.ends_with(".html") || from.ends_with(".htm") {
+        from.to_string()
+    } else if from.is_empty() {
+        "index.html".to_string()
+    } else {
+        format!("{}/index.html", from.trim_end_matches('/'))
+    }
+}
+
+/// Generates the `_redirects` file of Netlify and Cloudflare Pages.
+pub fn netlify_redirects(redirects: &[Redirect]) -> String {
+    let mut buf = String::new();
+    for redirect in redirects {
+        let _ = writeln!(buf, "{} {} {}", redirect.from, redirect.to, redirect.status);
+    }
+    buf
+}
+
+/// Generates the `_headers` file of Netlify and Cloudflare Pages.
+pub fn netlify_headers(rules: &[HeadersConfig]) -> String {
+    let mut buf = String::new();
+    for rule in rules {
+        let _ = writeln!(buf, "{}", rule.path);
+        for (name, value) in &rule.values {
+            let _ = writeln!(buf, "  {}: {}", name, value);
+        }
+    }
+    buf
+}
+
+/// Generates an nginx configuration snippet applying the redirects and headers.
+///
+/// Headers of every path (`/*`) are set for the whole server, the other ones in `location`
+/// blocks. nginx does not inherit the headers of the server in locations setting their own, so
+/// these blocks repeat them.
+pub fn nginx_config(redirects: &[Redirect], rules: &[HeadersConfig]) -> String {
+    let mut buf =
+        String::from("# Generated by Norgolith, include it in the server block of the site\n");
+    for redirect in redirects {
+        let _ = writeln!(
+            buf,
+            "location ~ \"{}\" {{ return {} {}; }}",
+            nginx_pattern(&redirect.from),
+            redirect.status,
+            nginx_quote(&redirect.to)
+        );
+    }
+
+    for (name, value) in merge_headers(rules.iter().filter(|rule| rule.path == "/*")) {
+        let _ = writeln!(buf, "add_header {} {} always;", name, nginx_quote(value));
+    }
+    let mut paths: Vec<&str> = Vec::new();
+    for rule in rules.iter().filter(|rule| rule.path != "/*") {
+        if paths.contains(&rule.path.as_str()) {
+            continue;
+        }
+        paths.push(&rule.path);
+        let (location, path) = match rule.path.strip_suffix('*') {
+            Some(prefix) => (prefix.to_string(), prefix),
+            None => (
+                format!("~ \"{}\"", nginx_pattern(&rule.path)),
+                rule.path.as_str(),
+            ),
+        };
+        let _ = writeln!(buf, "location {} {{", location);
+        for (name, value) in headers_for(rules, path) {
+            let _ = writeln!(
+                buf,
+                "    add_header {} {} always;",
+                name,
+                nginx_quote(value)
+            );
+        }
+        let _ = writeln!(buf, "}}");
+    }
+    buf
+}
+
+/// Returns the headers of the given rules, without the ones overridden by later rules.
+fn merge_headers<'a>(rules: impl Iterator<Item = &'a HeadersConfig>) -> Vec<(&'a str, &'a str)> {
+    let mut headers: Vec<(&str, &str)> = Vec::new();
+    for (name, value) in rules.flat_map(|rule| rule.values.iter()) {
+        headers.retain(|(other, _)| !other.eq_ignore_ascii_case(name));
+        headers.push((name, value));
+    }
+    headers
+}
+
+/// Normalizes a path so the paths of the same page compare equal, e.g. `/posts/hello`,
+/// `/posts/hello/` and `/posts/hello/index.html`.
+fn normalize_path(path: &str) -> String {
+    let path = match path.strip_suffix("index.html") {
+        Some(dir) if dir.is_empty() || dir.ends_with('/') => dir,
+        _ => path,
+    };
+    let path = path.trim_end_matches('/');
+    if path.starts_with('/') {
+        path.to_string()
+    } else {
+        format!("/{}", path)
+    }
+}
+
+/// Returns the path of a URL, e.g. `/posts/hello/` for `https://example.com/posts/hello/`.
+fn url_path(url: &str) -> &str {
+    match url.split_once("://") {
+        Some((_, rest)) => rest.find('/').map_or("/", |index| &rest[index..]),
+        None => url,
+    }
+}
+
+/// Returns the nginx regular expression matching the same paths as [`normalize_path`], e.g.
+/// `/about`, `/about/` and `/about/index.html` for `/about`.
+fn nginx_pattern(path: &str) -> String {
+    let path = normalize_path(path);
+    if path == "/" {
+        r"^/(index\.html)?$".to_string()
+    } else {
+        format!(r"^{}(/|/index\.html)?$", regex::escape(&path))
+    }
+}
+
+/// Quotes a value for the nginx configuration
+fn nginx_quote(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::*;
+    use crate::config::RedirectConfig;
+    use crate::converter::links::IndexedPage;
+
+    #[test]
+    fn test_collect_redirects() {
+        let mut links = LinkIndex::default();
+        links.insert(
+            Path::new("posts/hello.norg"),
+            IndexedPage {
+                permalink: "https://example.com/posts/hello/".into(),
+                aliases: vec!["/2020/hello/".into(), "/old".into()],
+                ..Default::default()
+            },
+        );
+        let config = SiteConfig {
+            redirects: vec![RedirectConfig {
+                from: "/old".into(),
+                to: "https://example.org/".into(),
+                status: 302,
+            }],
+            ..Default::default()
+        };
+
+        let redirects = collect(&config, &links);
+        assert_eq!(
+            redirects,
+            vec![
+                Redirect {
+                    from: "/2020/hello".into(),
+                    to: "/posts/hello/".into(),
+                    status: 301,
+                },
+                Redirect {
+                    from: "/old".into(),
+                    to: "https://example.org/".into(),
+                    status: 302,
+                },
+            ]
+        );
+        assert_eq!(
+            find(&redirects, "/2020/hello/index.html"),
+            Some(&redirects[0])
+        );
+        assert_eq!(find(&redirects, "/old/"), Some(&redirects[1]));
+        assert_eq!(find(&redirects, "/older"), None);
+        assert_eq!(normalize_path("/docs/myindex.html"), "/docs/myindex.html");
+        assert_eq!(normalize_path("index.html"), "/");
+
+        assert_eq!(stub_path("/2020/hello"), "2020/hello/index.html");
+        assert_eq!(stub_path("/old.html"), "old.html");
+        assert_eq!(
+            netlify_redirects(&redirects),
+            "/2020/hello /posts/hello/ 301\n/old https://example.org/ 302\n"
+        );
+        assert_eq!(
+            nginx_config(&redirects, &[])
+                .lines()
+                .skip(1)
+                .collect::<Vec<_>>(),
+            vec![
+                r#"location ~ "^/2020/hello(/|/index\.html)?$" { return 301 "/posts/hello/"; }"#,
+                r#"location ~ "^/old(/|/index\.html)?$" { return 302 "https://example.org/"; }"#,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_headers() {
+        let rules = vec![
+            HeadersConfig {
+                path: "/*".into(),
+                values: BTreeMap::from([("X-Frame-Options".into(), "DENY".into())]),
+            },
+            HeadersConfig {
+                path: "/assets/*".into(),
+                values: BTreeMap::from([(
+                    "Cache-Control".into(),
+                    "public, max-age=31536000".into(),
+                )]),
+            },
+        ];
+        assert_eq!(
+            headers_for(&rules, "/assets/css/style.css"),
+            vec![
+                ("X-Frame-Options", "DENY"),
+                ("Cache-Control", "public, max-age=31536000")
+            ]
+        );
+        assert_eq!(headers_for(&rules, "/about/").len(), 1);
+        assert_eq!(
+            netlify_headers(&rules),
+            "/*\n  X-Frame-Options: DENY\n/assets/*\n  Cache-Control: public, max-age=31536000\n"
+        );
+        assert_eq!(
+            nginx_config(&[], &rules)
+                .lines()
+                .skip(1)
+                .collect::<Vec<_>>(),
+            vec![
+                "add_header X-Frame-Options \"DENY\" always;",
+                "location /assets/ {",
+                "    add_header X-Frame-Options \"DENY\" always;",
+                "    add_header Cache-Control \"public, max-age=31536000\" always;",
+                "}",
+            ]
+        );
+
+        let about = vec![HeadersConfig {
+            path: "/about".into(),
+            values: BTreeMap::from([("X-Robots-Tag".into(), "noindex".into())]),
+        }];
+        assert_eq!(headers_for(&about, "/about/index.html").len(), 1);
+        assert_eq!(
+            nginx_config(&[], &about).lines().nth(1),
+            Some(r#"location ~ "^/about(/|/index\.html)?$" {"#)
+        );
+    }
+}
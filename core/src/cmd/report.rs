@@ -0,0 +1,160 @@
+//! Machine-readable build reports, written by `lith build --report json`.
+//!
+//! The report holds the timings of every build phase and page, the plugin hook timings, the
+//! cache statistics, the conversion diagnostics and the generated files, so CI jobs can track
+//! build regressions over time.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use colored::Colorize;
+use eyre::{eyre, Result};
+use serde::Serialize;
+
+use crate::converter::diagnostics::Diagnostic;
+
+/// Version of the report format, bump it on breaking changes
+const REPORT_VERSION: u32 = 1;
+
+/// Default report file name, relative to the site root
+pub const DEFAULT_REPORT_FILE: &str = "build-report.json";
+
+/// Format of the build report
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportFormat {
+    Json,
+}
+
+/// Build report settings, from the `--report` and `--report-file` options
+#[derive(Debug, Clone)]
+pub struct ReportOptions {
+    pub format: ReportFormat,
+    /// Report path, [`DEFAULT_REPORT_FILE`] in the site root when `None`
+    pub file: Option<PathBuf>,
+}
+
+/// Structured summary of a build
+#[derive(Debug, Serialize)]
+pub struct BuildReport {
+    pub version: u32,
+    /// Version of norgolith that built the site
+    pub norgolith: &'static str,
+    /// When the build started, in RFC 3339 format
+    pub started_at: String,
+    pub duration_ms: u128,
+    /// Duration of each build phase, in milliseconds
+    pub phases: BTreeMap<&'static str, u128>,
+    pub pages: Vec<PageReport>,
+    /// Total duration of the hooks of each plugin, in milliseconds
+    pub plugins: BTreeMap<String, u128>,
+    pub cache: CacheReport,
+    /// Conversion diagnostics of every page, including cached ones
+    pub warnings: Vec<Diagnostic>,
+    pub files: Vec<FileReport>,
+}
+
+/// A content page of the build
+#[derive(Debug, Clone, Serialize)]
+pub struct PageReport {
+    /// Source file, relative to the content directory
+    pub source: PathBuf,
+    /// Output file, relative to the public directory
+    pub output: PathBuf,
+    /// Time spent building the page, in milliseconds
+    pub duration_ms: f64,
+    /// Whether the page was rendered, `false` when it was unchanged since the last build
+    pub rendered: bool,
+}
+
+/// Build cache statistics
+#[derive(Debug, Default, Serialize)]
+pub struct CacheReport {
+    /// Number of content pages
+    pub pages: usize,
+    /// Pages whose conversion was read from the cache
+    pub hits: usize,
+    /// Pages converted by this build
+    pub misses: usize,
+    /// Pages that were not rendered again, as nothing they depend on changed
+    pub unchanged: usize,
+}
+
+/// A file of the public directory
+#[derive(Debug, Serialize)]
+pub struct FileReport {
+    /// Path relative to the public directory
+    pub path: PathBuf,
+    /// Size in bytes
+    pub size: u64,
+    /// blake3 hash of the file content
+    pub hash: String,
+}
+
+impl BuildReport {
+    /// Creates an empty report of a build
+    pub fn new(started_at: chrono::DateTime<chrono::Utc>, duration_ms: u128) -> Self {
+        Self {
+            version: REPORT_VERSION,
+            norgolith: env!("CARGO_PKG_VERSION"),
+            started_at: started_at.to_rfc3339(),
+            duration_ms,
+            phases: BTreeMap::new(),
+            pages: Vec::new(),
+            plugins: BTreeMap::new(),
+            cache: CacheReport::default(),
+            warnings: Vec::new(),
+            files: Vec::new(),
+        }
+    }
+
+    /// Writes the report to `path` in the given format
+    pub fn write(&self, format: ReportFormat, path: &Path) -> Result<()> {
+        let content = match format {
+            ReportFormat::Json => serde_json::to_string_pretty(self)
+                .map_err(|e| eyre!("{}: {}", "Failed to serialize build report".bold(), e))?,
+        };
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent).map_err(|e| {
+                eyre!(
+                    "{} {}: {}",
+                    "Failed to create report directory".bold(),
+                    parent.display(),
+                    e
+                )
+            })?;
+        }
+        std::fs::write(path, content).map_err(|e| {
+            eyre!(
+                "{} {}: {}",
+                "Failed to write build report".bold(),
+                path.display(),
+                e
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_json_report() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut report = BuildReport::new(chrono::Utc::now(), 10);
+        report.phases.insert("content", 8);
+        report.files.push(FileReport {
+            path: PathBuf::from("index.html"),
+            size: 14,
+            hash: blake3::hash(b"<h1>Hello</h1>").to_hex().to_string(),
+        });
+        let path = dir.path().join("reports/build.json");
+        report.write(ReportFormat::Json, &path).unwrap();
+
+        let json: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(json["phases"]["content"], 8);
+        assert_eq!(json["files"].as_array().unwrap().len(), 1);
+        assert_eq!(json["files"][0]["size"], 14);
+    }
+}
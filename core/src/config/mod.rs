@@ -1,14 +1,40 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 use colored::Colorize;
 use serde::{Deserialize, Serialize};
 
 use crate::schema::ContentSchema;
 
+/// Output of the `builtin` highlighter engine
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum HighlightStyle {
+    /// Emit class names and generate a theme stylesheet in `public/assets/highlight.css`
+    #[default]
+    #[serde(rename = "classes")]
+    Classes,
+    /// Emit inline `style` attributes, no stylesheet needed
+    #[serde(rename = "inline")]
+    Inline,
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct SiteConfigHighlighter {
     pub enable: bool,
     pub engine: Option<String>, // fallbacks to prism if not defined
+    /// Theme used by the `builtin` engine
+    #[serde(default)]
+    pub theme: Option<String>,
+    #[serde(default)]
+    pub style: HighlightStyle,
+    #[serde(default)]
+    pub line_numbers: bool,
+}
+
+impl SiteConfigHighlighter {
+    /// Whether code blocks are highlighted at build time instead of client-side
+    pub fn is_builtin(&self) -> bool {
+        self.enable && self.engine.as_deref() == Some("builtin")
+    }
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -48,6 +74,162 @@ pub enum RobotsPreset {
     BlockAll,
 }
 
+/// Math rendering strategy for `@math` blocks and inline `$...$` math
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum MathRenderer {
+    /// Convert math to MathML at build time, no JavaScript required
+    #[default]
+    #[serde(rename = "mathml")]
+    MathMl,
+    /// Emit KaTeX auto-render delimiters so math is rendered client-side
+    #[serde(rename = "katex")]
+    Katex,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct SiteConfigMath {
+    #[serde(default)]
+    pub renderer: MathRenderer,
+}
+
+/// How heading titles are turned into heading ids
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum SlugStrategy {
+    /// Lowercase ASCII, unicode characters are transliterated (`Ünïcode Title` -> `unicode-title`)
+    #[default]
+    #[serde(rename = "ascii")]
+    Ascii,
+    /// Lowercase, unicode letters and digits are kept as-is (`Ünïcode Title` -> `ünïcode-title`)
+    #[serde(rename = "unicode")]
+    Unicode,
+    /// Title as written with spaces replaced by dashes, close to the ids of older Norgolith versions
+    #[serde(rename = "preserve")]
+    Preserve,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct SiteConfigHeadings {
+    #[serde(default)]
+    pub slug: SlugStrategy,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SiteConfigFootnotes {
+    /// Whether the converted content ends with the footnotes section, themes placing
+    /// `metadata.footnotes` themselves turn it off
+    #[serde(default = "default_true")]
+    pub section: bool,
+}
+
+/// Modern formats generated by the responsive image pipeline, besides the original format
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub enum ImageFormat {
+    #[serde(rename = "webp")]
+    WebP,
+    #[serde(rename = "avif")]
+    Avif,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct SiteConfigImages {
+    #[serde(default = "default_true")]
+    pub enable: bool,
+    /// Widths of the resized variants, widths larger than the original image are skipped
+    #[serde(default = "default_image_widths")]
+    pub widths: Vec<u32>,
+    /// Modern formats generated besides the original format. None by default, WebP variants
+    /// are lossless and often larger than the JPEG originals
+    #[serde(default = "default_image_formats")]
+    pub formats: Vec<ImageFormat>,
+    /// Value of the `sizes` attribute
+    #[serde(default = "default_image_sizes")]
+    pub sizes: String,
+    /// Encoding quality (1-100) of JPEG and AVIF variants, WebP variants are lossless
+    #[serde(default = "default_image_quality")]
+    pub quality: u8,
+    /// Whether images are lazy loaded
+    #[serde(default = "default_true")]
+    pub lazy: bool,
+}
+
+impl Default for SiteConfigImages {
+    fn default() -> Self {
+        Self {
+            enable: true,
+            widths: default_image_widths(),
+            formats: default_image_formats(),
+            sizes: default_image_sizes(),
+            quality: default_image_quality(),
+            lazy: true,
+        }
+    }
+}
+
+fn default_image_widths() -> Vec<u32> {
+    vec![480, 960, 1440]
+}
+
+fn default_image_formats() -> Vec<ImageFormat> {
+    Vec::new()
+}
+
+fn default_image_sizes() -> String {
+    "100vw".into()
+}
+
+fn default_image_quality() -> u8 {
+    80
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+pub struct SiteConfigAssets {
+    /// Whether CSS, JS and image assets get content-hashed file names on builds
+    #[serde(default)]
+    pub fingerprint: bool,
+    /// Browserslist query of the browsers stylesheets are prefixed for, e.g. `"defaults"`
+    pub browserslist: Option<String>,
+    /// JavaScript/TypeScript entry points bundled into single files, e.g. `"js/main.ts"`
+    #[serde(default)]
+    pub entries: Vec<String>,
+}
+
+/// Settings of the `lith dev` server
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+pub struct SiteConfigDev {
+    /// Path prefixes forwarded to upstream servers, e.g. `"/api" = "http://localhost:8000"`
+    #[serde(default)]
+    pub proxy: BTreeMap<String, String>,
+}
+
+/// A moved page, redirected from its former path
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct RedirectConfig {
+    /// Former path of the page, e.g. `"/old-post"`
+    pub from: String,
+    /// Path or URL the page is redirected to
+    pub to: String,
+    /// HTTP status of the redirect, `301` by default
+    #[serde(default = "default_redirect_status")]
+    pub status: u16,
+}
+
+/// Custom response headers of the paths matching a pattern
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct HeadersConfig {
+    /// Exact path, or path prefix ending with `*` (e.g. `"/assets/*"`)
+    #[serde(alias = "for")]
+    pub path: String,
+    /// Header names mapped to their values
+    pub values: BTreeMap<String, String>,
+}
+
+/// HTTP statuses redirects can use
+const REDIRECT_STATUSES: [u16; 5] = [301, 302, 303, 307, 308];
+
+fn default_redirect_status() -> u16 {
+    301
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct CollectionConfig {
     pub name: String,
@@ -88,6 +270,25 @@ pub struct SiteConfig {
     #[serde(default)]
     pub seo: Option<SiteConfigSeo>,
     pub robots: Option<SiteConfigRobots>,
+    #[serde(default)]
+    pub math: Option<SiteConfigMath>,
+    #[serde(default)]
+    pub headings: Option<SiteConfigHeadings>,
+    #[serde(default)]
+    pub footnotes: Option<SiteConfigFootnotes>,
+    #[serde(default)]
+    pub images: Option<SiteConfigImages>,
+    #[serde(default)]
+    pub assets: Option<SiteConfigAssets>,
+    #[serde(default)]
+    pub dev: Option<SiteConfigDev>,
+    #[serde(default)]
+    pub redirects: Vec<RedirectConfig>,
+    #[serde(default)]
+    pub headers: Vec<HeadersConfig>,
+    /// Inline macro definitions: macro name → Tera snippet
+    #[serde(default)]
+    pub macros: Option<HashMap<String, String>>,
 }
 
 impl Default for SiteConfig {
@@ -105,6 +306,15 @@ impl Default for SiteConfig {
             categories_dir: default_categories_dir(),
             seo: None,
             robots: None,
+            math: None,
+            headings: None,
+            footnotes: None,
+            images: None,
+            assets: None,
+            dev: None,
+            redirects: Vec::new(),
+            headers: Vec::new(),
+            macros: None,
         }
     }
 }
@@ -137,6 +347,90 @@ impl SiteConfig {
                 "Validation failed".bold()
             ));
         }
+        if let Some(theme) = self
+            .highlighter
+            .as_ref()
+            .filter(|h| h.is_builtin())
+            .and_then(|h| h.theme.as_deref())
+        {
+            let themes = crate::converter::highlight::theme_names();
+            if !themes.contains(&theme) {
+                errors.push(format!(
+                    "{}: unknown 'highlighter.theme' '{}', available themes: {}",
+                    "Validation failed".bold(),
+                    theme,
+                    themes.join(", ")
+                ));
+            }
+        }
+
+        if let Some(images) = &self.images {
+            if images.widths.contains(&0) {
+                errors.push(format!(
+                    "{}: 'images.widths' must only contain positive widths",
+                    "Validation failed".bold()
+                ));
+            }
+            if !(1..=100).contains(&images.quality) {
+                errors.push(format!(
+                    "{}: 'images.quality' must be between 1 and 100",
+                    "Validation failed".bold()
+                ));
+            }
+        }
+
+        if let Some(dev) = &self.dev {
+            for (prefix, upstream) in &dev.proxy {
+                if !prefix.starts_with('/') {
+                    errors.push(format!(
+                        "{}: 'dev.proxy' path prefix '{}' must start with '/'",
+                        "Validation failed".bold(),
+                        prefix
+                    ));
+                }
+                if !upstream.starts_with("http://") {
+                    errors.push(format!(
+                        "{}: 'dev.proxy' upstream '{}' must be an http:// URL",
+                        "Validation failed".bold(),
+                        upstream
+                    ));
+                }
+            }
+        }
+
+        for redirect in &self.redirects {
+            if !redirect.from.starts_with('/') {
+                errors.push(format!(
+                    "{}: redirect 'from' path '{}' must start with '/'",
+                    "Validation failed".bold(),
+                    redirect.from
+                ));
+            }
+            if redirect.to.is_empty() {
+                errors.push(format!(
+                    "{}: redirect 'to' of '{}' must not be empty",
+                    "Validation failed".bold(),
+                    redirect.from
+                ));
+            }
+            if !REDIRECT_STATUSES.contains(&redirect.status) {
+                errors.push(format!(
+                    "{}: redirect status {} of '{}' must be one of 301, 302, 303, 307 or 308",
+                    "Validation failed".bold(),
+                    redirect.status,
+                    redirect.from
+                ));
+            }
+        }
+        for headers in &self.headers {
+            if !headers.path.starts_with('/') {
+                errors.push(format!(
+                    "{}: headers path '{}' must start with '/'",
+                    "Validation failed".bold(),
+                    headers.path
+                ));
+            }
+        }
 
         errors
     }
@@ -0,0 +1,189 @@
+use std::fmt;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+/// Kinds of problems found while converting a Norg document
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum DiagnosticKind {
+    /// The document could not be parsed at all
+    ParseError,
+    /// A node kind the converter does not know how to render yet
+    UnsupportedNode,
+    /// An attached modifier other than the standard Norg ones
+    UnsupportedModifier,
+    /// A link target kind that cannot be turned into an URL
+    UnsupportedLink,
+    /// A ranged, verbatim or infirm tag without a converter implementation
+    UnsupportedTag,
+    /// A strong carryover tag (macro invocation) without a matching macro
+    UnsupportedMacro,
+    /// A macro or shortcode that failed to render
+    MacroError,
+    /// A link to a file or heading that does not exist
+    BrokenLink,
+    /// A local image that could not be read or resized
+    ImageError,
+    /// An image without alt text
+    MissingAlt,
+    /// A math expression that could not be converted to MathML
+    MathError,
+}
+
+impl fmt::Display for DiagnosticKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = match self {
+            DiagnosticKind::ParseError => "parse-error",
+            DiagnosticKind::UnsupportedNode => "unsupported-node",
+            DiagnosticKind::UnsupportedModifier => "unsupported-modifier",
+            DiagnosticKind::UnsupportedLink => "unsupported-link",
+            DiagnosticKind::UnsupportedTag => "unsupported-tag",
+            DiagnosticKind::UnsupportedMacro => "unsupported-macro",
+            DiagnosticKind::MacroError => "macro-error",
+            DiagnosticKind::BrokenLink => "broken-link",
+            DiagnosticKind::ImageError => "image-error",
+            DiagnosticKind::MissingAlt => "missing-alt",
+            DiagnosticKind::MathError => "math-error",
+        };
+        write!(f, "{}", kind)
+    }
+}
+
+/// 1-based line/column range in the source document
+///
+/// The rust-norg AST does not carry source positions, so spans are located on a best-effort
+/// basis by searching the node's source text (see [`SourceLocator`]).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Span {
+    pub start_line: usize,
+    pub start_column: usize,
+    pub end_line: usize,
+    pub end_column: usize,
+}
+
+impl fmt::Display for Span {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.start_line, self.start_column)
+    }
+}
+
+/// A single conversion problem
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Diagnostic {
+    pub kind: DiagnosticKind,
+    /// Source file relative to the content directory, empty until the caller fills it in
+    #[serde(default)]
+    pub file: PathBuf,
+    pub span: Span,
+    /// Human readable description of the offending node (e.g. `infirm tag '.toc'`)
+    pub node: String,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.file.as_os_str().is_empty() {
+            write!(f, "{} {}: {}", self.span, self.kind, self.node)
+        } else {
+            write!(
+                f,
+                "{}:{} {}: {}",
+                self.file.display(),
+                self.span,
+                self.kind,
+                self.node
+            )
+        }
+    }
+}
+
+/// Finds approximate source positions for AST nodes
+///
+/// Nodes are converted in document order, so every lookup starts searching from the end of the
+/// previous match. This keeps repeated snippets (e.g. two `.toc` tags) pointing at distinct lines.
+#[derive(Debug)]
+pub struct SourceLocator<'a> {
+    source: &'a str,
+    cursor: usize,
+}
+
+impl<'a> SourceLocator<'a> {
+    pub fn new(source: &'a str) -> Self {
+        Self { source, cursor: 0 }
+    }
+
+    /// Returns the span of the next occurrence of `needle`, falling back to the last known
+    /// position when it cannot be found
+    pub fn locate(&mut self, needle: &str) -> Span {
+        let found = if needle.is_empty() {
+            None
+        } else {
+            self.source[self.cursor..]
+                .find(needle)
+                .map(|offset| self.cursor + offset)
+                .or_else(|| self.source.find(needle))
+        };
+
+        match found {
+            Some(start) => {
+                let end = start + needle.len();
+                self.cursor = end;
+                let (start_line, start_column) = self.position(start);
+                let (end_line, end_column) = self.position(end);
+                Span {
+                    start_line,
+                    start_column,
+                    end_line,
+                    end_column,
+                }
+            }
+            None => {
+                let (line, column) = self.position(self.cursor);
+                Span {
+                    start_line: line,
+                    start_column: column,
+                    end_line: line,
+                    end_column: column,
+                }
+            }
+        }
+    }
+
+    /// Converts a byte offset into a 1-based (line, column) pair
+    fn position(&self, offset: usize) -> (usize, usize) {
+        let before = &self.source[..offset];
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map(|idx| idx + 1).unwrap_or(0);
+        let column = before[line_start..].chars().count() + 1;
+        (line, column)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_locate_sequential_occurrences() {
+        let source = "* Title\n.toc\nsome text\n.toc\n";
+        let mut locator = SourceLocator::new(source);
+
+        let first = locator.locate(".toc");
+        assert_eq!((first.start_line, first.start_column), (2, 1));
+        assert_eq!((first.end_line, first.end_column), (2, 5));
+
+        let second = locator.locate(".toc");
+        assert_eq!((second.start_line, second.start_column), (4, 1));
+    }
+
+    #[test]
+    fn test_locate_missing_needle_uses_cursor() {
+        let source = "first line\n{? wiki link}\n";
+        let mut locator = SourceLocator::new(source);
+        locator.locate("{?");
+
+        let span = locator.locate("@unknown");
+        assert_eq!((span.start_line, span.start_column), (2, 3));
+        assert_eq!(span.start_line, span.end_line);
+    }
+}
@@ -0,0 +1,308 @@
+//! Build-time syntax highlighting for `@code` blocks, used by the `builtin` highlighter engine.
+
+use std::sync::OnceLock;
+
+use eyre::{eyre, Result};
+use syntect::easy::HighlightLines;
+use syntect::highlighting::{Color, Theme, ThemeSet};
+use syntect::html::{
+    css_for_theme_with_class_style, line_tokens_to_classed_spans, styled_line_to_highlighted_html,
+    ClassStyle, IncludeBackground,
+};
+use syntect::parsing::{ParseState, ScopeStack, SyntaxReference, SyntaxSet};
+use syntect::util::LinesWithEndings;
+
+pub use crate::config::HighlightStyle;
+
+/// Theme used when `highlighter.theme` is not set
+pub const DEFAULT_THEME: &str = "base16-ocean.dark";
+
+/// Path of the generated theme stylesheet, relative to the public directory
+pub const CSS_PATH: &str = "assets/highlight.css";
+
+/// Prefix added to every generated class name to avoid collisions with site styles
+const CLASS_PREFIX: &str = "hl-";
+const CLASS_STYLE: ClassStyle = ClassStyle::SpacedPrefixed {
+    prefix: CLASS_PREFIX,
+};
+
+fn syntax_set() -> &'static SyntaxSet {
+    static SYNTAXES: OnceLock<SyntaxSet> = OnceLock::new();
+    SYNTAXES.get_or_init(SyntaxSet::load_defaults_newlines)
+}
+
+fn theme_set() -> &'static ThemeSet {
+    static THEMES: OnceLock<ThemeSet> = OnceLock::new();
+    THEMES.get_or_init(ThemeSet::load_defaults)
+}
+
+/// Site-wide options of the builtin highlighter
+#[derive(Clone, Debug)]
+pub struct HighlightOptions {
+    pub theme: String,
+    pub style: HighlightStyle,
+    /// Show line numbers on every code block, can be overridden per block
+    pub line_numbers: bool,
+}
+
+/// Per-block options, set through `+code.*` carryover tags
+#[derive(Clone, Debug, Default)]
+pub struct CodeBlockOptions {
+    pub line_numbers: Option<bool>,
+    /// 1-based, inclusive line ranges to highlight
+    pub highlighted: Vec<(usize, usize)>,
+}
+
+impl CodeBlockOptions {
+    fn is_highlighted(&self, line: usize) -> bool {
+        self.highlighted
+            .iter()
+            .any(|(start, end)| (*start..=*end).contains(&line))
+    }
+}
+
+/// Returns the names of the bundled themes
+pub fn theme_names() -> Vec<&'static str> {
+    theme_set().themes.keys().map(String::as_str).collect()
+}
+
+fn theme(name: &str) -> Result<&'static Theme> {
+    theme_set().themes.get(name).ok_or_else(|| {
+        eyre!(
+            "unknown highlighter theme '{}', available themes: {}",
+            name,
+            theme_names().join(", ")
+        )
+    })
+}
+
+/// Parses line ranges like `["1-3", "5"]` or `["1-3,5"]` into inclusive ranges
+///
+/// Invalid ranges are returned as an error so the caller can report them.
+pub fn parse_line_ranges(parameters: &[String]) -> Result<Vec<(usize, usize)>, String> {
+    let mut ranges = Vec::new();
+    for part in parameters
+        .iter()
+        .flat_map(|param| param.split(','))
+        .map(str::trim)
+        .filter(|part| !part.is_empty())
+    {
+        let (start, end) = match part.split_once('-') {
+            Some((start, end)) => (start.trim().parse(), end.trim().parse()),
+            None => (part.parse(), part.parse()),
+        };
+        match (start, end) {
+            (Ok(start), Ok(end)) if start >= 1 && start <= end => ranges.push((start, end)),
+            _ => return Err(part.to_string()),
+        }
+    }
+    Ok(ranges)
+}
+
+fn find_syntax(language: Option<&str>) -> &'static SyntaxReference {
+    let syntaxes = syntax_set();
+    language
+        .and_then(|lang| syntaxes.find_syntax_by_token(lang))
+        .unwrap_or_else(|| syntaxes.find_syntax_plain_text())
+}
+
+fn css_color(color: Color) -> String {
+    format!("#{:02x}{:02x}{:02x}", color.r, color.g, color.b)
+}
+
+/// Highlights a code block and returns the full `<pre><code>` markup
+///
+/// `attributes` are extra HTML attributes (e.g. from `+html.*` carryover tags) added to the
+/// `<pre>` element. Every line is wrapped in a `<span class="line">` so line numbers and
+/// highlighted lines can be styled.
+pub fn highlight_code(
+    code: &str,
+    language: Option<&str>,
+    options: &HighlightOptions,
+    block: &CodeBlockOptions,
+    attributes: &str,
+) -> Result<String> {
+    let theme = theme(&options.theme)?;
+    let syntax = find_syntax(language);
+    let line_numbers = block.line_numbers.unwrap_or(options.line_numbers);
+
+    let lines = match options.style {
+        HighlightStyle::Classes => classed_lines(code, syntax)?,
+        HighlightStyle::Inline => inline_lines(code, syntax, theme)?,
+    };
+
+    let mut html = String::from("<pre class=\"");
+    html.push_str(CLASS_PREFIX);
+    html.push_str("code");
+    if line_numbers {
+        html.push_str(" line-numbers");
+    }
+    html.push('"');
+    if options.style == HighlightStyle::Inline {
+        html.push_str(" style=\"");
+        if let Some(bg) = theme.settings.background {
+            html.push_str("background-color:");
+            html.push_str(&css_color(bg));
+            html.push(';');
+        }
+        if let Some(fg) = theme.settings.foreground {
+            html.push_str("color:");
+            html.push_str(&css_color(fg));
+            html.push(';');
+        }
+        html.push('"');
+    }
+    if !attributes.is_empty() {
+        html.push(' ');
+        html.push_str(attributes);
+    }
+    html.push_str("><code");
+    if let Some(lang) = language {
+        html.push_str(" class=\"language-");
+        html.push_str(lang);
+        html.push('"');
+    }
+    html.push('>');
+
+    for (idx, line) in lines.iter().enumerate() {
+        let number = idx + 1;
+        let highlighted = block.is_highlighted(number);
+        html.push_str("<span class=\"line");
+        if highlighted {
+            html.push_str(" highlighted");
+        }
+        html.push('"');
+        if highlighted && options.style == HighlightStyle::Inline {
+            if let Some(color) = theme.settings.line_highlight {
+                html.push_str(" style=\"background-color:");
+                html.push_str(&css_color(color));
+                html.push('"');
+            }
+        }
+        html.push('>');
+        if line_numbers {
+            html.push_str("<span class=\"line-number\" aria-hidden=\"true\">");
+            html.push_str(&number.to_string());
+            html.push_str("</span>");
+        }
+        html.push_str(line);
+        html.push_str("</span>\n");
+    }
+    html.push_str("</code></pre>");
+
+    Ok(html)
+}
+
+/// Highlights every line using class names, closing and re-opening the scope spans at line
+/// boundaries so each line is a self-contained fragment
+fn classed_lines(code: &str, syntax: &SyntaxReference) -> Result<Vec<String>> {
+    let mut parse_state = ParseState::new(syntax);
+    let mut stack = ScopeStack::new();
+    let mut lines = Vec::new();
+
+    for line in LinesWithEndings::from(code) {
+        let ops = parse_state.parse_line(line, syntax_set())?;
+        let mut html = String::new();
+        // Re-open the scopes that are still open from the previous line
+        for scope in stack.as_slice() {
+            html.push_str("<span class=\"");
+            html.push_str(&scope_classes(&scope.build_string()));
+            html.push_str("\">");
+        }
+        let (spans, _) = line_tokens_to_classed_spans(line, &ops, CLASS_STYLE, &mut stack)?;
+        html.push_str(&spans);
+        html.push_str(&"</span>".repeat(stack.len()));
+        lines.push(html.replace('\n', ""));
+    }
+
+    Ok(lines)
+}
+
+/// Converts a scope name (`source.rust`) into its prefixed classes (`hl-source hl-rust`)
+fn scope_classes(scope: &str) -> String {
+    scope
+        .split('.')
+        .map(|atom| format!("{}{}", CLASS_PREFIX, atom))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Highlights every line using inline styles from the theme
+fn inline_lines(code: &str, syntax: &SyntaxReference, theme: &Theme) -> Result<Vec<String>> {
+    let mut highlighter = HighlightLines::new(syntax, theme);
+    let mut lines = Vec::new();
+
+    for line in LinesWithEndings::from(code) {
+        let regions = highlighter.highlight_line(line, syntax_set())?;
+        let html = styled_line_to_highlighted_html(&regions, IncludeBackground::No)?;
+        lines.push(html.replace('\n', ""));
+    }
+
+    Ok(lines)
+}
+
+/// Generates the stylesheet for the class-based output of the given theme
+pub fn theme_css(theme_name: &str) -> Result<String> {
+    let theme = theme(theme_name)?;
+    let mut css = css_for_theme_with_class_style(theme, CLASS_STYLE)?;
+
+    css.push_str("\n.hl-code .line { display: inline-block; width: 100%; }\n");
+    if let Some(color) = theme.settings.line_highlight {
+        css.push_str(&format!(
+            ".hl-code .line.highlighted {{ background-color: {}; }}\n",
+            css_color(color)
+        ));
+    }
+    let gutter = theme
+        .settings
+        .gutter_foreground
+        .or(theme.settings.foreground)
+        .map(css_color)
+        .unwrap_or_else(|| String::from("inherit"));
+    css.push_str(&format!(
+        ".hl-code .line-number {{ display: inline-block; min-width: 2.5em; padding-right: 1em; text-align: right; color: {}; opacity: 0.6; user-select: none; }}\n",
+        gutter
+    ));
+
+    Ok(css)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_line_ranges() {
+        let params = vec![String::from("1-3,5"), String::from("8")];
+        assert_eq!(parse_line_ranges(&params), Ok(vec![(1, 3), (5, 5), (8, 8)]));
+        assert_eq!(
+            parse_line_ranges(&[String::from("4-2")]),
+            Err(String::from("4-2"))
+        );
+    }
+
+    #[test]
+    fn test_highlight_wraps_lines() {
+        let options = HighlightOptions {
+            theme: DEFAULT_THEME.into(),
+            style: HighlightStyle::Classes,
+            line_numbers: true,
+        };
+        let block = CodeBlockOptions {
+            line_numbers: None,
+            highlighted: vec![(2, 2)],
+        };
+        let html = highlight_code(
+            "/* a\n b */\nfn main() {}\n",
+            Some("rust"),
+            &options,
+            &block,
+            "",
+        )
+        .unwrap();
+
+        assert_eq!(html.matches("class=\"line-number\"").count(), 3);
+        assert!(html.contains("<span class=\"line highlighted\">"));
+        assert_eq!(html.matches("<span").count(), html.matches("</span>").count());
+    }
+}
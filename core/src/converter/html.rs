@@ -6,16 +6,26 @@
 // BUG: currently, strong carryover tags AST is missing a lot of things in the rust-norg parser
 // so we are going to omit them for now until it's fixed.
 
-use std::collections::VecDeque;
-use std::sync::OnceLock;
+use std::collections::{HashMap, VecDeque};
+use std::path::PathBuf;
+use std::sync::Arc;
 
-use html_escape::encode_text_minimal_to_string;
-use regex::Regex;
+use html_escape::{encode_double_quoted_attribute_to_string, encode_text_minimal_to_string};
 use rust_norg::{
-    parse_tree, CarryoverTag, DelimitingModifier, LinkTarget, NestableDetachedModifier, NorgAST,
-    NorgASTFlat, ParagraphSegment, ParagraphSegmentToken,
+    parse_tree, CarryoverTag, DelimitingModifier, DetachedModifierExtension, LinkTarget,
+    NestableDetachedModifier, NorgAST, NorgASTFlat, ParagraphSegment, ParagraphSegmentToken,
+    RangeableDetachedModifier, TodoStatus,
 };
-use tracing::{error, info, warn};
+use tracing::{debug, error, warn};
+
+use crate::config::MathRenderer;
+use crate::converter::diagnostics::{Diagnostic, DiagnosticKind, SourceLocator};
+use crate::converter::highlight::{self, CodeBlockOptions, HighlightOptions};
+use crate::converter::images::{ImagePipeline, ProcessedImage};
+use crate::converter::links::{LinkIndex, ResolvedLink};
+use crate::converter::macros::Macros;
+use crate::converter::shortcodes::Shortcodes;
+use crate::converter::slug::{self, SlugStrategy, Slugger};
 
 /// CarryOver
 #[derive(Clone, Debug)]
@@ -32,9 +42,412 @@ pub struct TocEntry {
     id: String,
 }
 
-fn inline_re() -> &'static Regex {
-    static RE: OnceLock<Regex> = OnceLock::new();
-    RE.get_or_init(|| Regex::new(r"-?<.*>").expect("valid regex"))
+/// Footnote entries, numbered in order of first appearance
+#[derive(Clone, Debug)]
+pub struct Footnote {
+    number: usize,
+    title: String,
+    content: String,
+    references: usize,
+}
+
+/// Result of converting a Norg document to HTML
+#[derive(Clone, Debug, Default)]
+pub struct ConvertedDocument {
+    pub html: String,
+    pub toc: Vec<TocEntry>,
+    pub footnotes: Vec<Footnote>,
+    /// Unsupported or malformed nodes found during the conversion
+    pub diagnostics: Vec<Diagnostic>,
+    /// Local images processed by the responsive image pipeline
+    pub images: Vec<PageImage>,
+    /// Link targets looked up in the site index
+    pub links: Vec<ResolvedLink>,
+}
+
+/// A local image used by a page
+#[derive(Clone, Debug)]
+pub struct PageImage {
+    src: String,
+    width: u32,
+    height: u32,
+    /// Hash of the image content, so cached pages are converted again when it changes
+    hash: String,
+}
+
+/// Options that change how Norg documents are converted to HTML
+#[derive(Clone, Debug, Default)]
+pub struct ConvertOptions {
+    /// URL prepended to local links and images
+    pub root_url: String,
+    /// How `@math` blocks and inline math are rendered
+    pub math: MathRenderer,
+    /// Build-time highlighting of `@code` blocks, `None` leaves it to client-side engines
+    pub highlight: Option<HighlightOptions>,
+    /// Index of the site pages used to resolve and validate internal links. Without it, file
+    /// links are only prefixed with `root_url`
+    pub links: Option<Arc<LinkIndex>>,
+    /// Path of the converted document relative to the content directory
+    pub source_path: PathBuf,
+    /// How heading titles are turned into heading ids
+    pub slug: SlugStrategy,
+    /// Leaves the footnotes section out of the HTML, for themes placing the footnotes themselves
+    pub omit_footnotes: bool,
+    /// User-defined macros, expanded from strong carryover tags (`#name args`)
+    pub macros: Option<Arc<Macros>>,
+    /// Templates rendered by `.shortcode` infirm tags and ranged tags
+    pub shortcodes: Option<Arc<Shortcodes>>,
+    /// Responsive image pipeline used by `.image` tags pointing to local images
+    pub images: Option<Arc<ImagePipeline>>,
+}
+
+impl ConvertOptions {
+    /// Resolves internal links using the given site index
+    pub fn with_links(mut self, links: Arc<LinkIndex>) -> Self {
+        self.links = Some(links);
+        self
+    }
+
+    /// Expands strong carryover tags using the given macros
+    pub fn with_macros(mut self, macros: Arc<Macros>) -> Self {
+        self.macros = Some(macros);
+        self
+    }
+
+    /// Renders shortcodes using the given templates
+    pub fn with_shortcodes(mut self, shortcodes: Arc<Shortcodes>) -> Self {
+        self.shortcodes = Some(shortcodes);
+        self
+    }
+
+    /// Generates responsive variants of local images using the given pipeline
+    pub fn with_images(mut self, images: Arc<ImagePipeline>) -> Self {
+        self.images = Some(images);
+        self
+    }
+
+    /// Hash of the site-wide inputs that can change the output of a page without changing its
+    /// source (the macros, the shortcodes and the image settings)
+    ///
+    /// The link index and the images are not part of it, pages record the link targets and the
+    /// images they use instead (see [`LinkIndex::is_current`] and [`ImagePipeline::register`]).
+    pub fn fingerprint(&self) -> String {
+        let mut hasher = blake3::Hasher::new();
+        if let Some(macros) = &self.macros {
+            hasher.update(macros.fingerprint().as_bytes());
+        }
+        if let Some(shortcodes) = &self.shortcodes {
+            hasher.update(shortcodes.fingerprint().as_bytes());
+        }
+        if let Some(images) = &self.images {
+            hasher.update(images.fingerprint().as_bytes());
+        }
+        hasher.finalize().to_hex().to_string()
+    }
+}
+
+/// Mutable state shared across the whole conversion of a single document
+struct ConvertState<'a> {
+    options: &'a ConvertOptions,
+    toc: Vec<TocEntry>,
+    footnotes: Vec<Footnote>,
+    diagnostics: Vec<Diagnostic>,
+    locator: SourceLocator<'a>,
+    /// Unique ids of the headings converted so far
+    slugger: Slugger,
+    images: Vec<PageImage>,
+    links: Vec<ResolvedLink>,
+}
+
+impl<'a> ConvertState<'a> {
+    fn new(source: &'a str, options: &'a ConvertOptions) -> Self {
+        Self {
+            options,
+            toc: Vec::new(),
+            footnotes: Vec::new(),
+            diagnostics: Vec::new(),
+            locator: SourceLocator::new(source),
+            slugger: Slugger::new(options.slug),
+            images: Vec::new(),
+            links: Vec::new(),
+        }
+    }
+
+    /// Returns the id a link to the heading with the given title points to
+    ///
+    /// Repeated headings get a suffixed id, but links select headings by title so they always
+    /// point to the first one. The suffixed ids cannot be linked to, see the headings docs.
+    fn heading_anchor(&self, title: &str) -> String {
+        slug::slugify(title, self.options.slug)
+    }
+
+    /// Records a diagnostic for an unsupported node and returns a visible placeholder for it
+    ///
+    /// `needle` is a snippet of the node's source text used to locate it in the document.
+    fn unsupported(
+        &mut self,
+        kind: DiagnosticKind,
+        needle: &str,
+        node: String,
+        block: bool,
+    ) -> String {
+        let placeholder = unsupported_placeholder(&node, block);
+        self.report(kind, needle, node);
+        placeholder
+    }
+
+    /// Records a diagnostic, `needle` is used to locate the node in the document
+    fn report(&mut self, kind: DiagnosticKind, needle: &str, node: String) {
+        debug!("[converter] {}: {}", kind, node);
+        let span = self.locator.locate(needle);
+        self.diagnostics.push(Diagnostic {
+            kind,
+            file: Default::default(),
+            span,
+            node,
+        });
+    }
+
+    fn broken_link(&mut self, needle: &str, node: String) {
+        self.report(DiagnosticKind::BrokenLink, needle, node);
+    }
+
+    /// Records a link target looked up in the site index, once
+    fn record_link(&mut self, link: ResolvedLink) {
+        if !self.links.contains(&link) {
+            self.links.push(link);
+        }
+    }
+
+    /// Resolves a link to another Norg file, and optionally to one of its headings, into an URL
+    fn resolve_file_link(&mut self, path: &str, anchor: Option<&str>) -> String {
+        let fragment = anchor.map(|id| format!("#{}", id)).unwrap_or_default();
+        let Some(links) = self.options.links.clone() else {
+            return format!("{}{}{}", self.options.root_url, path, fragment);
+        };
+
+        self.record_link(links.file_link(&self.options.source_path, path));
+        let needle = format!("{{:{}:", path);
+        match links.resolve(&self.options.source_path, path) {
+            Some(page) => {
+                if let Some(id) = anchor {
+                    if !page.has_heading(id) {
+                        self.broken_link(
+                            &needle,
+                            format!("link to missing heading '{}' in '{}'", id, path),
+                        );
+                    }
+                }
+                format!("{}{}", page.permalink, fragment)
+            }
+            None => {
+                self.broken_link(&needle, format!("link to missing file '{}'", path));
+                format!("{}{}{}", self.options.root_url, path, fragment)
+            }
+        }
+    }
+
+    /// Resolves a link to a heading, in the current file when `filepath` is `None`
+    fn resolve_heading_link(&mut self, filepath: Option<&str>, title: &str) -> String {
+        let anchor = self.heading_anchor(title);
+        if let Some(path) = filepath {
+            return self.resolve_file_link(path, Some(&anchor));
+        }
+
+        if let Some(links) = self.options.links.clone() {
+            let exists = links
+                .page(&self.options.source_path)
+                .is_none_or(|page| page.has_heading(&anchor));
+            if !exists {
+                self.broken_link(
+                    &format!("{{* {}", title),
+                    format!("link to missing heading '{}'", title),
+                );
+            }
+        }
+        format!("#{}", anchor)
+    }
+
+    /// Resolves a wiki link by looking for a page title or heading in the whole site
+    fn resolve_wiki_link(&mut self, filepath: Option<&str>, title: &str) -> String {
+        if filepath.is_some() {
+            return self.resolve_heading_link(filepath, title);
+        }
+        let anchor = self.heading_anchor(title);
+        let Some(links) = self.options.links.clone() else {
+            return format!("#{}", anchor);
+        };
+
+        self.record_link(links.wiki_link());
+        let strategy = self.options.slug;
+        match links.find_wiki(&anchor, |title| slug::slugify(title, strategy)) {
+            Some((page, Some(id))) => format!("{}#{}", page.permalink, id),
+            Some((page, None)) => page.permalink.clone(),
+            None => {
+                self.broken_link("{?", format!("wiki link to missing page or heading '{}'", title));
+                format!("#{}", anchor)
+            }
+        }
+    }
+
+    /// Renders a shortcode, or a placeholder when it does not exist or fails to render
+    ///
+    /// `needle` locates the shortcode in the document and `content` is the converted content of
+    /// ranged shortcodes.
+    fn shortcode(
+        &mut self,
+        name: &str,
+        args: &[String],
+        content: Option<&str>,
+        needle: &str,
+    ) -> String {
+        let shortcodes = self
+            .options
+            .shortcodes
+            .clone()
+            .filter(|shortcodes| shortcodes.contains(name));
+        let Some(shortcodes) = shortcodes else {
+            let mut placeholder = self.unsupported(
+                DiagnosticKind::UnsupportedTag,
+                needle,
+                format!("unknown shortcode '{}'", name),
+                true,
+            );
+            placeholder.push_str(content.unwrap_or_default());
+            return placeholder;
+        };
+
+        match shortcodes.render(name, args, content) {
+            Ok(html) => html,
+            Err(e) => {
+                let mut placeholder = self.unsupported(
+                    DiagnosticKind::MacroError,
+                    needle,
+                    format!("shortcode '{}' failed: {}", name, e),
+                    true,
+                );
+                placeholder.push_str(content.unwrap_or_default());
+                placeholder
+            }
+        }
+    }
+
+    /// Runs a local image through the responsive image pipeline
+    ///
+    /// Returns `None` when there is no pipeline, the image is not local or it failed to process,
+    /// in which case it is used as-is.
+    fn process_image(&mut self, src: &str) -> Option<Arc<ProcessedImage>> {
+        let images = self.options.images.clone()?;
+        match images.process(src) {
+            Ok(Some(image)) => {
+                if !self.images.iter().any(|page_image| page_image.src == src) {
+                    self.images.push(PageImage {
+                        src: src.to_string(),
+                        width: image.width,
+                        height: image.height,
+                        hash: image.hash.clone(),
+                    });
+                }
+                Some(image)
+            }
+            Ok(None) => None,
+            Err(e) => {
+                self.report(
+                    DiagnosticKind::ImageError,
+                    &format!(".image {}", src),
+                    format!("image '{}' could not be processed: {}", src, e),
+                );
+                None
+            }
+        }
+    }
+
+    /// Returns the index of the footnote with the given title, registering it if it is new
+    fn footnote_index(&mut self, title: &str) -> usize {
+        if let Some(idx) = self.footnotes.iter().position(|f| f.title == title) {
+            return idx;
+        }
+        self.footnotes.push(Footnote {
+            number: self.footnotes.len() + 1,
+            title: title.to_string(),
+            content: String::new(),
+            references: 0,
+        });
+        self.footnotes.len() - 1
+    }
+
+    /// Registers a new reference to a footnote and returns its number and the reference id
+    fn reference_footnote(&mut self, title: &str) -> (usize, String) {
+        let idx = self.footnote_index(title);
+        let footnote = &mut self.footnotes[idx];
+        footnote.references += 1;
+        (footnote.number, footnote_ref_id(footnote.number, footnote.references))
+    }
+
+    /// Stores the converted content of a footnote definition
+    fn define_footnote(&mut self, title: &str, content: String) {
+        let idx = self.footnote_index(title);
+        if !self.footnotes[idx].content.is_empty() {
+            warn!("[converter] Footnote '{}' is defined more than once, using the last definition", title);
+        }
+        self.footnotes[idx].content = content;
+    }
+}
+
+/// Returns the id of the n-th reference to a footnote (`fnref-1`, `fnref-1-2`, ...)
+fn footnote_ref_id(number: usize, reference: usize) -> String {
+    if reference <= 1 {
+        format!("fnref-{}", number)
+    } else {
+        format!("fnref-{}-{}", number, reference)
+    }
+}
+
+/// Renders the placeholder shown in place of nodes the converter cannot render
+fn unsupported_placeholder(description: &str, block: bool) -> String {
+    let tag = if block { "div" } else { "span" };
+    format!(
+        "<{tag} class=\"lith-unsupported\" title=\"Unsupported Norg syntax\">{}</{tag}>",
+        tera::escape_html(description)
+    )
+}
+
+/// Returns the source prefix of a link target, used to locate it in the document
+fn link_target_needle(target: &LinkTarget) -> &'static str {
+    match target {
+        LinkTarget::Definition(_) => "{$",
+        LinkTarget::Footnote(_) => "{^",
+        LinkTarget::Wiki(_) => "{?",
+        LinkTarget::Generic(_) => "{#",
+        LinkTarget::Timestamp(_) => "{@",
+        LinkTarget::Extendable(_) => "{=",
+        _ => "{",
+    }
+}
+
+/// Returns a short human readable name for a link target kind
+fn link_target_name(target: &LinkTarget) -> &'static str {
+    match target {
+        LinkTarget::Definition(_) => "definition",
+        LinkTarget::Footnote(_) => "footnote",
+        LinkTarget::Wiki(_) => "wiki",
+        LinkTarget::Generic(_) => "generic",
+        LinkTarget::Timestamp(_) => "timestamp",
+        LinkTarget::Extendable(_) => "extendable",
+        LinkTarget::Heading { .. } => "heading",
+        LinkTarget::Path(_) => "path",
+        LinkTarget::Url(_) => "url",
+    }
+}
+
+/// Returns the name of an AST node variant (e.g. `RangedTag`) for diagnostics
+fn node_name<T: std::fmt::Debug>(node: &T) -> String {
+    let debug = format!("{:?}", node);
+    debug
+        .split(['{', '(', ' '])
+        .next()
+        .unwrap_or_default()
+        .to_string()
 }
 
 /// Converts paragraph segment tokens to a String
@@ -57,12 +470,139 @@ fn paragraph_tokens_to_string(tokens: &[ParagraphSegmentToken]) -> String {
     s
 }
 
+/// Converts a ParagraphSegment into its plain source text, without any HTML markup
+///
+/// Used by verbatim-like modifiers (e.g. inline math) where the inner content must not be
+/// interpreted as Norg markup.
+fn paragraph_to_plain_text(segment: &[ParagraphSegment]) -> String {
+    let mut text = String::new();
+    for node in segment {
+        match node {
+            ParagraphSegment::Token(t) => match t {
+                ParagraphSegmentToken::Text(s) => text.push_str(s),
+                ParagraphSegmentToken::Whitespace => text.push(' '),
+                ParagraphSegmentToken::Special(c) => text.push(*c),
+                ParagraphSegmentToken::Escape(c) => {
+                    text.push('\\');
+                    text.push(*c);
+                }
+            },
+            ParagraphSegment::AttachedModifier {
+                modifier_type,
+                content,
+            } => {
+                text.push(*modifier_type);
+                text.push_str(&paragraph_to_plain_text(content));
+                text.push(*modifier_type);
+            }
+            ParagraphSegment::InlineVerbatim(content) => {
+                text.push('`');
+                for token in content {
+                    match token {
+                        ParagraphSegmentToken::Text(s) => text.push_str(s),
+                        ParagraphSegmentToken::Whitespace => text.push(' '),
+                        ParagraphSegmentToken::Special(c) | ParagraphSegmentToken::Escape(c) => {
+                            text.push(*c)
+                        }
+                    }
+                }
+                text.push('`');
+            }
+            _ => {}
+        }
+    }
+    text
+}
+
+/// Renders a TeX math expression according to the configured math renderer
+///
+/// `MathRenderer::MathMl` converts the expression at build time into MathML markup, while
+/// `MathRenderer::Katex` only emits the expression wrapped in the KaTeX auto-render delimiters
+/// (`\(...\)` for inline math and `\[...\]` for display math) so it can be rendered client-side.
+/// Expressions that cannot be converted to MathML are reported as diagnostics.
+fn render_math(tex: &str, display: bool, state: &mut ConvertState) -> String {
+    let tex = tex.trim();
+    match state.options.math {
+        MathRenderer::MathMl => {
+            let style = if display {
+                latex2mathml::DisplayStyle::Block
+            } else {
+                latex2mathml::DisplayStyle::Inline
+            };
+            match latex2mathml::latex_to_mathml(tex, style) {
+                Ok(mathml) => mathml,
+                Err(e) => {
+                    state.report(
+                        DiagnosticKind::MathError,
+                        tex,
+                        format!(
+                            "math expression '{}' could not be converted to MathML: {}",
+                            tex, e
+                        ),
+                    );
+                    let tag = if display { "pre" } else { "code" };
+                    format!(
+                        "<{tag} class=\"math math-error\">{}</{tag}>",
+                        tera::escape_html(tex)
+                    )
+                }
+            }
+        }
+        MathRenderer::Katex => {
+            if display {
+                format!(
+                    "<div class=\"math math-display\">\\[{}\\]</div>",
+                    tera::escape_html(tex)
+                )
+            } else {
+                format!(
+                    "<span class=\"math math-inline\">\\({}\\)</span>",
+                    tera::escape_html(tex)
+                )
+            }
+        }
+    }
+}
+
+/// Renders a footnote reference ('{^ note}') as a numbered superscript link
+fn footnote_reference(
+    title: &[ParagraphSegment],
+    description: Option<&[ParagraphSegment]>,
+    strong_carry: &[CarryOverTag],
+    weak_carry: &mut VecDeque<CarryOverTag>,
+    state: &mut ConvertState,
+) -> String {
+    let mut reference = String::new();
+    let mut weak = VecDeque::<CarryOverTag>::new();
+    let title = paragraph_to_string(title, &[], &mut weak, state);
+    if let Some(desc) = description {
+        reference.push_str(&paragraph_to_string(desc, strong_carry, weak_carry, state));
+    }
+    let (number, ref_id) = state.reference_footnote(&title);
+    reference.push_str("<sup class=\"footnote-ref\" id=\"");
+    reference.push_str(&ref_id);
+    reference.push_str("\"><a href=\"#fn-");
+    reference.push_str(&number.to_string());
+    reference.push('"');
+    if !weak_carry.is_empty() {
+        let tags: Vec<_> = weak_carry.drain(..).collect();
+        for weak_carryover in tags {
+            reference.push(' ');
+            reference.push_str(&weak_carryover_attribute(weak_carryover));
+        }
+    }
+    reference.push_str(" role=\"doc-noteref\">");
+    reference.push_str(&number.to_string());
+    reference.push_str("</a></sup>");
+    reference
+}
+
 /// Converts a ParagraphSegment into a String
 fn paragraph_to_string(
     segment: &[ParagraphSegment],
     _strong_carry: &[CarryOverTag],
     weak_carry: &mut VecDeque<CarryOverTag>,
-    root_url: &str,
+    state: &mut ConvertState,
 ) -> String {
     let mut paragraph = String::new();
     segment.iter().for_each(|node| match node {
@@ -77,7 +617,12 @@ fn paragraph_to_string(
             modifier_type,
             content,
         } => {
-            let inner = paragraph_to_string(content, _strong_carry, weak_carry, root_url);
+            // Inline math content must not be interpreted as Norg markup
+            if *modifier_type == '$' {
+                paragraph.push_str(&render_math(&paragraph_to_plain_text(content), false, state));
+                return;
+            }
+            let inner = paragraph_to_string(content, _strong_carry, weak_carry, state);
             match modifier_type {
                 '*' => { paragraph.push_str("<strong>"); paragraph.push_str(&inner); paragraph.push_str("</strong>"); }
                 '/' => { paragraph.push_str("<em>"); paragraph.push_str(&inner); paragraph.push_str("</em>"); }
@@ -86,14 +631,15 @@ fn paragraph_to_string(
                 '^' => { paragraph.push_str("<sup>"); paragraph.push_str(&inner); paragraph.push_str("</sup>"); }
                 ',' => { paragraph.push_str("<sub>"); paragraph.push_str(&inner); paragraph.push_str("</sub>"); }
                 '!' => { paragraph.push_str("<span class='spoiler'>"); paragraph.push_str(&inner); paragraph.push_str("</span>"); }
-                '$' => { paragraph.push_str("<code>"); paragraph.push_str(&inner); paragraph.push_str("</code>"); }
                 '%' => {}           // ignore comments
                 _ => {
-                    info!(
-                        "[converter] ParagraphSegment::AttachedModifier: {} {:#?}",
-                        modifier_type, content
-                    );
-                    todo!()
+                    paragraph.push_str(&state.unsupported(
+                        DiagnosticKind::UnsupportedModifier,
+                        &modifier_type.to_string(),
+                        format!("attached modifier '{}'", modifier_type),
+                        false,
+                    ));
+                    paragraph.push_str(&inner);
                 }
             }
         }
@@ -112,49 +658,87 @@ fn paragraph_to_string(
             targets,
             description,
         } => {
+            // footnote references ('{^ note}') are rendered as numbered superscript links
+            if let (None, [LinkTarget::Footnote(title), ..]) = (filepath, targets.as_slice()) {
+                paragraph.push_str(&footnote_reference(
+                    title,
+                    description.as_deref(),
+                    _strong_carry,
+                    weak_carry,
+                    state,
+                ));
+                return;
+            }
+
             let mut link_name = String::new();
-            paragraph.push_str("<a ");
+            let mut href = None;
+            let mut unsupported = false;
 
-            // link to local paths (':/about:' -> '/about')
-            if let Some(path) = filepath {
-                if description.is_none() {
-                    link_name = path.to_string();
+            match targets.first() {
+                // link to external URLs
+                Some(LinkTarget::Url(path) | LinkTarget::Path(path)) => {
+                    if description.is_none() {
+                        link_name = path.to_string();
+                    }
+                    href = Some(path.clone());
                 }
-                paragraph.push_str("href=\"");
-                paragraph.push_str(root_url);
-                paragraph.push_str(path);
-                paragraph.push('"');
-            }
-
-            // link to anything else
-            if !targets.is_empty() {
-                match &targets[0] {
-                    // link to external URLs
-                    LinkTarget::Url(path) | LinkTarget::Path(path) => {
-                        if description.is_none() {
-                            link_name = path.to_string();
-                        }
-                        paragraph.push_str("href=\"");
-                        paragraph.push_str(path);
-                        paragraph.push('"');
+                // link to a heading, in this file or in another one (':about:* Contact')
+                Some(LinkTarget::Heading { level: _, title }) => {
+                    let mut weak = VecDeque::<CarryOverTag>::new();
+                    let title_str = paragraph_to_string(title, &[], &mut weak, state);
+                    if description.is_none() {
+                        link_name = title_str.clone();
                     }
-                    LinkTarget::Heading { level: _, title } => {
-                        let title_str = paragraph_to_string(title, _strong_carry, weak_carry, root_url);
-
+                    href = Some(state.resolve_heading_link(filepath.as_deref(), &title_str));
+                }
+                // generic links ('{# Title}') look for the title in the current file, wiki links
+                // ('{? Title}') look for it in the whole site
+                Some(LinkTarget::Generic(title) | LinkTarget::Wiki(title)) => {
+                    let mut weak = VecDeque::<CarryOverTag>::new();
+                    let title_str = paragraph_to_string(title, &[], &mut weak, state);
+                    if description.is_none() {
+                        link_name = title_str.clone();
+                    }
+                    href = Some(if matches!(targets[0], LinkTarget::Wiki(_)) {
+                        state.resolve_wiki_link(filepath.as_deref(), &title_str)
+                    } else {
+                        state.resolve_heading_link(filepath.as_deref(), &title_str)
+                    });
+                }
+                // link to local Norg files (':/about:' -> '/about/')
+                None => {
+                    if let Some(path) = filepath {
                         if description.is_none() {
-                            link_name = title_str.clone();
+                            link_name = path.to_string();
                         }
-                        paragraph.push_str("href=\"#");
-                        paragraph.push_str(&title_str.replace(" ", "-"));
-                        paragraph.push('"');
+                        href = Some(state.resolve_file_link(path, None));
                     }
-                    // Missing: Footnote, Definition, Wiki, Generic, Timestamp, Extendable
-                    _ => {
-                        info!("ParagraphSegment::Link: {:#?}", &node);
-                        todo!()
+                }
+                // Missing: Definition, Timestamp, Extendable
+                Some(target) => {
+                    let placeholder = state.unsupported(
+                        DiagnosticKind::UnsupportedLink,
+                        link_target_needle(target),
+                        format!("{} link target", link_target_name(target)),
+                        false,
+                    );
+                    // Keep the placeholder as the link text so the broken link is visible
+                    if description.is_none() {
+                        link_name = placeholder;
                     }
+                    unsupported = true;
                 }
             }
+
+            paragraph.push_str("<a");
+            if let Some(href) = href {
+                paragraph.push_str(" href=\"");
+                paragraph.push_str(&href);
+                paragraph.push('"');
+            }
+            if unsupported {
+                paragraph.push_str(" class=\"lith-unsupported\"");
+            }
             if !weak_carry.is_empty() {
                 let tags: Vec<_> = weak_carry.drain(..).collect();
                 for weak_carryover in tags {
@@ -168,7 +752,7 @@ fn paragraph_to_string(
                     desc,
                     _strong_carry,
                     weak_carry,
-                    root_url
+                    state
                 ));
                 paragraph.push_str("</a>");
             } else if link_name.is_empty() {
@@ -193,7 +777,7 @@ fn paragraph_to_string(
                     // link to external URLs
                     LinkTarget::Url(path) | LinkTarget::Path(path) => {
                         let href_path = if path.starts_with('/') {
-                            format!("{}{}", root_url, path)
+                            format!("{}{}", state.options.root_url, path)
                         } else {
                             path.clone()
                         };
@@ -202,20 +786,22 @@ fn paragraph_to_string(
                         paragraph.push('"');
                     }
                     LinkTarget::Heading { level: _, title } => {
-                        // Regex to remove possible links from heading title ids during href
-                        let re = inline_re();
-                        paragraph.push_str(" href=\"#");
-                        paragraph.push_str(&re.replace(
-                            &paragraph_to_string(title, _strong_carry, weak_carry, root_url)
-                                .replace(" ", "-"),
-                            ""
-                        ));
+                        let mut weak = VecDeque::<CarryOverTag>::new();
+                        let title_str = paragraph_to_string(title, &[], &mut weak, state);
+                        let href = state.resolve_heading_link(None, &title_str);
+                        paragraph.push_str(" href=\"");
+                        paragraph.push_str(&href);
                         paragraph.push('"');
                     }
                     // Missing: Footnote, Definition, Wiki, Generic, Timestamp, Extendable
-                    _ => {
-                        info!("ParagraphSegment::Link: {:#?}", &node);
-                        todo!()
+                    target => {
+                        state.unsupported(
+                            DiagnosticKind::UnsupportedLink,
+                            link_target_needle(target),
+                            format!("{} anchor target", link_target_name(target)),
+                            false,
+                        );
+                        paragraph.push_str(" class=\"lith-unsupported\"");
                     }
                 }
             }
@@ -227,14 +813,18 @@ fn paragraph_to_string(
                 }
             }
             paragraph.push('>');
-            paragraph.push_str(&paragraph_to_string(&content.clone(), _strong_carry, weak_carry, root_url));
+            paragraph.push_str(&paragraph_to_string(&content.clone(), _strong_carry, weak_carry, state));
             paragraph.push_str("</a>");
         }
         // ParagraphSegment::Anchor { content, description } => todo!(),
         // ParagraphSegment::InlineLinkTarget(_) => todo!(),
         _ => {
-            info!("[converter] ParagraphSegment: {:#?}", node);
-            todo!()
+            paragraph.push_str(&state.unsupported(
+                DiagnosticKind::UnsupportedNode,
+                "",
+                format!("inline {}", node_name(node)),
+                false,
+            ));
         }
     });
 
@@ -290,24 +880,168 @@ fn weak_carryover_attribute(weak_carryover: CarryOverTag) -> String {
     attr
 }
 
+/// Builds the tag of an `.image` infirm tag
+///
+/// Processed images get a `srcset` with their resized variants and their intrinsic size, and
+/// are wrapped in a `<picture>` offering the modern format variants to browsers supporting them.
+fn image_html(
+    src: &str,
+    alt: Option<&str>,
+    attributes: &[String],
+    image: Option<&ProcessedImage>,
+    options: &ConvertOptions,
+) -> String {
+    let root_url = &options.root_url;
+    let mut image_tag = String::from("<img src=\"");
+    if src.starts_with('/') {
+        image_tag.push_str(root_url);
+    }
+    image_tag.push_str(src);
+    image_tag.push('"');
+    if let Some(alt) = alt {
+        image_tag.push_str(" alt=\"");
+        encode_double_quoted_attribute_to_string(alt, &mut image_tag);
+        image_tag.push('"');
+    }
+
+    let sizes = options
+        .images
+        .as_ref()
+        .map(|images| images.config().sizes.as_str())
+        .unwrap_or_default();
+    if let Some(image) = image {
+        let srcset = image.srcset(image.format, root_url);
+        // A single candidate would only repeat the src
+        if srcset.contains(',') {
+            image_tag.push_str(&format!(" srcset=\"{}\" sizes=\"{}\"", srcset, sizes));
+        }
+        // Sizes set through carryover tags win over the intrinsic size
+        let has_size_attribute = attributes
+            .iter()
+            .any(|attr| attr.starts_with("width=") || attr.starts_with("height="));
+        if !has_size_attribute {
+            image_tag.push_str(&format!(
+                " width=\"{}\" height=\"{}\"",
+                image.width, image.height
+            ));
+        }
+    }
+    if options.images.as_ref().is_some_and(|images| images.config().lazy) {
+        image_tag.push_str(" loading=\"lazy\" decoding=\"async\"");
+    }
+    for attribute in attributes.iter().filter(|attribute| !attribute.is_empty()) {
+        image_tag.push(' ');
+        image_tag.push_str(attribute);
+    }
+    image_tag.push_str("/>");
+
+    let Some(image) = image.filter(|image| !image.modern_formats().is_empty()) else {
+        return image_tag;
+    };
+    let mut picture = String::from("<picture>");
+    for format in image.modern_formats() {
+        picture.push_str(&format!(
+            "<source type=\"{}\" srcset=\"{}\" sizes=\"{}\">",
+            format.mime_type(),
+            image.srcset(format, root_url),
+            sizes
+        ));
+    }
+    picture.push_str(&image_tag);
+    picture.push_str("</picture>");
+    picture
+}
+
+/// Builds the options of a code block from its `+code.*` carryover tags
+///
+/// Supported tags are `+code.line-numbers [true|false]` and `+code.highlight <ranges>`, where
+/// ranges look like `1-3 5` or `1-3,5`.
+fn code_block_options(tags: Vec<CarryOverTag>) -> CodeBlockOptions {
+    let mut block = CodeBlockOptions::default();
+    for tag in tags {
+        match tag.name.get(1).map(String::as_str) {
+            Some("line-numbers") => {
+                block.line_numbers = Some(tag.parameters.first().is_none_or(|p| p != "false"));
+            }
+            Some("highlight") => match highlight::parse_line_ranges(&tag.parameters) {
+                Ok(ranges) => block.highlighted.extend(ranges),
+                Err(range) => warn!(
+                    "[converter] Invalid line range '{}' in '+code.highlight', expected e.g. '1-3 5'",
+                    range
+                ),
+            },
+            _ => warn!(
+                "[converter] Unknown code block carryover tag '{}'",
+                tag.name.join(".")
+            ),
+        }
+    }
+    block
+}
+
+/// Converts flat AST nodes (e.g. the contents of rangeable detached modifiers) into HTML
+fn flat_to_html(ast: &[NorgASTFlat], state: &mut ConvertState) -> String {
+    let mut res = String::new();
+    for node in ast {
+        match node {
+            NorgASTFlat::Paragraph(s) => {
+                let mut weak = VecDeque::<CarryOverTag>::new();
+                res.push_str("<p>");
+                res.push_str(&paragraph_to_string(s, &[], &mut weak, state));
+                res.push_str("</p>");
+            }
+            _ => {
+                res.push_str(&state.unsupported(
+                    DiagnosticKind::UnsupportedNode,
+                    "",
+                    node_name(node),
+                    true,
+                ));
+            }
+        }
+    }
+    res
+}
+
+/// Renders the footnotes section that is appended at the end of the document
+fn footnotes_section(footnotes: &[Footnote]) -> String {
+    if footnotes.is_empty() {
+        return String::new();
+    }
+
+    let mut section = String::from("<section class=\"footnotes\" role=\"doc-endnotes\"><ol>");
+    for footnote in footnotes {
+        section.push_str("<li id=\"fn-");
+        section.push_str(&footnote.number.to_string());
+        section.push_str("\">");
+        section.push_str(&footnote.content);
+        for reference in 1..=footnote.references {
+            section.push_str("<a href=\"#");
+            section.push_str(&footnote_ref_id(footnote.number, reference));
+            section.push_str("\" class=\"footnote-backref\" role=\"doc-backlink\">↩</a>");
+        }
+        section.push_str("</li>");
+    }
+    section.push_str("</ol></section>");
+    section
+}
+
 trait NorgToHtml {
     fn to_html(
         &self,
         strong_carry: &[CarryOverTag],
         weak_carry: VecDeque<CarryOverTag>,
-        root_url: &str,
-        toc: &mut Vec<TocEntry>,
+        state: &mut ConvertState,
     ) -> String;
 }
 
 impl NorgToHtml for NorgAST {
-    // TODO: finish VerbatimRangedTag support, add support for strong carryover tags, footnotes (they are tricky in HTML), anything else that I'm missing
+    // TODO: finish VerbatimRangedTag support, anything else that I'm missing
     fn to_html(
         &self,
         strong_carry: &[CarryOverTag],
         mut weak_carry: VecDeque<CarryOverTag>,
-        root_url: &str,
-        toc: &mut Vec<TocEntry>,
+        state: &mut ConvertState,
     ) -> String {
         match self {
             NorgAST::Paragraph(s) => {
@@ -320,7 +1054,7 @@ impl NorgToHtml for NorgAST {
                     }
                 }
                 paragraph.push('>');
-                paragraph.push_str(&paragraph_to_string(s, strong_carry, &mut weak_carry, root_url));
+                paragraph.push_str(&paragraph_to_string(s, strong_carry, &mut weak_carry, state));
                 paragraph.push_str("</p>");
                 paragraph
             }
@@ -335,11 +1069,9 @@ impl NorgToHtml for NorgAST {
                 // the HTML carryovers meant for the heading are used for its internal content instead
                 let strong: &[CarryOverTag] = &[];
                 let mut weak = VecDeque::<CarryOverTag>::new();
-                let heading_title = paragraph_to_string(title, strong, &mut weak, root_url);
+                let heading_title = paragraph_to_string(title, strong, &mut weak, state);
 
-                // Regex to remove possible links from heading title ids
-                let re = inline_re();
-                let heading_id = re.replace(&heading_title.replace(" ", "-"), "").to_string();
+                let heading_id = state.slugger.slug(&heading_title);
 
                 let tag = match level {
                     1..=6 => format!("h{}", level),
@@ -349,7 +1081,7 @@ impl NorgToHtml for NorgAST {
                 section.push('<');
                 section.push_str(&tag);
                 section.push_str(" id=\"");
-                section.push_str(&heading_id);
+                encode_double_quoted_attribute_to_string(&heading_id, &mut section);
                 section.push('"');
                 if !weak_carry.is_empty() {
                     let tags: Vec<_> = weak_carry.drain(..).collect();
@@ -368,18 +1100,18 @@ impl NorgToHtml for NorgAST {
                     title: heading_title.clone(),
                     id: heading_id.clone(),
                 };
-                toc.push(entry);
+                state.toc.push(entry);
 
-                section.push_str(&to_html(content, strong_carry, &weak_carry, root_url, toc));
+                section.push_str(&to_html(content, strong_carry, &weak_carry, state));
 
                 section
             }
             NorgAST::NestableDetachedModifier {
                 modifier_type,
                 level: _,
+                extensions,
                 text,
                 content,
-                ..
             } => {
                 // HACK: 'text' is actually a 'Box<NorgASTFlat>' value. It should be converted into a `ParagraphSegment` later in the rust-norg parser
                 let mod_text = if let NorgASTFlat::Paragraph(s) = *text.clone() {
@@ -387,7 +1119,7 @@ impl NorgToHtml for NorgAST {
                     let mut weak = VecDeque::<CarryOverTag>::new();
                     // HACK: we are passing empty carryover vectors here because otherwise
                     // the HTML carryovers meant for the lists are used for its internal content instead
-                    paragraph_to_string(&s, strong, &mut weak, root_url)
+                    paragraph_to_string(&s, strong, &mut weak, state)
                 } else {
                     unreachable!();
                 };
@@ -395,19 +1127,47 @@ impl NorgToHtml for NorgAST {
                 match modifier_type {
                     NestableDetachedModifier::UnorderedList
                     | NestableDetachedModifier::OrderedList => {
+                        let todo = extensions.iter().find_map(|extension| match extension {
+                            DetachedModifierExtension::Todo(status) => Some(todo_status(status)),
+                            _ => None,
+                        });
                         let mut list = String::from("<li");
+                        let mut classes = Vec::new();
+                        if let Some((class, _)) = todo {
+                            classes.push(String::from("todo"));
+                            classes.push(format!("todo-{}", class));
+                        }
                         if !weak_carry.is_empty() {
                             let tags: Vec<_> = weak_carry.drain(..).collect();
                             for weak_carryover in tags {
+                                // Merge the classes so todo items do not end up with two class attributes
+                                if todo.is_some() && weak_carryover.name == ["html", "class"] {
+                                    classes.extend(weak_carryover.parameters);
+                                    continue;
+                                }
                                 list.push(' ');
                                 list.push_str(&weak_carryover_attribute(weak_carryover));
                             }
                         }
+                        if !classes.is_empty() {
+                            list.push_str(" class=\"");
+                            list.push_str(&classes.join(" "));
+                            list.push('"');
+                        }
                         list.push('>');
+                        if let Some((class, label)) = todo {
+                            list.push_str("<input type=\"checkbox\" class=\"todo-status\" disabled");
+                            if class == "done" {
+                                list.push_str(" checked");
+                            }
+                            list.push_str(" aria-label=\"");
+                            list.push_str(label);
+                            list.push_str("\"/> ");
+                        }
                         list.push_str(&mod_text);
                         list.push_str("</li>");
                         if !content.is_empty() {
-                            list.push_str(&to_html(content, strong_carry, &weak_carry, root_url, toc));
+                            list.push_str(&to_html(content, strong_carry, &weak_carry, state));
                         }
                         list
                     }
@@ -423,7 +1183,7 @@ impl NorgToHtml for NorgAST {
                         quote.push('>');
                         quote.push_str(&mod_text);
                         if !content.is_empty() {
-                            quote.push_str(&to_html(content, strong_carry, &weak_carry, root_url, toc));
+                            quote.push_str(&to_html(content, strong_carry, &weak_carry, state));
                         }
                         quote.push_str("</blockquote>");
                         quote
@@ -439,31 +1199,52 @@ impl NorgToHtml for NorgAST {
                 let mut verbatim_tag = String::new();
                 match name[0].as_str() {
                     "code" => {
-                        let mut code_tag = String::from("<pre");
-                        if !weak_carry.is_empty() {
-                            let tags: Vec<_> = weak_carry.drain(..).collect();
-                            for weak_carryover in tags {
+                        let language = parameters.first().map(String::as_str);
+                        // `+code.*` carryover tags configure the code block itself, the rest
+                        // are turned into HTML attributes as usual
+                        let (code_carry, html_carry): (Vec<_>, Vec<_>) =
+                            weak_carry.drain(..).partition(|tag| tag.name[0] == "code");
+                        let attributes = html_carry
+                            .into_iter()
+                            .map(weak_carryover_attribute)
+                            .filter(|attr| !attr.is_empty())
+                            .collect::<Vec<_>>()
+                            .join(" ");
+                        let block = code_block_options(code_carry);
+
+                        let highlighted = state.options.highlight.as_ref().and_then(|options| {
+                            highlight::highlight_code(content, language, options, &block, &attributes)
+                                .map_err(|e| {
+                                    warn!("[converter] Failed to highlight code block: {}", e);
+                                })
+                                .ok()
+                        });
+                        if let Some(highlighted) = highlighted {
+                            verbatim_tag = highlighted;
+                        } else {
+                            let mut code_tag = String::from("<pre");
+                            if !attributes.is_empty() {
                                 code_tag.push(' ');
-                                code_tag.push_str(&weak_carryover_attribute(weak_carryover));
+                                code_tag.push_str(&attributes);
                             }
+                            // NOTE: Tera completely skips HTML code block contents while rendering our HTML content
+                            // because we are forced to use the `safe` filter. This workaround aims to fix those
+                            // problems, and (hopefully) also including XML rendering.
+                            let content = &tera::escape_html(content);
+                            code_tag.push('>');
+                            code_tag.push_str("<code");
+                            if let Some(language) = language {
+                                // NOTE: the class `language-foo` is being added by default so the converter can
+                                // work out-of-the-box with code highlighting libraries like highlight.js or prismjs
+                                code_tag.push_str(" class=\"language-");
+                                code_tag.push_str(language);
+                                code_tag.push('"');
+                            }
+                            code_tag.push('>');
+                            code_tag.push_str(content);
+                            code_tag.push_str("</code></pre>");
+                            verbatim_tag = code_tag;
                         }
-                        // NOTE: Tera completely skips HTML code block contents while rendering our HTML content
-                        // because we are forced to use the `safe` filter. This workaround aims to fix those
-                        // problems, and (hopefully) also including XML rendering.
-                        let content = &tera::escape_html(content);
-                        code_tag.push('>');
-                        code_tag.push_str("<code");
-                        if !parameters.is_empty() {
-                            // NOTE: the class `language-foo` is being added by default so the converter can
-                            // work out-of-the-box with code highlighting libraries like highlight.js or prismjs
-                            code_tag.push_str(" class=\"language-");
-                            code_tag.push_str(&parameters[0]);
-                            code_tag.push('"');
-                        }
-                        code_tag.push('>');
-                        code_tag.push_str(content);
-                        code_tag.push_str("</code></pre>");
-                        verbatim_tag = code_tag;
                     }
                     // NOTE: this only works for base64 encoded images, regular images
                     // use the .image infirm tag.
@@ -487,11 +1268,31 @@ impl NorgToHtml for NorgAST {
                             verbatim_tag = content.to_string()
                         }
                     }
-                    // TODO: support other verbatim ranged tags like '@math'
+                    "math" => {
+                        verbatim_tag = render_math(content, true, state);
+                        if !weak_carry.is_empty() {
+                            // Wrap the math block so the HTML carryover tags have an element to target
+                            let mut math_tag = String::from("<div");
+                            let tags: Vec<_> = weak_carry.drain(..).collect();
+                            for weak_carryover in tags {
+                                math_tag.push(' ');
+                                math_tag.push_str(&weak_carryover_attribute(weak_carryover));
+                            }
+                            math_tag.push('>');
+                            math_tag.push_str(&verbatim_tag);
+                            math_tag.push_str("</div>");
+                            verbatim_tag = math_tag;
+                        }
+                    }
                     _ => {
                         if name[0] != "document" {
-                            info!("[converter] VerbatimRangedTag: {:#?}", self);
-                            todo!()
+                            let tag_name = name.join(".");
+                            verbatim_tag = state.unsupported(
+                                DiagnosticKind::UnsupportedTag,
+                                &format!("@{}", tag_name),
+                                format!("verbatim tag '@{}'", tag_name),
+                                true,
+                            );
                         }
                     }
                 }
@@ -514,44 +1315,174 @@ impl NorgToHtml for NorgAST {
                             &[*next_object.clone()],
                             strong_carry,
                             &weak_carry,
-                            root_url,
-                            toc,
+                            state,
                         )
                 }
+                // Strong carryover tags apply to the whole subtree of the next object, so their
+                // attributes go to an element wrapping it
+                CarryoverTag::Macro if name[0] == "html" => {
+                    let mut strong = strong_carry.to_vec();
+                    strong.push(CarryOverTag {
+                        name: name.clone(),
+                        parameters: parameters.clone(),
+                    });
+                    let mut node = &**next_object;
+                    while let NorgAST::CarryoverTag {
+                        tag_type: CarryoverTag::Macro,
+                        name,
+                        parameters,
+                        next_object,
+                    } = node
+                    {
+                        if name[0] != "html" {
+                            break;
+                        }
+                        strong.push(CarryOverTag {
+                            name: name.clone(),
+                            parameters: parameters.clone(),
+                        });
+                        node = next_object;
+                    }
+
+                    let element = if matches!(node, NorgAST::Heading { .. }) {
+                        "section"
+                    } else {
+                        "div"
+                    };
+                    let attributes =
+                        carryover_attributes(strong[strong_carry.len()..].iter().cloned());
+                    let content = to_html(std::slice::from_ref(node), &strong, &weak_carry, state);
+                    format!("<{element}{attributes}>{content}</{element}>")
+                }
+                // Any other strong carryover tag is a macro invocation
                 CarryoverTag::Macro => {
-                    error!("[converter] Carryover tag macros are unsupported right now");
-                    todo!()
+                    let tag_name = name.join(".");
+                    let needle = format!("#{}", tag_name);
+                    let macros = state
+                        .options
+                        .macros
+                        .clone()
+                        .filter(|macros| macros.contains(&tag_name));
+                    let Some(macros) = macros else {
+                        let mut macro_tag = state.unsupported(
+                            DiagnosticKind::UnsupportedMacro,
+                            &needle,
+                            format!("unknown macro '#{}'", tag_name),
+                            true,
+                        );
+                        macro_tag.push_str(&to_html(
+                            &[*next_object.clone()],
+                            strong_carry,
+                            &weak_carry,
+                            state,
+                        ));
+                        return macro_tag;
+                    };
+
+                    let content = to_html(&[*next_object.clone()], strong_carry, &weak_carry, state);
+                    match macros.expand(&tag_name, parameters, &content) {
+                        Ok(html) => html,
+                        Err(e) => {
+                            let mut macro_tag = state.unsupported(
+                                DiagnosticKind::MacroError,
+                                &needle,
+                                format!("macro '#{}' failed: {}", tag_name, e),
+                                true,
+                            );
+                            macro_tag.push_str(&content);
+                            macro_tag
+                        }
+                    }
                 }
             },
             // InfirmTag: InfirmTag { name: ["image"], parameters: ["/assets/norgolith.svg", "Norgolith", "logo"] }
             NorgAST::InfirmTag { name, parameters } => {
                 match name[0].as_str() {
+                    // .image <src> [alt] [caption...]
                     "image" => {
-                        let src_path = if parameters[0].starts_with('/') {
-                            format!("{}{}", root_url, parameters[0])
-                        } else {
-                            parameters[0].clone()
-                        };
-                        let mut image_tag = String::from("<img src=\"");
-                        image_tag.push_str(&src_path);
-                        image_tag.push('"');
-                        if !weak_carry.is_empty() {
-                            let tags: Vec<_> = weak_carry.drain(..).collect();
-                            for weak_carryover in tags {
-                                image_tag.push(' ');
-                                image_tag.push_str(&weak_carryover_attribute(weak_carryover));
+                        let src = &parameters[0];
+                        let image = state.process_image(src);
+                        let attributes: Vec<_> = weak_carry
+                            .drain(..)
+                            .map(weak_carryover_attribute)
+                            .collect();
+                        // `+html.alt` takes precedence, an empty one marks decorative images
+                        let has_alt_attribute =
+                            attributes.iter().any(|attr| attr.starts_with("alt="));
+                        let alt = parameters.get(1).filter(|_| !has_alt_attribute);
+                        if alt.is_none() && !has_alt_attribute {
+                            state.report(
+                                DiagnosticKind::MissingAlt,
+                                &format!(".image {}", src),
+                                format!("image '{}' has no alt text", src),
+                            );
+                        }
+
+                        let image_tag = image_html(
+                            src,
+                            alt.map(String::as_str),
+                            &attributes,
+                            image.as_deref(),
+                            state.options,
+                        );
+                        match parameters.get(2..).filter(|caption| !caption.is_empty()) {
+                            Some(caption) => {
+                                let mut figure = String::from("<figure>");
+                                figure.push_str(&image_tag);
+                                figure.push_str("<figcaption>");
+                                encode_text_minimal_to_string(caption.join(" "), &mut figure);
+                                figure.push_str("</figcaption></figure>");
+                                figure
                             }
+                            None => image_tag,
                         }
-                        image_tag.push_str("/>");
-                        image_tag
                     }
+                    // .shortcode youtube dQw4w9WgXcQ
+                    "shortcode" => match parameters.split_first() {
+                        Some((shortcode, args)) => {
+                            let needle = format!(".shortcode {}", shortcode);
+                            state.shortcode(shortcode, args, None, &needle)
+                        }
+                        None => state.unsupported(
+                            DiagnosticKind::UnsupportedTag,
+                            ".shortcode",
+                            String::from("shortcode without a name"),
+                            true,
+                        ),
+                    },
                     _ => {
-                        // FIXME: add Infirm tags support, we are currently ignoring them
-                        info!("[converter] InfirmTag: {:#?}", self);
-                        todo!()
+                        let tag_name = name.join(".");
+                        state.unsupported(
+                            DiagnosticKind::UnsupportedTag,
+                            &format!(".{}", tag_name),
+                            format!("infirm tag '.{}'", tag_name),
+                            true,
+                        )
                     }
                 }
             }
+            // RangeableDetachedModifier { modifier_type: Footnote, title: [...], content: [Paragraph(...)] }
+            NorgAST::RangeableDetachedModifier {
+                modifier_type,
+                title,
+                content,
+                ..
+            } => match modifier_type {
+                RangeableDetachedModifier::Footnote => {
+                    // NOTE: footnote definitions are not rendered in place, they are collected and
+                    // rendered at the end of the document instead
+                    let mut weak = VecDeque::<CarryOverTag>::new();
+                    let title = paragraph_to_string(title, &[], &mut weak, state);
+                    let body = flat_to_html(content, state);
+                    state.define_footnote(&title, body);
+                    String::new()
+                }
+                // NOTE: consecutive definitions and table cells are grouped by `to_html`, this
+                // is only reached for a lone one
+                RangeableDetachedModifier::Definition | RangeableDetachedModifier::Table => {
+                    rangeable_group_to_html(std::slice::from_ref(self), weak_carry, state)
+                }
+            },
             NorgAST::DelimitingModifier(t) => {
                 if *t == DelimitingModifier::HorizontalRule {
                     let mut hr_tag = String::from("<hr");
@@ -566,10 +1497,29 @@ impl NorgToHtml for NorgAST {
                     hr_tag
                 } else {
                     // XXX: support weak and strong delimiting modifiers?
-                    error!("[converter] {:#?}", self);
-                    todo!()
+                    let (needle, description) = if *t == DelimitingModifier::Weak {
+                        ("---", "weak delimiting modifier")
+                    } else {
+                        ("===", "strong delimiting modifier")
+                    };
+                    state.unsupported(
+                        DiagnosticKind::UnsupportedNode,
+                        needle,
+                        description.to_string(),
+                        true,
+                    )
                 }
             }
+            // RangedTag { name: ["callout"], parameters: ["warning"], content: [...] }
+            NorgAST::RangedTag {
+                name,
+                parameters,
+                content,
+            } => {
+                let tag_name = name.join(".");
+                let content = to_html(content, strong_carry, &VecDeque::new(), state);
+                state.shortcode(&tag_name, parameters, Some(&content), &format!("|{}", tag_name))
+            }
             NorgAST::List {
                 modifier_type,
                 items,
@@ -578,16 +1528,18 @@ impl NorgToHtml for NorgAST {
                     let list_open = get_list_tag(*modifier_type, true);
                     let list_close = get_list_tag(*modifier_type, false);
                     let mut list = list_open;
-                    list.push_str(&to_html(items, strong_carry, &weak_carry, root_url, toc));
+                    list.push_str(&to_html(items, strong_carry, &weak_carry, state));
                     list.push_str(&list_close);
                     list
                 }
-                _ => to_html(items, strong_carry, &weak_carry, root_url, toc),
+                _ => to_html(items, strong_carry, &weak_carry, state),
             },
-            _ => {
-                info!("[converter] {:#?}", self);
-                todo!() // Fail on stuff that we cannot parse yet
-            }
+            _ => state.unsupported(
+                DiagnosticKind::UnsupportedNode,
+                "",
+                node_name(self),
+                true,
+            ),
         }
     }
 }
@@ -596,17 +1548,247 @@ fn to_html(
     ast: &[NorgAST],
     strong_carry: &[CarryOverTag],
     weak_carry: &VecDeque<CarryOverTag>,
-    root_url: &str,
-    toc: &mut Vec<TocEntry>,
+    state: &mut ConvertState,
 ) -> String {
     let mut res = String::new();
-    for node in ast {
-        res.push_str(&node.to_html(strong_carry, weak_carry.clone(), root_url, toc));
+    let mut idx = 0;
+    while idx < ast.len() {
+        // Definitions and table cells are separate nodes in the AST, consecutive ones are
+        // rendered together as a single list or table
+        if let Some(kind) = grouped_modifier(&ast[idx]) {
+            let run = ast[idx..]
+                .iter()
+                .take_while(|node| {
+                    grouped_modifier(node).is_some_and(|other| {
+                        std::mem::discriminant(other) == std::mem::discriminant(kind)
+                    })
+                })
+                .count();
+            res.push_str(&rangeable_group_to_html(
+                &ast[idx..idx + run],
+                weak_carry.clone(),
+                state,
+            ));
+            idx += run;
+            continue;
+        }
+
+        res.push_str(&ast[idx].to_html(strong_carry, weak_carry.clone(), state));
+        idx += 1;
     }
 
     res
 }
 
+/// Returns the weak carryover tags attached to a node and the node they are attached to
+fn peel_weak_carryovers(mut node: &NorgAST) -> (Vec<CarryOverTag>, &NorgAST) {
+    let mut tags = Vec::new();
+    while let NorgAST::CarryoverTag {
+        tag_type: CarryoverTag::Attribute,
+        name,
+        parameters,
+        next_object,
+    } = node
+    {
+        tags.push(CarryOverTag {
+            name: name.clone(),
+            parameters: parameters.clone(),
+        });
+        node = next_object;
+    }
+    (tags, node)
+}
+
+/// Returns the modifier type of definitions and table cells, which are rendered in groups
+fn grouped_modifier(node: &NorgAST) -> Option<&RangeableDetachedModifier> {
+    match peel_weak_carryovers(node).1 {
+        NorgAST::RangeableDetachedModifier {
+            modifier_type:
+                modifier_type @ (RangeableDetachedModifier::Definition
+                | RangeableDetachedModifier::Table),
+            ..
+        } => Some(modifier_type),
+        _ => None,
+    }
+}
+
+/// Returns the CSS class suffix and the accessible label of a TODO status
+fn todo_status(status: &TodoStatus) -> (&'static str, &'static str) {
+    match status {
+        TodoStatus::Undone => ("undone", "Undone"),
+        TodoStatus::Done => ("done", "Done"),
+        TodoStatus::NeedsClarification => ("needs-input", "Needs further input"),
+        TodoStatus::Paused => ("on-hold", "On hold"),
+        TodoStatus::Urgent => ("urgent", "Urgent"),
+        TodoStatus::Recurring(_) => ("recurring", "Recurring"),
+        TodoStatus::Pending => ("pending", "In progress"),
+        TodoStatus::Canceled => ("cancelled", "Cancelled"),
+    }
+}
+
+/// Converts the content of a definition or a table cell, a single paragraph is not wrapped in
+/// a `<p>` tag
+fn rangeable_content_to_html(content: &[NorgASTFlat], state: &mut ConvertState) -> String {
+    if let [NorgASTFlat::Paragraph(s)] = content {
+        let mut weak = VecDeque::<CarryOverTag>::new();
+        paragraph_to_string(s, &[], &mut weak, state)
+    } else {
+        flat_to_html(content, state)
+    }
+}
+
+/// Joins weak carryover tags into HTML attributes, with a leading space
+fn carryover_attributes(tags: impl IntoIterator<Item = CarryOverTag>) -> String {
+    let mut attributes = String::new();
+    for tag in tags {
+        let attribute = weak_carryover_attribute(tag);
+        if !attribute.is_empty() {
+            attributes.push(' ');
+            attributes.push_str(&attribute);
+        }
+    }
+    attributes
+}
+
+/// Renders consecutive definitions as a `<dl>` or consecutive table cells as a `<table>`
+///
+/// Carryover tags of the first node apply to the whole list or table, the ones of the following
+/// nodes apply to their own term or cell.
+fn rangeable_group_to_html(
+    nodes: &[NorgAST],
+    mut weak_carry: VecDeque<CarryOverTag>,
+    state: &mut ConvertState,
+) -> String {
+    let mut items = Vec::new();
+    for (idx, node) in nodes.iter().enumerate() {
+        let (tags, node) = peel_weak_carryovers(node);
+        let NorgAST::RangeableDetachedModifier { title, content, .. } = node else {
+            continue;
+        };
+        let item_tags = if idx == 0 {
+            weak_carry.extend(tags);
+            Vec::new()
+        } else {
+            tags
+        };
+        let mut weak = VecDeque::<CarryOverTag>::new();
+        let title = paragraph_to_string(title, &[], &mut weak, state);
+        items.push((item_tags, title, content));
+    }
+    let attributes = carryover_attributes(weak_carry.drain(..));
+
+    let is_table = matches!(
+        grouped_modifier(&nodes[0]),
+        Some(RangeableDetachedModifier::Table)
+    );
+    if !is_table {
+        let mut list = format!("<dl{}>", attributes);
+        for (tags, title, content) in items {
+            list.push_str("<dt");
+            list.push_str(&carryover_attributes(tags));
+            list.push('>');
+            list.push_str(&title);
+            list.push_str("</dt><dd>");
+            list.push_str(&rangeable_content_to_html(content, state));
+            list.push_str("</dd>");
+        }
+        list.push_str("</dl>");
+        return list;
+    }
+
+    // Place every cell in the grid, a later cell in the same position replaces the former one.
+    // The grid is at most a few rows and columns larger than the number of cells, so a typo in
+    // a cell position (e.g. `A99999999`) cannot make the table huge.
+    let max_size = items.len() + TABLE_SIZE_SLACK;
+    let mut cells: HashMap<(usize, usize), (String, String)> = HashMap::new();
+    let mut position = None;
+    for (tags, title, content) in items {
+        let next = table_cell_position(&title, position).unwrap_or_else(|| {
+            state.report(
+                DiagnosticKind::UnsupportedNode,
+                &format!(": {}", title),
+                format!("table cell position '{}'", title),
+            );
+            next_table_column(position)
+        });
+        if next.0 >= max_size || next.1 >= max_size {
+            state.report(
+                DiagnosticKind::UnsupportedNode,
+                &format!(": {}", title),
+                format!(
+                    "table cell position '{}' outside of the {}x{} table",
+                    title, max_size, max_size
+                ),
+            );
+            continue;
+        }
+        position = Some(next);
+        let cell = rangeable_content_to_html(content, state);
+        cells.insert(next, (carryover_attributes(tags), cell));
+    }
+
+    let rows = cells.keys().map(|(row, _)| row + 1).max().unwrap_or(0);
+    let columns = cells.keys().map(|(_, col)| col + 1).max().unwrap_or(0);
+    let mut table = format!("<table{}><tbody>", attributes);
+    for row in 0..rows {
+        table.push_str("<tr>");
+        for col in 0..columns {
+            match cells.get(&(row, col)) {
+                Some((cell_attributes, cell)) => {
+                    table.push_str("<td");
+                    table.push_str(cell_attributes);
+                    table.push('>');
+                    table.push_str(cell);
+                    table.push_str("</td>");
+                }
+                None => table.push_str("<td></td>"),
+            }
+        }
+        table.push_str("</tr>");
+    }
+    table.push_str("</tbody></table>");
+    table
+}
+
+/// Number of rows and columns a table can have besides its number of cells, for the cells
+/// left empty
+const TABLE_SIZE_SLACK: usize = 8;
+
+/// Position of the cell after `current` in the same row, the first cell goes to `A1`
+fn next_table_column(current: Option<(usize, usize)>) -> (usize, usize) {
+    current.map(|(row, col)| (row, col + 1)).unwrap_or((0, 0))
+}
+
+/// Computes the 0-based (row, column) position of a table cell from its title
+///
+/// The title is either an absolute reference (`A1`, `B3`, `AA10`) or a move relative to the
+/// previous cell: `>` (next column, also used when the title is empty), `<` (previous column),
+/// `^` (previous row) and `v` (next row). Returns `None` for any other title.
+fn table_cell_position(title: &str, current: Option<(usize, usize)>) -> Option<(usize, usize)> {
+    let title = title.trim();
+    let (row, col) = current.unwrap_or((0, 0));
+    match title {
+        "" | ">" => return Some(next_table_column(current)),
+        "<" => return Some((row, col.saturating_sub(1))),
+        "^" => return Some((row.saturating_sub(1), col)),
+        "v" => return Some((if current.is_some() { row + 1 } else { 0 }, col)),
+        _ => {}
+    }
+
+    let split = title.find(|c: char| !c.is_ascii_alphabetic())?;
+    let (letters, digits) = title.split_at(split);
+    if letters.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let row = digits.parse::<usize>().ok()?.checked_sub(1)?;
+    // Long letter runs overflow, they are reported as unsupported positions
+    let col = letters.chars().try_fold(0usize, |acc, c| {
+        acc.checked_mul(26)?
+            .checked_add(c.to_ascii_uppercase() as usize - 'A' as usize + 1)
+    })? - 1;
+    Some((row, col))
+}
+
 /// Convert TOC entries to TOML
 pub fn toc_to_toml(toc: &[TocEntry]) -> toml::Value {
     let mut items = toml::value::Array::new();
@@ -622,11 +1804,421 @@ pub fn toc_to_toml(toc: &[TocEntry]) -> toml::Value {
     toml::Value::Array(items)
 }
 
-pub fn convert(document: &str, root_url: &str) -> (String, Vec<TocEntry>) {
-    let ast = parse_tree(document).unwrap();
-    let mut toc = Vec::<TocEntry>::new();
+/// Convert footnotes to TOML
+pub fn footnotes_to_toml(footnotes: &[Footnote]) -> toml::Value {
+    let mut items = toml::value::Array::new();
+
+    for footnote in footnotes {
+        let mut table = toml::value::Table::new();
+        table.insert("number".into(), toml::Value::Integer(footnote.number as i64));
+        table.insert("id".into(), toml::Value::String(format!("fn-{}", footnote.number)));
+        table.insert("title".into(), toml::Value::String(footnote.title.clone()));
+        table.insert("content".into(), toml::Value::String(footnote.content.clone()));
+        let backrefs = (1..=footnote.references)
+            .map(|reference| toml::Value::String(footnote_ref_id(footnote.number, reference)))
+            .collect();
+        table.insert("backrefs".into(), toml::Value::Array(backrefs));
+        items.push(toml::Value::Table(table));
+    }
+
+    toml::Value::Array(items)
+}
+
+/// Converts the local images of a page to TOML so templates can use them (e.g. for social
+/// previews) and cached pages can publish their image variants again, or be converted again
+/// when one of their images changed
+pub fn images_to_toml(images: &[PageImage]) -> toml::Value {
+    let items = images
+        .iter()
+        .map(|image| {
+            let mut table = toml::value::Table::new();
+            table.insert("src".into(), toml::Value::String(image.src.clone()));
+            table.insert("width".into(), toml::Value::Integer(image.width as i64));
+            table.insert("height".into(), toml::Value::Integer(image.height as i64));
+            table.insert("hash".into(), toml::Value::String(image.hash.clone()));
+            toml::Value::Table(table)
+        })
+        .collect();
+    toml::Value::Array(items)
+}
+
+/// Converts the link targets looked up by a page to TOML, so cached pages can be converted
+/// again when one of them changes (see [`LinkIndex::is_current`])
+pub fn links_to_toml(links: &[ResolvedLink]) -> toml::Value {
+    let items = links
+        .iter()
+        .map(|link| {
+            let mut table = toml::value::Table::new();
+            table.insert("target".into(), toml::Value::String(link.target.clone()));
+            table.insert("hash".into(), toml::Value::String(link.hash.clone()));
+            toml::Value::Table(table)
+        })
+        .collect();
+    toml::Value::Array(items)
+}
+
+/// Returns the ids of every heading in a document, in document order
+///
+/// Used to build the site [`LinkIndex`] without doing a full conversion.
+pub fn heading_ids(document: &str, slug: SlugStrategy) -> Vec<String> {
+    let Ok(ast) = parse_tree(document) else {
+        return Vec::new();
+    };
+    let options = ConvertOptions {
+        slug,
+        ..Default::default()
+    };
+    let mut state = ConvertState::new(document, &options);
+    let mut ids = Vec::new();
+    collect_heading_ids(&ast, &mut state, &mut ids);
+    ids
+}
+
+fn collect_heading_ids(ast: &[NorgAST], state: &mut ConvertState, ids: &mut Vec<String>) {
+    for node in ast {
+        if let NorgAST::Heading { title, content, .. } = node {
+            let mut weak = VecDeque::<CarryOverTag>::new();
+            let title = paragraph_to_string(title, &[], &mut weak, state);
+            let id = state.slugger.slug(&title);
+            ids.push(id);
+            collect_heading_ids(content, state, ids);
+        }
+    }
+}
+
+/// Converts a Norg document to HTML
+///
+/// Conversion never fails: nodes that cannot be rendered are replaced by a visible placeholder
+/// and reported in [`ConvertedDocument::diagnostics`].
+pub fn convert(document: &str, options: &ConvertOptions) -> ConvertedDocument {
+    let mut state = ConvertState::new(document, options);
+    let ast = match parse_tree(document) {
+        Ok(ast) => ast,
+        Err(e) => {
+            let html = state.unsupported(
+                DiagnosticKind::ParseError,
+                "",
+                format!("failed to parse document: {:?}", e),
+                true,
+            );
+            return ConvertedDocument {
+                html,
+                diagnostics: state.diagnostics,
+                ..Default::default()
+            };
+        }
+    };
     // We do not have any carryover tag when starting to convert the document
-    let html = to_html(&ast, &[], &VecDeque::new(), root_url, &mut toc);
+    let mut html = to_html(&ast, &[], &VecDeque::new(), &mut state);
+    for footnote in state.footnotes.iter().filter(|footnote| footnote.content.is_empty()) {
+        warn!("[converter] Footnote '{}' is referenced but never defined", footnote.title);
+    }
+    if !options.omit_footnotes {
+        html.push_str(&footnotes_section(&state.footnotes));
+    }
 
-    (html, toc)
+    ConvertedDocument {
+        html,
+        toc: state.toc,
+        footnotes: state.footnotes,
+        diagnostics: state.diagnostics,
+        images: state.images,
+        links: state.links,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::path::Path;
+
+    use super::*;
+    use crate::cache::TemplateDeps;
+    use crate::config::{ImageFormat, SiteConfigImages};
+    use crate::converter::links::IndexedPage;
+
+    fn diagnostics_of(converted: &ConvertedDocument, kind: DiagnosticKind) -> Vec<&str> {
+        converted
+            .diagnostics
+            .iter()
+            .filter(|diagnostic| diagnostic.kind == kind)
+            .map(|diagnostic| diagnostic.node.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_convert_footnotes() {
+        let document = "Norg {^ note} and Norgolith {^ note}.\n\n^ note\nA footnote.\n";
+        let converted = convert(document, &ConvertOptions::default());
+
+        assert!(converted.html.contains(
+            "<sup class=\"footnote-ref\" id=\"fnref-1\"><a href=\"#fn-1\" role=\"doc-noteref\">1</a></sup>"
+        ));
+        assert!(converted.html.contains(
+            "<sup class=\"footnote-ref\" id=\"fnref-1-2\"><a href=\"#fn-1\" role=\"doc-noteref\">1</a></sup>"
+        ));
+        assert!(converted.html.ends_with(
+            "<section class=\"footnotes\" role=\"doc-endnotes\"><ol><li id=\"fn-1\"><p>A footnote.</p>\
+             <a href=\"#fnref-1\" class=\"footnote-backref\" role=\"doc-backlink\">↩</a>\
+             <a href=\"#fnref-1-2\" class=\"footnote-backref\" role=\"doc-backlink\">↩</a>\
+             </li></ol></section>"
+        ));
+        assert_eq!(converted.footnotes.len(), 1);
+        assert_eq!(converted.footnotes[0].references, 2);
+
+        let options = ConvertOptions {
+            omit_footnotes: true,
+            ..Default::default()
+        };
+        let converted = convert(document, &options);
+        assert!(!converted.html.contains("<section class=\"footnotes\""));
+        assert_eq!(converted.footnotes.len(), 1);
+    }
+
+    #[test]
+    fn test_convert_math() {
+        let document = "Inline $x + y$ math.\n\n@math\nx = y\n@end\n";
+        let options = ConvertOptions {
+            math: MathRenderer::Katex,
+            ..Default::default()
+        };
+        let converted = convert(document, &options);
+        assert!(converted
+            .html
+            .contains("<span class=\"math math-inline\">\\(x + y\\)</span>"));
+        assert!(converted
+            .html
+            .contains("<div class=\"math math-display\">\\[x = y\\]</div>"));
+
+        let converted = convert(document, &ConvertOptions::default());
+        assert!(converted.html.contains("<math"));
+        assert!(!converted.html.contains("\\("));
+
+        let document = "@math\n\\begin{nope} x \\end{nope}\n@end\n";
+        let converted = convert(document, &ConvertOptions::default());
+        assert!(converted
+            .html
+            .contains("<pre class=\"math math-error\">\\begin{nope} x \\end{nope}</pre>"));
+        assert_eq!(converted.diagnostics.len(), 1);
+        assert_eq!(converted.diagnostics[0].kind, DiagnosticKind::MathError);
+    }
+
+    #[test]
+    fn test_convert_unsupported_diagnostics() {
+        let document = "Some text.\n\n.toc\n";
+        let converted = convert(document, &ConvertOptions::default());
+
+        assert!(converted.html.contains(
+            "<div class=\"lith-unsupported\" title=\"Unsupported Norg syntax\">infirm tag &#x27;.toc&#x27;</div>"
+        ));
+        assert_eq!(converted.diagnostics.len(), 1);
+        let diagnostic = &converted.diagnostics[0];
+        assert_eq!(diagnostic.kind, DiagnosticKind::UnsupportedTag);
+        assert_eq!(diagnostic.node, "infirm tag '.toc'");
+        assert_eq!(diagnostic.span.start_line, 3);
+    }
+
+    #[test]
+    fn test_convert_links() {
+        let mut links = LinkIndex::default();
+        links.insert(
+            Path::new("about.norg"),
+            IndexedPage {
+                permalink: "/about/".into(),
+                headings: vec!["contact".into()],
+                ..Default::default()
+            },
+        );
+        let options = ConvertOptions {
+            source_path: PathBuf::from("index.norg"),
+            ..Default::default()
+        }
+        .with_links(Arc::new(links));
+        let document = "{:about:}[About], {:about:* Contact}[Contact], {:missing:}[Missing] and \
+                        {:about:* Nowhere}[Nowhere].\n";
+        let converted = convert(document, &options);
+
+        assert!(converted.html.contains("<a href=\"/about/\">About</a>"));
+        assert!(converted
+            .html
+            .contains("<a href=\"/about/#contact\">Contact</a>"));
+        assert!(converted.html.contains("<a href=\"missing\">Missing</a>"));
+        let targets: Vec<_> = converted
+            .links
+            .iter()
+            .map(|link| link.target.as_str())
+            .collect();
+        assert_eq!(targets, vec!["about", "missing"]);
+        assert_eq!(
+            diagnostics_of(&converted, DiagnosticKind::BrokenLink),
+            vec![
+                "link to missing file 'missing'",
+                "link to missing heading 'nowhere' in 'about'"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_convert_heading_ids() {
+        let document = "{* Setup}[Setup]\n\n* Setup\n* Setup\n* Other\n";
+        let converted = convert(document, &ConvertOptions::default());
+
+        // Links select headings by title, repeated titles resolve to the first heading
+        assert!(converted.html.contains("<a href=\"#setup\">Setup</a>"));
+        assert!(converted.html.contains("<h1 id=\"setup\">Setup</h1>"));
+        assert!(converted.html.contains("<h1 id=\"setup-1\">Setup</h1>"));
+        let ids: Vec<_> = converted
+            .toc
+            .iter()
+            .map(|entry| entry.id.as_str())
+            .collect();
+        assert_eq!(ids, vec!["setup", "setup-1", "other"]);
+        assert_eq!(heading_ids(document, SlugStrategy::Ascii), ids);
+    }
+
+    #[test]
+    fn test_convert_definitions_tables_and_todos() {
+        let document = "$ Norg\nA structured markup format.\n\n\
+                        : A1\nName\n: >\nStatus\n: A2\nNorgolith\n: >\nStable\n\n\
+                        - (x) Write the first post\n- (-) Pick a theme\n- ( ) Deploy the site\n";
+        let converted = convert(document, &ConvertOptions::default());
+
+        assert!(converted
+            .html
+            .contains("<dl><dt>Norg</dt><dd>A structured markup format.</dd></dl>"));
+        assert!(converted.html.contains(
+            "<table><tbody><tr><td>Name</td><td>Status</td></tr>\
+             <tr><td>Norgolith</td><td>Stable</td></tr></tbody></table>"
+        ));
+        assert!(converted.html.contains(
+            "<li class=\"todo todo-done\"><input type=\"checkbox\" class=\"todo-status\" disabled checked \
+             aria-label=\"Done\"/> Write the first post</li>"
+        ));
+        assert!(converted.html.contains(
+            "<li class=\"todo todo-pending\"><input type=\"checkbox\" class=\"todo-status\" disabled \
+             aria-label=\"In progress\"/> Pick a theme</li>"
+        ));
+        assert!(converted.html.contains(
+            "<li class=\"todo todo-undone\"><input type=\"checkbox\" class=\"todo-status\" disabled \
+             aria-label=\"Undone\"/> Deploy the site</li>"
+        ));
+        assert!(converted.diagnostics.is_empty());
+    }
+
+    #[test]
+    fn test_convert_table_out_of_bounds() {
+        let document = ": A1\nName\n: A99999999\nTypo\n: ZZZZZZZZZZZZZZ1\nOverflow\n";
+        let converted = convert(document, &ConvertOptions::default());
+
+        assert!(converted
+            .html
+            .contains("<table><tbody><tr><td>Name</td><td>Overflow</td></tr></tbody></table>"));
+        let nodes = diagnostics_of(&converted, DiagnosticKind::UnsupportedNode);
+        assert_eq!(
+            nodes,
+            vec![
+                "table cell position 'A99999999' outside of the 11x11 table",
+                "table cell position 'ZZZZZZZZZZZZZZ1'"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_table_cell_position() {
+        assert_eq!(table_cell_position("A1", None), Some((0, 0)));
+        assert_eq!(table_cell_position("b3", None), Some((2, 1)));
+        assert_eq!(table_cell_position("AA10", None), Some((9, 26)));
+        assert_eq!(table_cell_position(">", Some((1, 1))), Some((1, 2)));
+        assert_eq!(table_cell_position("v", Some((1, 1))), Some((2, 1)));
+        assert_eq!(table_cell_position("A0", None), None);
+        assert_eq!(table_cell_position("1A", None), None);
+        assert_eq!(table_cell_position("ZZZZZZZZZZZZZZ1", None), None);
+    }
+
+    #[test]
+    fn test_convert_macros() {
+        let dir = tempfile::tempdir().unwrap();
+        let definitions = HashMap::from([(
+            "badge".to_string(),
+            "<div class=\"badge {{ args.0 }}\">{{ content }}</div>".to_string(),
+        )]);
+        let macros = Macros::load(dir.path(), &definitions).unwrap();
+        let options = ConvertOptions::default().with_macros(Arc::new(macros));
+
+        let document = "#badge new\nHello\n\n#missing\nWorld\n";
+        let converted = convert(document, &options);
+
+        assert!(converted
+            .html
+            .contains("<div class=\"badge new\"><p>Hello</p></div>"));
+        assert!(converted.html.contains("<p>World</p>"));
+        assert_eq!(
+            diagnostics_of(&converted, DiagnosticKind::UnsupportedMacro),
+            vec!["unknown macro '#missing'"]
+        );
+    }
+
+    #[test]
+    fn test_convert_shortcodes() {
+        let mut tera = tera::Tera::default();
+        tera.add_raw_template(
+            "shortcodes/youtube.html",
+            "<iframe src=\"https://www.youtube.com/embed/{{ args.0 }}\"></iframe>",
+        )
+        .unwrap();
+        let shortcodes = Shortcodes::load(&tera, &TemplateDeps::new(&tera)).unwrap();
+        let options = ConvertOptions::default().with_shortcodes(Arc::new(shortcodes));
+
+        let document = ".shortcode youtube abc\n\n.shortcode vimeo 123\n";
+        let converted = convert(document, &options);
+
+        assert!(converted
+            .html
+            .contains("<iframe src=\"https://www.youtube.com/embed/abc\"></iframe>"));
+        assert_eq!(
+            diagnostics_of(&converted, DiagnosticKind::UnsupportedTag),
+            vec!["unknown shortcode 'vimeo'"]
+        );
+    }
+
+    #[test]
+    fn test_convert_images() {
+        let dir = tempfile::tempdir().unwrap();
+        let assets_dir = dir.path().join("assets");
+        std::fs::create_dir_all(&assets_dir).unwrap();
+        image::DynamicImage::new_rgb8(1000, 500)
+            .save(assets_dir.join("photo.png"))
+            .unwrap();
+        let config = SiteConfigImages {
+            widths: vec![250],
+            formats: vec![ImageFormat::WebP],
+            ..Default::default()
+        };
+        let pipeline = ImagePipeline::new(config, vec![assets_dir], dir.path().join("cache"));
+        let options = ConvertOptions::default().with_images(Arc::new(pipeline));
+
+        let document = ".image /assets/photo.png Photo The caption\n\n.image /assets/logo.svg\n";
+        let converted = convert(document, &options);
+
+        assert!(converted.html.contains(
+            "<figure><picture><source type=\"image/webp\" \
+             srcset=\"/assets/photo-png-250w.webp 250w, /assets/photo-png-1000w.webp 1000w\" sizes=\"100vw\">\
+             <img src=\"/assets/photo.png\" alt=\"Photo\" \
+             srcset=\"/assets/photo-png-250w.png 250w, /assets/photo.png 1000w\" sizes=\"100vw\" \
+             width=\"1000\" height=\"500\" loading=\"lazy\" decoding=\"async\"/></picture>\
+             <figcaption>The caption</figcaption></figure>"
+        ));
+        assert!(converted
+            .html
+            .contains("<img src=\"/assets/logo.svg\" loading=\"lazy\" decoding=\"async\"/>"));
+        assert_eq!(converted.images.len(), 1);
+        assert_eq!(
+            (converted.images[0].width, converted.images[0].height),
+            (1000, 500)
+        );
+        assert_eq!(
+            diagnostics_of(&converted, DiagnosticKind::MissingAlt),
+            vec!["image '/assets/logo.svg' has no alt text"]
+        );
+    }
 }
@@ -0,0 +1,525 @@
+//! Responsive images: resized variants and modern formats of the local images used by `.image`
+//! tags.
+//!
+//! Variants are generated once per source image content and kept in the site cache directory.
+//! They are published next to their original image, so `/assets/photo.jpg` gets
+//! `/assets/photo-jpg-480w.jpg`, `/assets/photo-jpg-480w.webp` and so on. The source extension
+//! keeps apart the variants of `/assets/photo.jpg` and `/assets/photo.png`.
+
+use std::collections::HashMap;
+use std::io::Cursor;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use colored::Colorize;
+use eyre::{eyre, Result};
+use image::codecs::{avif::AvifEncoder, jpeg::JpegEncoder, png::PngEncoder, webp::WebPEncoder};
+use image::{imageops::FilterType, DynamicImage, ImageReader};
+use tracing::{debug, warn};
+
+use crate::config::{ImageFormat, SiteConfigImages};
+
+/// Extensions of the images the pipeline processes, other images (e.g. SVG or animated GIF)
+/// are used as-is
+const SOURCE_EXTENSIONS: &[&str] = &["jpg", "jpeg", "png", "webp"];
+
+/// AVIF encoder speed, from 1 (slowest, smallest) to 10 (fastest)
+const AVIF_SPEED: u8 = 8;
+
+/// Format of an image variant
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum VariantFormat {
+    Jpeg,
+    Png,
+    WebP,
+    Avif,
+}
+
+impl VariantFormat {
+    fn from_extension(ext: &str) -> Option<Self> {
+        match ext.to_ascii_lowercase().as_str() {
+            "jpg" | "jpeg" => Some(Self::Jpeg),
+            "png" => Some(Self::Png),
+            "webp" => Some(Self::WebP),
+            "avif" => Some(Self::Avif),
+            _ => None,
+        }
+    }
+
+    fn extension(self) -> &'static str {
+        match self {
+            Self::Jpeg => "jpg",
+            Self::Png => "png",
+            Self::WebP => "webp",
+            Self::Avif => "avif",
+        }
+    }
+
+    pub fn mime_type(self) -> &'static str {
+        match self {
+            Self::Jpeg => "image/jpeg",
+            Self::Png => "image/png",
+            Self::WebP => "image/webp",
+            Self::Avif => "image/avif",
+        }
+    }
+}
+
+impl From<ImageFormat> for VariantFormat {
+    fn from(format: ImageFormat) -> Self {
+        match format {
+            ImageFormat::WebP => Self::WebP,
+            ImageFormat::Avif => Self::Avif,
+        }
+    }
+}
+
+/// A single variant of an image
+#[derive(Clone, Debug)]
+pub struct ImageVariant {
+    /// Site-relative URL, e.g. `/assets/photo-jpg-480w.webp`
+    pub url: String,
+    pub width: u32,
+    pub format: VariantFormat,
+    /// Cached file of the variant, the source image itself for the original one
+    file: PathBuf,
+    original: bool,
+}
+
+/// A local image and its variants
+#[derive(Clone, Debug)]
+pub struct ProcessedImage {
+    /// Hash of the source image content
+    pub hash: String,
+    pub width: u32,
+    pub height: u32,
+    /// Format of the source image
+    pub format: VariantFormat,
+    /// Every variant, grouped by format (source format first) and sorted by width
+    pub variants: Vec<ImageVariant>,
+}
+
+impl ProcessedImage {
+    /// Returns the `srcset` of the variants with the given format
+    ///
+    /// # Arguments
+    /// * `format` - The variants format.
+    /// * `root_url` - URL prepended to the site-relative variant URLs.
+    pub fn srcset(&self, format: VariantFormat, root_url: &str) -> String {
+        self.variants
+            .iter()
+            .filter(|variant| variant.format == format)
+            .map(|variant| format!("{}{} {}w", root_url, variant.url, variant.width))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    /// Formats of the variants other than the source format, in the configured order
+    pub fn modern_formats(&self) -> Vec<VariantFormat> {
+        let mut formats = Vec::new();
+        for variant in &self.variants {
+            if variant.format != self.format && !formats.contains(&variant.format) {
+                formats.push(variant.format);
+            }
+        }
+        formats
+    }
+}
+
+/// Generates and keeps track of the image variants used by a site
+#[derive(Debug)]
+pub struct ImagePipeline {
+    config: SiteConfigImages,
+    /// Directories `/assets/...` image URLs are looked up in, in order
+    assets_dirs: Vec<PathBuf>,
+    /// Directory of the cached variants
+    cache_dir: PathBuf,
+    /// Hash of the configuration, used to invalidate cached conversions. Changed images only
+    /// invalidate the pages using them, see [`ImagePipeline::register`]
+    fingerprint: String,
+    /// Images processed so far, by source URL
+    processed: Mutex<HashMap<String, Arc<ProcessedImage>>>,
+}
+
+impl ImagePipeline {
+    /// Creates the pipeline of a site
+    ///
+    /// # Arguments
+    /// * `config` - The `[images]` section of the site configuration.
+    /// * `assets_dirs` - The site and theme assets directories, in lookup order.
+    /// * `cache_dir` - Directory where the generated variants are cached.
+    pub fn new(config: SiteConfigImages, assets_dirs: Vec<PathBuf>, cache_dir: PathBuf) -> Self {
+        // Source images are not part of it, the pages using an image record its content hash
+        let fingerprint = blake3::hash(
+            serde_json::to_string(&config)
+                .unwrap_or_default()
+                .as_bytes(),
+        )
+        .to_hex()
+        .to_string();
+
+        Self {
+            config,
+            assets_dirs,
+            cache_dir,
+            fingerprint,
+            processed: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn config(&self) -> &SiteConfigImages {
+        &self.config
+    }
+
+    pub fn fingerprint(&self) -> &str {
+        &self.fingerprint
+    }
+
+    /// Processes the image at the given URL, generating its missing variants
+    ///
+    /// # Returns
+    /// `None` when the URL does not point to a local image the pipeline can process, e.g. remote
+    /// images, SVG files or images that do not exist.
+    pub fn process(&self, src: &str) -> Result<Option<Arc<ProcessedImage>>> {
+        if let Some(image) = self.processed.lock().unwrap().get(src) {
+            return Ok(Some(image.clone()));
+        }
+        let Some(source) = self.source_path(src) else {
+            return Ok(None);
+        };
+
+        let image = Arc::new(self.process_source(src, &source)?);
+        self.processed
+            .lock()
+            .unwrap()
+            .insert(src.to_string(), image.clone());
+        Ok(Some(image))
+    }
+
+    /// Processes the images recorded in the `images` field of a cached page metadata
+    ///
+    /// Pages loaded from the build cache are not converted again, this makes sure the variants
+    /// they use are still published.
+    ///
+    /// # Returns
+    /// Whether every image still has the content it had when the page was converted. Otherwise
+    /// the page has to be converted again, e.g. for the new size of a replaced image.
+    pub fn register(&self, metadata: &serde_json::Value) -> bool {
+        let Some(images) = metadata.get("images").and_then(|v| v.as_array()) else {
+            return true;
+        };
+        let mut unchanged = true;
+        for image in images {
+            let Some(src) = image.get("src").and_then(|v| v.as_str()) else {
+                continue;
+            };
+            let hash = image.get("hash").and_then(|v| v.as_str());
+            match self.process(src) {
+                Ok(Some(processed)) => unchanged &= hash == Some(processed.hash.as_str()),
+                Ok(None) => unchanged = false,
+                Err(e) => {
+                    warn!("Failed to process image '{}': {}", src, e);
+                    unchanged = false;
+                }
+            }
+        }
+        unchanged
+    }
+
+    /// Whether an image processed so far changed or was removed since it was processed
+    pub fn is_outdated(&self) -> bool {
+        self.processed.lock().unwrap().iter().any(|(src, image)| {
+            self.source_path(src)
+                .and_then(|source| std::fs::read(source).ok())
+                .is_none_or(|bytes| blake3::hash(&bytes).to_hex().as_str() != image.hash)
+        })
+    }
+
+    /// Returns the cached file of a generated variant
+    ///
+    /// Variants of images that were not processed yet are generated on demand, as the
+    /// development server can serve pages loaded from the build cache.
+    pub fn variant_file(&self, url: &str) -> Option<PathBuf> {
+        if let Some(file) = self.find_variant(url) {
+            return Some(file);
+        }
+
+        // `/assets/photo-jpg-480w.webp` can only come from `/assets/photo.jpg`
+        let (stem, _) = url.rsplit_once('.')?;
+        let (stem, width) = stem.rsplit_once('-')?;
+        width.strip_suffix('w')?.parse::<u32>().ok()?;
+        let (base, ext) = stem.rsplit_once('-')?;
+        match self.process(&format!("{}.{}", base, ext)) {
+            Ok(Some(_)) => self.find_variant(url),
+            _ => None,
+        }
+    }
+
+    fn find_variant(&self, url: &str) -> Option<PathBuf> {
+        self.processed
+            .lock()
+            .unwrap()
+            .values()
+            .flat_map(|image| image.variants.iter())
+            .find(|variant| !variant.original && variant.url == url)
+            .map(|variant| variant.file.clone())
+    }
+
+    /// Copies the variants of every processed image to the public directory
+    ///
+    /// # Returns
+    /// The paths of the written files.
+    pub fn publish(&self, public_dir: &Path) -> Result<Vec<PathBuf>> {
+        let processed = self.processed.lock().unwrap();
+        let mut published = Vec::new();
+        for variant in processed
+            .values()
+            .flat_map(|image| image.variants.iter())
+            .filter(|variant| !variant.original)
+        {
+            let dest = public_dir.join(variant.url.trim_start_matches('/'));
+            if let Some(parent) = dest.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::copy(&variant.file, &dest).map_err(|e| {
+                eyre!(
+                    "{} '{}': {}",
+                    "Failed to publish image variant".bold(),
+                    variant.url,
+                    e
+                )
+            })?;
+            published.push(dest);
+        }
+        Ok(published)
+    }
+
+    /// Maps an `/assets/...` URL to the source image file
+    fn source_path(&self, src: &str) -> Option<PathBuf> {
+        let rel_path = src.strip_prefix("/assets/")?;
+        self.assets_dirs
+            .iter()
+            .map(|dir| dir.join(rel_path))
+            .find(|path| is_source(path) && path.is_file())
+    }
+
+    fn process_source(&self, src: &str, source: &Path) -> Result<ProcessedImage> {
+        let bytes = std::fs::read(source)
+            .map_err(|e| eyre!("{} '{}': {}", "Failed to read image".bold(), src, e))?;
+        let hash = blake3::hash(&bytes).to_hex();
+        let source_format = source
+            .extension()
+            .and_then(|ext| VariantFormat::from_extension(&ext.to_string_lossy()))
+            .ok_or_else(|| eyre!("unsupported image format"))?;
+        let (width, height) = ImageReader::new(Cursor::new(&bytes))
+            .with_guessed_format()?
+            .into_dimensions()?;
+
+        let mut widths: Vec<u32> = self
+            .config
+            .widths
+            .iter()
+            .copied()
+            .filter(|w| *w < width)
+            .collect();
+        widths.push(width);
+        widths.sort_unstable();
+        widths.dedup();
+
+        let mut formats = vec![source_format];
+        for format in &self.config.formats {
+            let format = VariantFormat::from(*format);
+            if !formats.contains(&format) {
+                formats.push(format);
+            }
+        }
+
+        let (base, source_ext) = src.rsplit_once('.').unwrap_or((src, ""));
+        let quality = self.config.quality;
+        // Only decoded when a variant is missing from the cache
+        let mut decoded: Option<DynamicImage> = None;
+        let mut variants = Vec::new();
+        for format in formats {
+            for &variant_width in &widths {
+                if format == source_format && variant_width == width {
+                    variants.push(ImageVariant {
+                        url: src.to_string(),
+                        width,
+                        format,
+                        file: source.to_path_buf(),
+                        original: true,
+                    });
+                    continue;
+                }
+
+                let file = self.cache_dir.join(format!(
+                    "{}-{}-q{}.{}",
+                    &hash[..16],
+                    variant_width,
+                    quality,
+                    format.extension()
+                ));
+                if !file.exists() {
+                    if decoded.is_none() {
+                        decoded = Some(image::load_from_memory(&bytes)?);
+                    }
+                    let image = decoded.as_ref().unwrap();
+                    let resized;
+                    let image = if variant_width == width {
+                        image
+                    } else {
+                        let variant_height =
+                            ((height as u64 * variant_width as u64) / width as u64).max(1) as u32;
+                        resized =
+                            image.resize_exact(variant_width, variant_height, FilterType::Lanczos3);
+                        &resized
+                    };
+                    debug!(src, variant_width, ?format, "Generating image variant");
+                    write_variant(&file, &encode(image, format, quality)?)?;
+                }
+
+                variants.push(ImageVariant {
+                    url: format!(
+                        "{}-{}-{}w.{}",
+                        base,
+                        source_ext,
+                        variant_width,
+                        format.extension()
+                    ),
+                    width: variant_width,
+                    format,
+                    file,
+                    original: false,
+                });
+            }
+        }
+
+        Ok(ProcessedImage {
+            hash: hash.to_string(),
+            width,
+            height,
+            format: source_format,
+            variants,
+        })
+    }
+}
+
+fn is_source(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| {
+        SOURCE_EXTENSIONS.contains(&ext.to_string_lossy().to_lowercase().as_str())
+    })
+}
+
+fn encode(image: &DynamicImage, format: VariantFormat, quality: u8) -> Result<Vec<u8>> {
+    // The encoders only take 8-bit RGB(A) pixels, and JPEG has no alpha channel at all
+    let image = if image.color().has_alpha() && format != VariantFormat::Jpeg {
+        DynamicImage::ImageRgba8(image.to_rgba8())
+    } else {
+        DynamicImage::ImageRgb8(image.to_rgb8())
+    };
+
+    let mut buffer = Vec::new();
+    match format {
+        VariantFormat::Jpeg => {
+            image.write_with_encoder(JpegEncoder::new_with_quality(&mut buffer, quality))?
+        }
+        VariantFormat::Png => image.write_with_encoder(PngEncoder::new(&mut buffer))?,
+        // The WebP encoder of `image` is lossless only, `quality` does not apply to it
+        VariantFormat::WebP => image.write_with_encoder(WebPEncoder::new_lossless(&mut buffer))?,
+        VariantFormat::Avif => image.write_with_encoder(AvifEncoder::new_with_speed_quality(
+            &mut buffer,
+            AVIF_SPEED,
+            quality,
+        ))?,
+    }
+    Ok(buffer)
+}
+
+/// Writes a variant to the cache, through a temporary file as pages are converted in parallel
+fn write_variant(file: &Path, content: &[u8]) -> Result<()> {
+    let write = || -> std::io::Result<()> {
+        let dir = file.parent().unwrap_or(Path::new("."));
+        std::fs::create_dir_all(dir)?;
+        let tmp = tempfile::NamedTempFile::new_in(dir)?;
+        std::fs::write(tmp.path(), content)?;
+        tmp.persist(file).map_err(|e| e.error)?;
+        Ok(())
+    };
+    write().map_err(|e| {
+        eyre!(
+            "{} '{}': {}",
+            "Failed to cache image variant".bold(),
+            file.display(),
+            e
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_process_image() {
+        let dir = tempfile::tempdir().unwrap();
+        let assets_dir = dir.path().join("assets");
+        std::fs::create_dir_all(&assets_dir).unwrap();
+        DynamicImage::new_rgb8(1000, 500)
+            .save(assets_dir.join("photo.png"))
+            .unwrap();
+
+        let config = SiteConfigImages {
+            widths: vec![250, 2000],
+            formats: vec![ImageFormat::WebP],
+            ..Default::default()
+        };
+        let pipeline = ImagePipeline::new(
+            config.clone(),
+            vec![assets_dir.clone()],
+            dir.path().join("cache"),
+        );
+        let image = pipeline.process("/assets/photo.png").unwrap().unwrap();
+
+        assert_eq!((image.width, image.height), (1000, 500));
+        assert_eq!(image.modern_formats(), vec![VariantFormat::WebP]);
+        assert_eq!(
+            image.srcset(VariantFormat::Png, "https://example.com"),
+            "https://example.com/assets/photo-png-250w.png 250w, https://example.com/assets/photo.png 1000w"
+        );
+        assert_eq!(
+            image.srcset(VariantFormat::WebP, ""),
+            "/assets/photo-png-250w.webp 250w, /assets/photo-png-1000w.webp 1000w"
+        );
+        assert!(pipeline
+            .variant_file("/assets/photo-png-250w.webp")
+            .is_some());
+        assert!(pipeline
+            .variant_file("/assets/photo-jpg-250w.webp")
+            .is_none());
+        assert!(pipeline.process("/assets/missing.png").unwrap().is_none());
+        assert!(pipeline
+            .process("https://example.com/photo.png")
+            .unwrap()
+            .is_none());
+
+        let public_dir = dir.path().join("public");
+        assert_eq!(pipeline.publish(&public_dir).unwrap().len(), 3);
+        assert!(public_dir.join("assets/photo-png-1000w.webp").exists());
+
+        // Cached pages are only converted again when their images change
+        let page = |hash: &str| {
+            let image = serde_json::json!({ "src": "/assets/photo.png", "hash": hash });
+            serde_json::json!({ "images": [image] })
+        };
+        assert!(pipeline.register(&page(&image.hash)));
+        assert!(!pipeline.register(&page("outdated")));
+        assert!(!pipeline.is_outdated());
+        DynamicImage::new_rgb8(800, 400)
+            .save(assets_dir.join("photo.png"))
+            .unwrap();
+        assert!(pipeline.is_outdated());
+        let reloaded = ImagePipeline::new(config, vec![assets_dir], dir.path().join("cache"));
+        assert_eq!(reloaded.fingerprint(), pipeline.fingerprint());
+        assert!(!reloaded.register(&page(&image.hash)));
+    }
+}
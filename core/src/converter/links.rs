@@ -0,0 +1,352 @@
+//! Site-wide index of pages and headings, used to resolve internal links between Norg files.
+
+use std::collections::HashMap;
+use std::path::{Component, Path, PathBuf};
+
+/// A content page that links can point to
+#[derive(Clone, Debug, Default)]
+pub struct IndexedPage {
+    /// Final URL of the page, as computed by `shared::compute_permalink`
+    pub permalink: String,
+    /// `title` metadata field, used to resolve wiki links
+    pub title: Option<String>,
+    /// Ids of every heading in the page, in document order
+    pub headings: Vec<String>,
+    /// `aliases` metadata field, the former paths of the page redirected to its permalink
+    pub aliases: Vec<String>,
+}
+
+impl IndexedPage {
+    pub fn has_heading(&self, id: &str) -> bool {
+        self.headings.iter().any(|heading| heading == id)
+    }
+
+    /// Hashes what links to the page can resolve to: its permalink, title and headings
+    fn hash(&self, hasher: &mut blake3::Hasher) {
+        hasher.update(self.permalink.as_bytes());
+        hasher.update(b"\0");
+        hasher.update(self.title.as_deref().unwrap_or_default().as_bytes());
+        hasher.update(b"\0");
+        hasher.update(&(self.headings.len() as u64).to_le_bytes());
+        for heading in &self.headings {
+            hasher.update(heading.as_bytes());
+            hasher.update(b"\0");
+        }
+    }
+}
+
+/// Target of wiki links in [`ResolvedLink`], they are looked up in the whole site
+pub const WIKI_TARGET: &str = "?";
+
+/// A link target looked up in the index while converting a page
+///
+/// Pages record the targets they looked up, so cached pages are converted again when one of
+/// them changes instead of when any page of the site changes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ResolvedLink {
+    /// Path of the target relative to the content directory and without the `.norg`
+    /// extension, or [`WIKI_TARGET`]
+    pub target: String,
+    /// Hash of the indexed target when the page was converted, see [`LinkIndex::target_hash`]
+    pub hash: String,
+}
+
+/// Index of every page in the content directory, keyed by its path relative to the content
+/// directory and without the `.norg` extension (e.g. `posts/hello`)
+#[derive(Clone, Debug, Default)]
+pub struct LinkIndex {
+    pages: HashMap<PathBuf, IndexedPage>,
+}
+
+impl LinkIndex {
+    /// Registers a page, `rel_path` is relative to the content directory
+    pub fn insert(&mut self, rel_path: &Path, page: IndexedPage) {
+        self.pages.insert(rel_path.with_extension(""), page);
+    }
+
+    /// Number of indexed pages
+    pub fn len(&self) -> usize {
+        self.pages.len()
+    }
+
+    /// Returns the page stored for a content file
+    pub fn page(&self, rel_path: &Path) -> Option<&IndexedPage> {
+        self.pages.get(&rel_path.with_extension(""))
+    }
+
+    /// Resolves the file part of a Norg link (`{:path:}`) written in the `from` file
+    ///
+    /// Paths starting with `/` or `$/` are relative to the content directory, any other path
+    /// is relative to the directory of `from`. The `.norg` extension is optional and directory
+    /// links resolve to their `index.norg` file.
+    pub fn resolve(&self, from: &Path, target: &str) -> Option<&IndexedPage> {
+        self.lookup(&target_path(from, target))
+    }
+
+    /// Returns the page stored at `path` or, for directories, at `path/index`
+    fn lookup(&self, path: &Path) -> Option<&IndexedPage> {
+        self.pages
+            .get(path)
+            .or_else(|| self.pages.get(&path.join("index")))
+    }
+
+    /// Records the lookup of the file part of a Norg link, see [`LinkIndex::resolve`]
+    pub fn file_link(&self, from: &Path, target: &str) -> ResolvedLink {
+        let target = target_path(from, target).to_string_lossy().into_owned();
+        ResolvedLink {
+            hash: self.target_hash(&target),
+            target,
+        }
+    }
+
+    /// Records the lookup of a wiki link, see [`LinkIndex::find_wiki`]
+    pub fn wiki_link(&self) -> ResolvedLink {
+        ResolvedLink {
+            target: WIKI_TARGET.to_string(),
+            hash: self.fingerprint(),
+        }
+    }
+
+    /// Hash of what a link target resolves to, it changes when the target page is added,
+    /// removed, moved or when its title or headings change
+    ///
+    /// # Arguments
+    /// * `target` - The target of a [`ResolvedLink`].
+    pub fn target_hash(&self, target: &str) -> String {
+        if target == WIKI_TARGET {
+            return self.fingerprint();
+        }
+        let mut hasher = blake3::Hasher::new();
+        match self.lookup(Path::new(target)) {
+            Some(page) => {
+                hasher.update(b"page\0");
+                page.hash(&mut hasher);
+            }
+            None => {
+                hasher.update(b"missing\0");
+            }
+        }
+        hasher.finalize().to_hex().to_string()
+    }
+
+    /// Whether the link targets recorded in the `links` field of a page metadata still resolve
+    /// the same way, see [`ResolvedLink`]
+    pub fn is_current(&self, metadata: &serde_json::Value) -> bool {
+        let Some(links) = metadata.get("links").and_then(|v| v.as_array()) else {
+            return true;
+        };
+        links.iter().all(|link| {
+            let target = link.get("target").and_then(|v| v.as_str());
+            let hash = link.get("hash").and_then(|v| v.as_str());
+            target.is_some_and(|target| hash == Some(self.target_hash(target).as_str()))
+        })
+    }
+
+    /// Finds the target of a wiki link (`{? title}`) across the whole site
+    ///
+    /// Page titles take precedence over headings. `anchor` is compared against the anchor of the
+    /// page titles and the heading ids, so it must be computed the same way heading ids are.
+    /// Returns the page and, when the match is a heading, its id.
+    pub fn find_wiki(
+        &self,
+        anchor: &str,
+        to_anchor: impl Fn(&str) -> String,
+    ) -> Option<(&IndexedPage, Option<&str>)> {
+        // Sort the pages so the same link always resolves to the same page
+        let mut pages: Vec<_> = self.pages.iter().collect();
+        pages.sort_by(|a, b| a.0.cmp(b.0));
+
+        let by_title = pages.iter().find(|(_, page)| {
+            page.title
+                .as_deref()
+                .is_some_and(|title| to_anchor(title).eq_ignore_ascii_case(anchor))
+        });
+        if let Some((_, page)) = by_title {
+            return Some((page, None));
+        }
+
+        pages.into_iter().find_map(|(_, page)| {
+            page.headings
+                .iter()
+                .find(|id| id.eq_ignore_ascii_case(anchor))
+                .map(|id| (page, Some(id.as_str())))
+        })
+    }
+
+    /// Iterates over the indexed pages, in no particular order
+    pub fn pages(&self) -> impl Iterator<Item = &IndexedPage> {
+        self.pages.values()
+    }
+
+    /// Hash of every indexed permalink, title and heading
+    ///
+    /// Changes whenever a wiki link could resolve differently, as they are looked up in the
+    /// whole site.
+    pub fn fingerprint(&self) -> String {
+        let mut keys: Vec<_> = self.pages.keys().collect();
+        keys.sort();
+
+        let mut hasher = blake3::Hasher::new();
+        for key in keys {
+            hasher.update(key.to_string_lossy().as_bytes());
+            hasher.update(b"\0");
+            self.pages[key].hash(&mut hasher);
+        }
+        hasher.finalize().to_hex().to_string()
+    }
+}
+
+/// Resolves the file part of a Norg link written in the `from` file into an index key
+fn target_path(from: &Path, target: &str) -> PathBuf {
+    let target = target.trim();
+    let target = target.strip_suffix(".norg").unwrap_or(target);
+    if let Some(rest) = target
+        .strip_prefix("$/")
+        .or_else(|| target.strip_prefix('/'))
+    {
+        normalize(Path::new(rest))
+    } else {
+        normalize(&from.parent().unwrap_or(Path::new("")).join(target))
+    }
+}
+
+/// Lexically resolves `.` and `..` components
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::Normal(part) => normalized.push(part),
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            _ => {}
+        }
+    }
+    normalized
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn index() -> LinkIndex {
+        let mut index = LinkIndex::default();
+        index.insert(
+            Path::new("about.norg"),
+            IndexedPage {
+                permalink: "/about/".into(),
+                title: Some("About me".into()),
+                headings: vec!["Contact".into()],
+                ..Default::default()
+            },
+        );
+        index.insert(
+            Path::new("posts/index.norg"),
+            IndexedPage {
+                permalink: "/posts/".into(),
+                ..Default::default()
+            },
+        );
+        index.insert(
+            Path::new("posts/hello.norg"),
+            IndexedPage {
+                permalink: "/posts/hello/".into(),
+                title: Some("Hello".into()),
+                headings: vec!["Setup".into()],
+                ..Default::default()
+            },
+        );
+        index
+    }
+
+    #[test]
+    fn test_resolve_paths() {
+        let index = index();
+        let from = Path::new("posts/hello.norg");
+
+        assert_eq!(index.resolve(from, "/about").unwrap().permalink, "/about/");
+        assert_eq!(index.resolve(from, "$/about.norg").unwrap().permalink, "/about/");
+        assert_eq!(index.resolve(from, "../about").unwrap().permalink, "/about/");
+        assert_eq!(index.resolve(from, "index").unwrap().permalink, "/posts/");
+        assert_eq!(index.resolve(from, "/posts/").unwrap().permalink, "/posts/");
+        assert!(index.resolve(from, "missing").is_none());
+    }
+
+    #[test]
+    fn test_find_wiki() {
+        let index = index();
+        let to_anchor = |s: &str| s.replace(' ', "-");
+
+        let (page, heading) = index.find_wiki("About-me", to_anchor).unwrap();
+        assert_eq!((page.permalink.as_str(), heading), ("/about/", None));
+
+        let (page, heading) = index.find_wiki("setup", to_anchor).unwrap();
+        assert_eq!((page.permalink.as_str(), heading), ("/posts/hello/", Some("Setup")));
+
+        assert!(index.find_wiki("nothing", to_anchor).is_none());
+    }
+
+    #[test]
+    fn test_link_targets() {
+        let index = index();
+        let from = Path::new("posts/hello.norg");
+        let about = index.file_link(from, "../about");
+        let missing = index.file_link(from, "missing");
+        assert_eq!(about.target, "about");
+        assert_eq!(missing.target, "posts/missing");
+        let metadata = serde_json::json!({
+            "links": [
+                { "target": about.target, "hash": about.hash },
+                { "target": missing.target, "hash": missing.hash },
+            ]
+        });
+        assert!(index.is_current(&metadata));
+
+        // Changes to pages that are not linked to do not matter
+        let mut changed = index.clone();
+        changed.insert(
+            Path::new("posts/index.norg"),
+            IndexedPage {
+                permalink: "/blog/".into(),
+                ..Default::default()
+            },
+        );
+        assert!(changed.is_current(&metadata));
+        changed.insert(
+            Path::new("about.norg"),
+            IndexedPage {
+                permalink: "/about/".into(),
+                title: Some("About me".into()),
+                headings: vec!["Contact".into(), "Email".into()],
+                ..Default::default()
+            },
+        );
+        assert!(!changed.is_current(&metadata));
+
+        let mut created = index.clone();
+        created.insert(Path::new("posts/missing.norg"), IndexedPage::default());
+        assert!(!created.is_current(&metadata));
+    }
+
+    #[test]
+    fn test_target_hash_separates_headings() {
+        let mut first = LinkIndex::default();
+        first.insert(
+            Path::new("about.norg"),
+            IndexedPage {
+                headings: vec!["ab".into(), "c".into()],
+                ..Default::default()
+            },
+        );
+        let mut second = LinkIndex::default();
+        second.insert(
+            Path::new("about.norg"),
+            IndexedPage {
+                headings: vec!["a".into(), "bc".into()],
+                ..Default::default()
+            },
+        );
+        assert_ne!(first.target_hash("about"), second.target_hash("about"));
+        assert_ne!(first.fingerprint(), second.fingerprint());
+    }
+}
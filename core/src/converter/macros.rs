@@ -0,0 +1,149 @@
+//! User-defined macros, expanded from strong carryover tags (`#name args`).
+//!
+//! Macros are Tera snippets declared in the `[macros]` section of `norgolith.toml` or as files in
+//! the `macros/` directory of the site (`macros/note.html` defines `#note`, `macros/ui/card.html`
+//! defines `#ui.card`).
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use colored::Colorize;
+use eyre::{eyre, Result};
+use tera::{Context, Tera};
+use walkdir::WalkDir;
+
+/// Name of the directory holding the macro files, relative to the site root
+pub const MACROS_DIR: &str = "macros";
+
+/// Set of macros available to the converter
+#[derive(Debug, Default)]
+pub struct Macros {
+    tera: Tera,
+    /// Sources of every macro, sorted by name, used to invalidate cached conversions
+    sources: Vec<(String, String)>,
+}
+
+impl Macros {
+    /// Loads the macros of a site
+    ///
+    /// Macros declared in the configuration take precedence over the files with the same name.
+    ///
+    /// # Arguments
+    /// * `root_dir` - The site root directory.
+    /// * `definitions` - The `[macros]` section of the site configuration.
+    pub fn load(root_dir: &Path, definitions: &HashMap<String, String>) -> Result<Self> {
+        let mut sources = HashMap::new();
+
+        let macros_dir = root_dir.join(MACROS_DIR);
+        if macros_dir.exists() {
+            for entry in WalkDir::new(&macros_dir)
+                .into_iter()
+                .filter_map(|e| e.ok())
+                .filter(|e| e.path().extension().is_some_and(|ext| ext == "html"))
+            {
+                let Ok(rel_path) = entry.path().strip_prefix(&macros_dir) else {
+                    continue;
+                };
+                let name = rel_path
+                    .with_extension("")
+                    .components()
+                    .map(|c| c.as_os_str().to_string_lossy().into_owned())
+                    .collect::<Vec<_>>()
+                    .join(".");
+                let source = std::fs::read_to_string(entry.path()).map_err(|e| {
+                    eyre!(
+                        "{} '{}': {}",
+                        "Failed to read macro".bold(),
+                        rel_path.display(),
+                        e
+                    )
+                })?;
+                sources.insert(name, source);
+            }
+        }
+        for (name, source) in definitions {
+            sources.insert(name.clone(), source.clone());
+        }
+
+        let mut sources: Vec<_> = sources.into_iter().collect();
+        sources.sort();
+
+        let mut tera = Tera::default();
+        // Macro output is already HTML, and their names have no extension so Tera does not
+        // autoescape them anyway
+        tera.autoescape_on(vec![]);
+        tera.add_raw_templates(
+            sources
+                .iter()
+                .map(|(name, source)| (name.as_str(), source.as_str())),
+        )
+        .map_err(|e| eyre!("{}: {:?}", "Failed to parse macros".bold(), e))?;
+
+        Ok(Self { tera, sources })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sources.is_empty()
+    }
+
+    /// Whether a macro with the given name exists
+    pub fn contains(&self, name: &str) -> bool {
+        self.sources.iter().any(|(macro_name, _)| macro_name == name)
+    }
+
+    /// Expands a macro
+    ///
+    /// # Arguments
+    /// * `name` - The macro name, e.g. `note` for `#note`.
+    /// * `args` - The parameters of the carryover tag.
+    /// * `content` - The converted HTML of the object the tag is attached to.
+    pub fn expand(&self, name: &str, args: &[String], content: &str) -> Result<String> {
+        let mut context = Context::new();
+        context.insert("name", name);
+        context.insert("args", args);
+        context.insert("content", content);
+        self.tera.render(name, &context).map_err(|e| {
+            // Tera errors keep the useful message in their source
+            let cause = std::error::Error::source(&e)
+                .map(|source| source.to_string())
+                .unwrap_or_default();
+            eyre!("{} {}", e, cause)
+        })
+    }
+
+    /// Hash of every macro source
+    pub fn fingerprint(&self) -> String {
+        let mut hasher = blake3::Hasher::new();
+        for (name, source) in &self.sources {
+            hasher.update(name.as_bytes());
+            hasher.update(source.as_bytes());
+        }
+        hasher.finalize().to_hex().to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expand_macro() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join("macros/ui")).unwrap();
+        std::fs::write(
+            dir.path().join("macros/ui/card.html"),
+            "<div class=\"card {{ args.0 }}\">{{ content }}</div>",
+        )
+        .unwrap();
+        let definitions =
+            HashMap::from([("badge".to_string(), "<b>{{ args | join(sep=\" \") }}</b>".to_string())]);
+        let macros = Macros::load(dir.path(), &definitions).unwrap();
+
+        let card = macros.expand("ui.card", &["wide".into()], "<p>Hi</p>").unwrap();
+        assert_eq!(card, "<div class=\"card wide\"><p>Hi</p></div>");
+        let badge = macros.expand("badge", &["new".into(), "post".into()], "").unwrap();
+        assert_eq!(badge, "<b>new post</b>");
+        assert!(!macros.contains("missing"));
+        assert!(macros.expand("missing", &[], "").is_err());
+    }
+}
@@ -1,2 +1,9 @@
+pub mod diagnostics;
+pub mod highlight;
 pub mod html;
+pub mod images;
+pub mod links;
+pub mod macros;
 pub mod meta;
+pub mod shortcodes;
+pub mod slug;
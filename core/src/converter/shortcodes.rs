@@ -0,0 +1,135 @@
+//! Shortcodes: Tera partials from `templates/shortcodes/` called from Norg documents.
+//!
+//! `.shortcode youtube dQw4w9WgXcQ` renders `shortcodes/youtube.html`, while a ranged tag like
+//! `|callout warning` ... `|end` renders `shortcodes/callout.html` with the converted content of
+//! the tag.
+
+use eyre::{eyre, Result};
+use tera::{Context, Tera};
+
+use crate::cache::TemplateDeps;
+
+/// Directory of the shortcode templates, relative to the templates directory
+const SHORTCODES_DIR: &str = "shortcodes";
+
+/// Shortcode templates available to the converter
+#[derive(Debug)]
+pub struct Shortcodes {
+    tera: Tera,
+    /// Hash of the shortcode templates and their dependencies, used to invalidate cached
+    /// conversions
+    fingerprint: String,
+}
+
+impl Shortcodes {
+    /// Creates the shortcodes from the site templates
+    ///
+    /// # Arguments
+    /// * `tera` - The site templates, shortcodes can use anything they define (e.g. macros or
+    ///   other partials).
+    /// * `templates` - The dependencies of the site templates, used to fingerprint the
+    ///   shortcodes.
+    ///
+    /// # Returns
+    /// `None` when there are no shortcode templates.
+    pub fn load(tera: &Tera, templates: &TemplateDeps) -> Option<Self> {
+        let prefix = format!("{}/", SHORTCODES_DIR);
+        let mut names: Vec<_> = tera
+            .get_template_names()
+            .filter(|name| name.starts_with(&prefix))
+            .collect();
+        if names.is_empty() {
+            return None;
+        }
+
+        names.sort_unstable();
+        let mut hasher = blake3::Hasher::new();
+        for name in names {
+            hasher.update(name.as_bytes());
+            hasher.update(templates.get(name).hash.as_bytes());
+        }
+
+        Some(Self {
+            tera: tera.clone(),
+            fingerprint: hasher.finalize().to_hex().to_string(),
+        })
+    }
+
+    fn template_name(name: &str) -> String {
+        format!("{}/{}.html", SHORTCODES_DIR, name)
+    }
+
+    /// Whether a shortcode with the given name exists
+    pub fn contains(&self, name: &str) -> bool {
+        let template = Self::template_name(name);
+        self.tera.get_template_names().any(|name| name == template)
+    }
+
+    /// Renders a shortcode
+    ///
+    /// Parameters in the `key=value` form are also available by key in the `kwargs` variable.
+    ///
+    /// # Arguments
+    /// * `name` - The shortcode name, e.g. `youtube` for `shortcodes/youtube.html`.
+    /// * `args` - The shortcode parameters.
+    /// * `content` - The converted HTML inside a ranged shortcode, `None` for infirm ones.
+    pub fn render(&self, name: &str, args: &[String], content: Option<&str>) -> Result<String> {
+        let kwargs: std::collections::BTreeMap<_, _> =
+            args.iter().filter_map(|arg| arg.split_once('=')).collect();
+
+        let mut context = Context::new();
+        context.insert("name", name);
+        context.insert("args", args);
+        context.insert("kwargs", &kwargs);
+        context.insert("content", content.unwrap_or_default());
+        self.tera
+            .render(&Self::template_name(name), &context)
+            .map_err(|e| {
+                let cause = std::error::Error::source(&e)
+                    .map(|source| source.to_string())
+                    .unwrap_or_default();
+                eyre!("{} {}", e, cause)
+            })
+    }
+
+    pub fn fingerprint(&self) -> &str {
+        &self.fingerprint
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_shortcodes() {
+        let mut tera = Tera::default();
+        tera.add_raw_templates(vec![
+            (
+                "shortcodes/youtube.html",
+                "<iframe src=\"https://www.youtube.com/embed/{{ args.0 }}\" title=\"{{ kwargs.title }}\"></iframe>",
+            ),
+            (
+                "shortcodes/callout.html",
+                "<aside class=\"{{ args.0 }}\">{{ content | safe }}</aside>",
+            ),
+        ])
+        .unwrap();
+        let shortcodes = Shortcodes::load(&tera, &TemplateDeps::new(&tera)).unwrap();
+
+        assert!(shortcodes.contains("youtube"));
+        assert!(!shortcodes.contains("missing"));
+        let embed = shortcodes
+            .render("youtube", &["abc".into(), "title=Demo".into()], None)
+            .unwrap();
+        assert_eq!(
+            embed,
+            "<iframe src=\"https://www.youtube.com/embed/abc\" title=\"Demo\"></iframe>"
+        );
+        let callout = shortcodes
+            .render("callout", &["warning".into()], Some("<p>Careful</p>"))
+            .unwrap();
+        assert_eq!(callout, "<aside class=\"warning\"><p>Careful</p></aside>");
+        assert!(Shortcodes::load(&Tera::default(), &TemplateDeps::default()).is_none());
+    }
+}
@@ -0,0 +1,123 @@
+//! Heading id generation, shared by headings, the table of contents and heading links.
+
+use std::collections::HashSet;
+use std::sync::OnceLock;
+
+use regex::Regex;
+
+pub use crate::config::SlugStrategy;
+
+fn tag_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r"<[^>]*>").expect("valid regex"))
+}
+
+fn entity_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r"&(#[0-9]+|#[xX][0-9a-fA-F]+|[a-zA-Z]+);").expect("valid regex"))
+}
+
+/// Turns a (converted) heading title into an id
+///
+/// HTML tags and entities are stripped before applying the strategy, so `<em>Foo</em> &amp; Bar`
+/// becomes `foo-bar` with the default strategy. The `preserve` strategy decodes the entities
+/// instead and drops double quotes, the id must still be escaped when written into an attribute.
+/// Titles without any usable character fall back to `section`.
+pub fn slugify(title: &str, strategy: SlugStrategy) -> String {
+    let text = tag_re().replace_all(title, "");
+
+    let slug = match strategy {
+        SlugStrategy::Preserve => html_escape::decode_html_entities(&text)
+            .trim()
+            .replace('"', "")
+            .replace(' ', "-"),
+        SlugStrategy::Ascii => {
+            let text = entity_re().replace_all(&text, " ");
+            dashed(&deunicode::deunicode(&text).to_lowercase(), |c| {
+                c.is_ascii_alphanumeric()
+            })
+        }
+        SlugStrategy::Unicode => {
+            let text = entity_re().replace_all(&text, " ");
+            dashed(&text.to_lowercase(), char::is_alphanumeric)
+        }
+    };
+
+    if slug.is_empty() {
+        String::from("section")
+    } else {
+        slug
+    }
+}
+
+/// Keeps the characters accepted by `keep` and collapses everything else into single dashes
+fn dashed(text: &str, keep: impl Fn(char) -> bool) -> String {
+    let mut slug = String::with_capacity(text.len());
+    for c in text.chars() {
+        if keep(c) || c == '_' {
+            slug.push(c);
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    slug.trim_end_matches('-').to_string()
+}
+
+/// Generates unique heading ids for a single page
+///
+/// Repeated titles get a numeric suffix (`setup`, `setup-1`, `setup-2`, ...). Links select
+/// headings by title, so they always resolve to the first heading with a repeated title.
+#[derive(Debug, Default)]
+pub struct Slugger {
+    strategy: SlugStrategy,
+    used: HashSet<String>,
+}
+
+impl Slugger {
+    pub fn new(strategy: SlugStrategy) -> Self {
+        Self {
+            strategy,
+            used: HashSet::new(),
+        }
+    }
+
+    /// Returns the id of the next heading with the given title
+    pub fn slug(&mut self, title: &str) -> String {
+        let base = slugify(title, self.strategy);
+        let mut slug = base.clone();
+        let mut suffix = 0;
+        while self.used.contains(&slug) {
+            suffix += 1;
+            slug = format!("{}-{}", base, suffix);
+        }
+        self.used.insert(slug.clone());
+        slug
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slugify_strategies() {
+        let title = "<em>Ünïcode</em> &amp; Title!";
+        assert_eq!(slugify(title, SlugStrategy::Ascii), "unicode-title");
+        assert_eq!(slugify(title, SlugStrategy::Unicode), "ünïcode-title");
+        assert_eq!(slugify(title, SlugStrategy::Preserve), "Ünïcode-&-Title!");
+        assert_eq!(
+            slugify("Say &quot;hi&quot; \"there\"", SlugStrategy::Preserve),
+            "Say-hi-there"
+        );
+        assert_eq!(slugify("&lt;&gt;", SlugStrategy::Ascii), "section");
+    }
+
+    #[test]
+    fn test_slugger_deduplicates() {
+        let mut slugger = Slugger::new(SlugStrategy::Ascii);
+        assert_eq!(slugger.slug("Setup"), "setup");
+        assert_eq!(slugger.slug("Setup"), "setup-1");
+        assert_eq!(slugger.slug("Setup 1"), "setup-1-1");
+        assert_eq!(slugger.slug("setup"), "setup-2");
+    }
+}
@@ -1,3 +1,4 @@
+mod assets;
 mod cache;
 mod cli;
 mod cmd;
@@ -10,6 +11,7 @@ mod schema;
 mod shared;
 mod tera_functions;
 mod theme;
+mod tls;
 
 use eyre::Result;
 use tracing_subscriber::{filter::EnvFilter, fmt::time::ChronoLocal, FmtSubscriber};
@@ -35,7 +37,7 @@ async fn main() -> Result<()> {
     tracing::subscriber::set_global_default(subscriber)?;
 
     if let Err(e) = cli::start().await {
-        tracing::error!("{}", e);
+        tracing::error!("{:#}", e);
         std::process::exit(1);
     }
 
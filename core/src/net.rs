@@ -1,24 +1,93 @@
-use std::net::{Ipv4Addr, SocketAddr, TcpListener};
+use std::net::{IpAddr, Ipv4Addr, SocketAddr, TcpListener, ToSocketAddrs};
 
 use eyre::{eyre, Result};
 
+/// Resolves the address to bind to from the `--host` value.
+///
+/// The host can be an IP address or a host name, optionally with a port overriding the given
+/// one: `0.0.0.0`, `192.168.1.10:4000`, `::`, `[::1]:4000` or `my-machine.local`. Without a
+/// host, the server is only reachable from localhost.
+///
+/// # Arguments
+/// * `host` - The `--host` value, if any.
+/// * `port` - The port to use when the host does not have one.
+pub fn bind_address(host: Option<&str>, port: u16) -> Result<SocketAddr> {
+    let Some(host) = host else {
+        return Ok((Ipv4Addr::LOCALHOST, port).into());
+    };
+    if let Ok(addr) = host.parse::<SocketAddr>() {
+        return Ok(addr);
+    }
+    if let Ok(ip) = host
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .parse::<IpAddr>()
+    {
+        return Ok((ip, port).into());
+    }
+
+    let (name, port) = match host.rsplit_once(':') {
+        Some((name, host_port)) => (
+            name,
+            host_port
+                .parse()
+                .map_err(|_| eyre!("Invalid port in host address '{}'", host))?,
+        ),
+        None => (host, port),
+    };
+    (name, port)
+        .to_socket_addrs()
+        .map_err(|e| eyre!("Could not resolve host '{}': {}", host, e))?
+        .next()
+        .ok_or_else(|| eyre!("Could not resolve host '{}'", host))
+}
+
 /// Bind to the given address, returning the listener if successful.
 ///
 /// This eliminates the TOCTOU race of check-then-act: the caller
 /// holds the bound listener and passes it directly to the server.
-pub fn bind_available(port: u16, host: bool) -> Result<TcpListener> {
-    let addr: SocketAddr = if host {
-        (Ipv4Addr::UNSPECIFIED, port).into()
-    } else {
-        (Ipv4Addr::LOCALHOST, port).into()
-    };
-    tracing::debug!(%port, %host, "Binding to port");
+pub fn bind_available(addr: SocketAddr) -> Result<TcpListener> {
+    tracing::debug!(%addr, "Binding to address");
     TcpListener::bind(addr).map_err(|e| {
-        let label = if port == 3030 {
+        let label = if addr.port() == 3030 {
             "default Norgolith port (3030)"
         } else {
-            "requested port"
+            "requested address"
         };
-        eyre!("Could not bind to {} ({}): {}", label, port, e)
+        eyre!("Could not bind to {} ({}): {}", label, addr, e)
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bind_address() {
+        assert_eq!(
+            bind_address(None, 3030).unwrap(),
+            "127.0.0.1:3030".parse().unwrap()
+        );
+        assert_eq!(
+            bind_address(Some("0.0.0.0"), 3030).unwrap(),
+            "0.0.0.0:3030".parse().unwrap()
+        );
+        assert_eq!(
+            bind_address(Some("192.168.1.10:4000"), 3030).unwrap(),
+            "192.168.1.10:4000".parse().unwrap()
+        );
+        assert_eq!(
+            bind_address(Some("::"), 3030).unwrap(),
+            "[::]:3030".parse().unwrap()
+        );
+        assert_eq!(
+            bind_address(Some("[::1]:4000"), 3030).unwrap(),
+            "[::1]:4000".parse().unwrap()
+        );
+        assert!(bind_address(Some("localhost:4000"), 3030)
+            .unwrap()
+            .ip()
+            .is_loopback());
+        assert!(bind_address(Some("localhost:port"), 3030).is_err());
+    }
+}
@@ -1 +1,215 @@
-!function e(t,r,o){function n(s,c){if(!r[s]){if(!t[s]){var a="function"==typeof require&&require;if(!c&&a)return a(s,!0);if(i)return i(s,!0);var u=new Error("Cannot find module '"+s+"'");throw u.code="MODULE_NOT_FOUND",u}var l=r[s]={exports:{}};t[s][0].call(l.exports,(function(e){return n(t[s][1][e]||e)}),l,l.exports,e,t,r,o)}return r[s].exports}for(var i="function"==typeof require&&require,s=0;s<o.length;s++)n(o[s]);return n}({1:[function(e,t,r){t.exports=function(e){if("function"!=typeof e)throw TypeError(e+" is not a function!");return e}},{}],2:[function(e,t,r){var o=e("./_wks")("unscopables"),n=Array.prototype;null==n[o]&&e("./_hide")(n,o,{}),t.exports=function(e){n[o][e]=!0}},{"./_hide":27,"./_wks":81}],3:[function(e,t,r){"use strict";var o=e("./_string-at")(!0);t.exports=function(e,t,r){return t+(r?o(e,t).length:1)}},{"./_string-at":68}],4:[function(e,t,r){var o=e("./_is-object");t.exports=function(e){if(!o(e))throw TypeError(e+" is not an object!");return e}},{"./_is-object":34}],5:[function(e,t,r){var o=e("./_to-iobject"),n=e("./_to-length"),i=e("./_to-absolute-index");t.exports=function(e){return function(t,r,s){var c,a=o(t),u=n(a.length),l=i(s,u);if(e&&r!=r){for(;u>l;)if((c=a[l++])!=c)return!0}else for(;u>l;l++)if((e||l in a)&&a[l]===r)return e||l||0;return!e&&-1}}},{"./_to-absolute-index":72,"./_to-iobject":74,"./_to-length":75}],6:[function(e,t,r){var o=e("./_ctx"),n=e("./_iobject"),i=e("./_to-object"),s=e("./_to-length"),c=e("./_array-species-create");t.exports=function(e,t){var r=1==e,a=2==e,u=3==e,l=4==e,f=6==e,d=5==e||f,p=t||c;return function(t,c,h){for(var _,m,y=i(t),v=n(y),g=o(c,h,3),b=s(v.length),j=0,w=r?p(t,b):a?p(t,0):void 0;b>j;j++)if((d||j in v)&&(m=g(_=v[j],j,y),e))if(r)w[j]=m;else if(m)switch(e){case 3:return!0;case 5:return _;case 6:return j;case 2:w.push(_)}else if(l)return!1;return f?-1:u||l?l:w}}},{"./_array-species-create":8,"./_ctx":13,"./_iobject":31,"./_to-length":75,"./_to-object":76}],7:[function(e,t,r){var o=e("./_is-object"),n=e("./_is-array"),i=e("./_wks")("species");t.exports=function(e){var t;return n(e)&&("function"!=typeof(t=e.constructor)||t!==Array&&!n(t.prototype)||(t=void 0),o(t)&&null===(t=t[i])&&(t=void 0)),void 0===t?Array:t}},{"./_is-array":33,"./_is-object":34,"./_wks":81}],8:[function(e,t,r){var o=e("./_array-species-constructor");t.exports=function(e,t){return new(o(e))(t)}},{"./_array-species-constructor":7}],9:[function(e,t,r){var o=e("./_cof"),n=e("./_wks")("toStringTag"),i="Arguments"==o(function(){return arguments}());t.exports=function(e){var t,r,s;return void 0===e?"Undefined":null===e?"Null":"string"==typeof(r=function(e,t){try{return e[t]}catch(e){}}(t=Object(e),n))?r:i?o(t):"Object"==(s=o(t))&&"function"==typeof t.callee?"Arguments":s}},{"./_cof":10,"./_wks":81}],10:[function(e,t,r){var o={}.toString;t.exports=function(e){return o.call(e).slice(8,-1)}},{}],11:[function(e,t,r){var o=t.exports={version:"2.6.12"};"number"==typeof __e&&(__e=o)},{}],12:[function(e,t,r){"use strict";var o=e("./_object-dp"),n=e("./_property-desc");t.exports=function(e,t,r){t in e?o.f(e,t,n(0,r)):e[t]=r}},{"./_object-dp":45,"./_property-desc":57}],13:[function(e,t,r){var o=e("./_a-function");t.exports=function(e,t,r){if(o(e),void 0===t)return e;switch(r){case 1:return function(r){return e.call(t,r)};case 2:return function(r,o){return e.call(t,r,o)};case 3:return function(r,o,n){return e.call(t,r,o,n)}}return function(){return e.apply(t,arguments)}}},{"./_a-function":1}],14:[function(e,t,r){t.exports=function(e){if(null==e)throw TypeError("Can't call method on  "+e);return e}},{}],15:[function(e,t,r){t.exports=!e("./_fails")((function(){return 7!=Object.defineProperty({},"a",{get:function(){return 7}}).a}))},{"./_fails":21}],16:[function(e,t,r){var o=e("./_is-object"),n=e("./_global").document,i=o(n)&&o(n.createElement);t.exports=function(e){return i?n.createElement(e):{}}},{"./_global":25,"./_is-object":34}],17:[function(e,t,r){t.exports="constructor,hasOwnProperty,isPrototypeOf,propertyIsEnumerable,toLocaleString,toString,valueOf".split(",")},{}],18:[function(e,t,r){var o=e("./_object-keys"),n=e("./_object-gops"),i=e("./_object-pie");t.exports=function(e){var t=o(e),r=n.f;if(r)for(var s,c=r(e),a=i.f,u=0;c.length>u;)a.call(e,s=c[u++])&&t.push(s);return t}},{"./_object-gops":50,"./_object-keys":53,"./_object-pie":54}],19:[function(e,t,r){var o=e("./_global"),n=e("./_core"),i=e("./_hide"),s=e("./_redefine"),c=e("./_ctx"),a="prototype",u=function(e,t,r){var l,f,d,p,h=e&u.F,_=e&u.G,m=e&u.S,y=e&u.P,v=e&u.B,g=_?o:m?o[t]||(o[t]={}):(o[t]||{})[a],b=_?n:n[t]||(n[t]={}),j=b[a]||(b[a]={});for(l in _&&(r=t),r)d=((f=!h&&g&&void 0!==g[l])?g:r)[l],p=v&&f?c(d,o):y&&"function"==typeof d?c(Function.call,d):d,g&&s(g,l,d,e&u.U),b[l]!=d&&i(b,l,p),y&&j[l]!=d&&(j[l]=d)};o.core=n,u.F=1,u.G=2,u.S=4,u.P=8,u.B=16,u.W=32,u.U=64,u.R=128,t.exports=u},{"./_core":11,"./_ctx":13,"./_global":25,"./_hide":27,"./_redefine":58}],20:[function(e,t,r){var o=e("./_wks")("match");t.exports=function(e){var t=/./;try{"/./"[e](t)}catch(r){try{return t[o]=!1,!"/./"[e](t)}catch(e){}}return!0}},{"./_wks":81}],21:[function(e,t,r){t.exports=function(e){try{return!!e()}catch(e){return!0}}},{}],22:[function(e,t,r){"use strict";e("./es6.regexp.exec");var o=e("./_redefine"),n=e("./_hide"),i=e("./_fails"),s=e("./_defined"),c=e("./_wks"),a=e("./_regexp-exec"),u=c("species"),l=!i((function(){var e=/./;return e.exec=function(){var e=[];return e.groups={a:"7"},e},"7"!=="".replace(e,"$<a>")})),f=function(){var e=/(?:)/,t=e.exec;e.exec=function(){return t.apply(this,arguments)};var r="ab".split(e);return 2===r.length&&"a"===r[0]&&"b"===r[1]}();t.exports=function(e,t,r){var d=c(e),p=!i((function(){var t={};return t[d]=function(){return 7},7!=""[e](t)})),h=p?!i((function(){var t=!1,r=/a/;return r.exec=function(){return t=!0,null},"split"===e&&(r.constructor={},r.constructor[u]=function(){return r}),r[d](""),!t})):void 0;if(!p||!h||"replace"===e&&!l||"split"===e&&!f){var _=/./[d],m=r(s,d,""[e],(function(e,t,r,o,n){return t.exec===a?p&&!n?{done:!0,value:_.call(t,r,o)}:{done:!0,value:e.call(r,t,o)}:{done:!1}})),y=m[0],v=m[1];o(String.prototype,e,y),n(RegExp.prototype,d,2==t?function(e,t){return v.call(e,this,t)}:function(e){return v.call(e,this)})}}},{"./_defined":14,"./_fails":21,"./_hide":27,"./_redefine":58,"./_regexp-exec":60,"./_wks":81,"./es6.regexp.exec":93}],23:[function(e,t,r){"use strict";var o=e("./_an-object");t.exports=function(){var e=o(this),t="";return e.global&&(t+="g"),e.ignoreCase&&(t+="i"),e.multiline&&(t+="m"),e.unicode&&(t+="u"),e.sticky&&(t+="y"),t}},{"./_an-object":4}],24:[function(e,t,r){t.exports=e("./_shared")("native-function-to-string",Function.toString)},{"./_shared":65}],25:[function(e,t,r){var o=t.exports="undefined"!=typeof window&&window.Math==Math?window:"undefined"!=typeof self&&self.Math==Math?self:Function("return this")();"number"==typeof __g&&(__g=o)},{}],26:[function(e,t,r){var o={}.hasOwnProperty;t.exports=function(e,t){return o.call(e,t)}},{}],27:[function(e,t,r){var o=e("./_object-dp"),n=e("./_property-desc");t.exports=e("./_descriptors")?function(e,t,r){return o.f(e,t,n(1,r))}:function(e,t,r){return e[t]=r,e}},{"./_descriptors":15,"./_object-dp":45,"./_property-desc":57}],28:[function(e,t,r){var o=e("./_global").document;t.exports=o&&o.documentElement},{"./_global":25}],29:[function(e,t,r){t.exports=!e("./_descriptors")&&!e("./_fails")((function(){return 7!=Object.defineProperty(e("./_dom-create")("div"),"a",{get:function(){return 7}}).a}))},{"./_descriptors":15,"./_dom-create":16,"./_fails":21}],30:[function(e,t,r){var o=e("./_is-object"),n=e("./_set-proto").set;t.exports=function(e,t,r){var i,s=t.constructor;return s!==r&&"function"==typeof s&&(i=s.prototype)!==r.prototype&&o(i)&&n&&n(e,i),e}},{"./_is-object":34,"./_set-proto":61}],31:[function(e,t,r){var o=e("./_cof");t.exports=Object("z").propertyIsEnumerable(0)?Object:function(e){return"String"==o(e)?e.split(""):Object(e)}},{"./_cof":10}],32:[function(e,t,r){var o=e("./_iterators"),n=e("./_wks")("iterator"),i=Array.prototype;t.exports=function(e){return void 0!==e&&(o.Array===e||i[n]===e)}},{"./_iterators":41,"./_wks":81}],33:[function(e,t,r){var o=e("./_cof");t.exports=Array.isArray||function(e){return"Array"==o(e)}},{"./_cof":10}],34:[function(e,t,r){t.exports=function(e){return"object"==typeof e?null!==e:"function"==typeof e}},{}],35:[function(e,t,r){var o=e("./_is-object"),n=e("./_cof"),i=e("./_wks")("match");t.exports=function(e){var t;return o(e)&&(void 0!==(t=e[i])?!!t:"RegExp"==n(e))}},{"./_cof":10,"./_is-object":34,"./_wks":81}],36:[function(e,t,r){var o=e("./_an-object");t.exports=function(e,t,r,n){try{return n?t(o(r)[0],r[1]):t(r)}catch(t){var i=e.return;throw void 0!==i&&o(i.call(e)),t}}},{"./_an-object":4}],37:[function(e,t,r){"use strict";var o=e("./_object-create"),n=e("./_property-desc"),i=e("./_set-to-string-tag"),s={};e("./_hide")(s,e("./_wks")("iterator"),(function(){return this})),t.exports=function(e,t,r){e.prototype=o(s,{next:n(1,r)}),i(e,t+" Iterator")}},{"./_hide":27,"./_object-create":44,"./_property-desc":57,"./_set-to-string-tag":63,"./_wks":81}],38:[function(e,t,r){"use strict";var o=e("./_library"),n=e("./_export"),i=e("./_redefine"),s=e("./_hide"),c=e("./_iterators"),a=e("./_iter-create"),u=e("./_set-to-string-tag"),l=e("./_object-gpo"),f=e("./_wks")("iterator"),d=!([].keys&&"next"in[].keys()),p="keys",h="values",_=function(){return this};t.exports=function(e,t,r,m,y,v,g){a(r,t,m);var b,j,w,x=function(e){if(!d&&e in E)return E[e];switch(e){case p:case h:return function(){return new r(this,e)}}return function(){return new r(this,e)}},S=t+" Iterator",k=y==h,O=!1,E=e.prototype,R=E[f]||E["@@iterator"]||y&&E[y],P=R||x(y),L=y?k?x("entries"):P:void 0,A="Array"==t&&E.entries||R;if(A&&(w=l(A.call(new e)))!==Object.prototype&&w.next&&(u(w,S,!0),o||"function"==typeof w[f]||s(w,f,_)),k&&R&&R.name!==h&&(O=!0,P=function(){return R.call(this)}),o&&!g||!d&&!O&&E[f]||s(E,f,P),c[t]=P,c[S]=_,y)if(b={values:k?P:x(h),keys:v?P:x(p),entries:L},g)for(j in b)j in E||i(E,j,b[j]);else n(n.P+n.F*(d||O),t,b);return b}},{"./_export":19,"./_hide":27,"./_iter-create":37,"./_iterators":41,"./_library":42,"./_object-gpo":51,"./_redefine":58,"./_set-to-string-tag":63,"./_wks":81}],39:[function(e,t,r){var o=e("./_wks")("iterator"),n=!1;try{var i=[7][o]();i.return=function(){n=!0},Array.from(i,(function(){throw 2}))}catch(e){}t.exports=function(e,t){if(!t&&!n)return!1;var r=!1;try{var i=[7],s=i[o]();s.next=function(){return{done:r=!0}},i[o]=function(){return s},e(i)}catch(e){}return r}},{"./_wks":81}],40:[function(e,t,r){t.exports=function(e,t){return{value:t,done:!!e}}},{}],41:[function(e,t,r){t.exports={}},{}],42:[function(e,t,r){t.exports=!1},{}],43:[function(e,t,r){var o=e("./_uid")("meta"),n=e("./_is-object"),i=e("./_has"),s=e("./_object-dp").f,c=0,a=Object.isExtensible||function(){return!0},u=!e("./_fails")((function(){return a(Object.preventExtensions({}))})),l=function(e){s(e,o,{value:{i:"O"+ ++c,w:{}}})},f=t.exports={KEY:o,NEED:!1,fastKey:function(e,t){if(!n(e))return"symbol"==typeof e?e:("string"==typeof e?"S":"P")+e;if(!i(e,o)){if(!a(e))return"F";if(!t)return"E";l(e)}return e[o].i},getWeak:function(e,t){if(!i(e,o)){if(!a(e))return!0;if(!t)return!1;l(e)}return e[o].w},onFreeze:function(e){return u&&f.NEED&&a(e)&&!i(e,o)&&l(e),e}}},{"./_fails":21,"./_has":26,"./_is-object":34,"./_object-dp":45,"./_uid":78}],44:[function(e,t,r){var o=e("./_an-object"),n=e("./_object-dps"),i=e("./_enum-bug-keys"),s=e("./_shared-key")("IE_PROTO"),c=function(){},a="prototype",u=function(){var t,r=e("./_dom-create")("iframe"),o=i.length;for(r.style.display="none",e("./_html").appendChild(r),r.src="javascript:",(t=r.contentWindow.document).open(),t.write("<script>document.F=Object<\/script>"),t.close(),u=t.F;o--;)delete u[a][i[o]];return u()};t.exports=Object.create||function(e,t){var r;return null!==e?(c[a]=o(e),r=new c,c[a]=null,r[s]=e):r=u(),void 0===t?r:n(r,t)}},{"./_an-object":4,"./_dom-create":16,"./_enum-bug-keys":17,"./_html":28,"./_object-dps":46,"./_shared-key":64}],45:[function(e,t,r){var o=e("./_an-object"),n=e("./_ie8-dom-define"),i=e("./_to-primitive"),s=Object.defineProperty;r.f=e("./_descriptors")?Object.defineProperty:function(e,t,r){if(o(e),t=i(t,!0),o(r),n)try{return s(e,t,r)}catch(e){}if("get"in r||"set"in r)throw TypeError("Accessors not supported!");return"value"in r&&(e[t]=r.value),e}},{"./_an-object":4,"./_descriptors":15,"./_ie8-dom-define":29,"./_to-primitive":77}],46:[function(e,t,r){var o=e("./_object-dp"),n=e("./_an-object"),i=e("./_object-keys");t.exports=e("./_descriptors")?Object.defineProperties:function(e,t){n(e);for(var r,s=i(t),c=s.length,a=0;c>a;)o.f(e,r=s[a++],t[r]);return e}},{"./_an-object":4,"./_descriptors":15,"./_object-dp":45,"./_object-keys":53}],47:[function(e,t,r){var o=e("./_object-pie"),n=e("./_property-desc"),i=e("./_to-iobject"),s=e("./_to-primitive"),c=e("./_has"),a=e("./_ie8-dom-define"),u=Object.getOwnPropertyDescriptor;r.f=e("./_descriptors")?u:function(e,t){if(e=i(e),t=s(t,!0),a)try{return u(e,t)}catch(e){}if(c(e,t))return n(!o.f.call(e,t),e[t])}},{"./_descriptors":15,"./_has":26,"./_ie8-dom-define":29,"./_object-pie":54,"./_property-desc":57,"./_to-iobject":74,"./_to-primitive":77}],48:[function(e,t,r){var o=e("./_to-iobject"),n=e("./_object-gopn").f,i={}.toString,s="object"==typeof window&&window&&Object.getOwnPropertyNames?Object.getOwnPropertyNames(window):[];t.exports.f=function(e){return s&&"[object Window]"==i.call(e)?function(e){try{return n(e)}catch(e){return s.slice()}}(e):n(o(e))}},{"./_object-gopn":49,"./_to-iobject":74}],49:[function(e,t,r){var o=e("./_object-keys-internal"),n=e("./_enum-bug-keys").concat("length","prototype");r.f=Object.getOwnPropertyNames||function(e){return o(e,n)}},{"./_enum-bug-keys":17,"./_object-keys-internal":52}],50:[function(e,t,r){r.f=Object.getOwnPropertySymbols},{}],51:[function(e,t,r){var o=e("./_has"),n=e("./_to-object"),i=e("./_shared-key")("IE_PROTO"),s=Object.prototype;t.exports=Object.getPrototypeOf||function(e){return e=n(e),o(e,i)?e[i]:"function"==typeof e.constructor&&e instanceof e.constructor?e.constructor.prototype:e instanceof Object?s:null}},{"./_has":26,"./_shared-key":64,"./_to-object":76}],52:[function(e,t,r){var o=e("./_has"),n=e("./_to-iobject"),i=e("./_array-includes")(!1),s=e("./_shared-key")("IE_PROTO");t.exports=function(e,t){var r,c=n(e),a=0,u=[];for(r in c)r!=s&&o(c,r)&&u.push(r);for(;t.length>a;)o(c,r=t[a++])&&(~i(u,r)||u.push(r));return u}},{"./_array-includes":5,"./_has":26,"./_shared-key":64,"./_to-iobject":74}],53:[function(e,t,r){var o=e("./_object-keys-internal"),n=e("./_enum-bug-keys");t.exports=Object.keys||function(e){return o(e,n)}},{"./_enum-bug-keys":17,"./_object-keys-internal":52}],54:[function(e,t,r){r.f={}.propertyIsEnumerable},{}],55:[function(e,t,r){var o=e("./_export"),n=e("./_core"),i=e("./_fails");t.exports=function(e,t){var r=(n.Object||{})[e]||Object[e],s={};s[e]=t(r),o(o.S+o.F*i((function(){r(1)})),"Object",s)}},{"./_core":11,"./_export":19,"./_fails":21}],56:[function(e,t,r){var o=e("./_object-gopn"),n=e("./_object-gops"),i=e("./_an-object"),s=e("./_global").Reflect;t.exports=s&&s.ownKeys||function(e){var t=o.f(i(e)),r=n.f;return r?t.concat(r(e)):t}},{"./_an-object":4,"./_global":25,"./_object-gopn":49,"./_object-gops":50}],57:[function(e,t,r){t.exports=function(e,t){return{enumerable:!(1&e),configurable:!(2&e),writable:!(4&e),value:t}}},{}],58:[function(e,t,r){var o=e("./_global"),n=e("./_hide"),i=e("./_has"),s=e("./_uid")("src"),c=e("./_function-to-string"),a="toString",u=(""+c).split(a);e("./_core").inspectSource=function(e){return c.call(e)},(t.exports=function(e,t,r,c){var a="function"==typeof r;a&&(i(r,"name")||n(r,"name",t)),e[t]!==r&&(a&&(i(r,s)||n(r,s,e[t]?""+e[t]:u.join(String(t)))),e===o?e[t]=r:c?e[t]?e[t]=r:n(e,t,r):(delete e[t],n(e,t,r)))})(Function.prototype,a,(function(){return"function"==typeof this&&this[s]||c.call(this)}))},{"./_core":11,"./_function-to-string":24,"./_global":25,"./_has":26,"./_hide":27,"./_uid":78}],59:[function(e,t,r){"use strict";var o=e("./_classof"),n=RegExp.prototype.exec;t.exports=function(e,t){var r=e.exec;if("function"==typeof r){var i=r.call(e,t);if("object"!=typeof i)throw new TypeError("RegExp exec method returned something other than an Object or null");return i}if("RegExp"!==o(e))throw new TypeError("RegExp#exec called on incompatible receiver");return n.call(e,t)}},{"./_classof":9}],60:[function(e,t,r){"use strict";var o,n,i=e("./_flags"),s=RegExp.prototype.exec,c=String.prototype.replace,a=s,u="lastIndex",l=(o=/a/,n=/b*/g,s.call(o,"a"),s.call(n,"a"),0!==o[u]||0!==n[u]),f=void 0!==/()??/.exec("")[1];(l||f)&&(a=function(e){var t,r,o,n,a=this;return f&&(r=new RegExp("^"+a.source+"$(?!\\s)",i.call(a))),l&&(t=a[u]),o=s.call(a,e),l&&o&&(a[u]=a.global?o.index+o[0].length:t),f&&o&&o.length>1&&c.call(o[0],r,(function(){for(n=1;n<arguments.length-2;n++)void 0===arguments[n]&&(o[n]=void 0)})),o}),t.exports=a},{"./_flags":23}],61:[function(e,t,r){var o=e("./_is-object"),n=e("./_an-object"),i=function(e,t){if(n(e),!o(t)&&null!==t)throw TypeError(t+": can't set as prototype!")};t.exports={set:Object.setPrototypeOf||("__proto__"in{}?function(t,r,o){try{(o=e("./_ctx")(Function.call,e("./_object-gopd").f(Object.prototype,"__proto__").set,2))(t,[]),r=!(t instanceof Array)}catch(e){r=!0}return function(e,t){return i(e,t),r?e.__proto__=t:o(e,t),e}}({},!1):void 0),check:i}},{"./_an-object":4,"./_ctx":13,"./_is-object":34,"./_object-gopd":47}],62:[function(e,t,r){"use strict";var o=e("./_global"),n=e("./_object-dp"),i=e("./_descriptors"),s=e("./_wks")("species");t.exports=function(e){var t=o[e];i&&t&&!t[s]&&n.f(t,s,{configurable:!0,get:function(){return this}})}},{"./_descriptors":15,"./_global":25,"./_object-dp":45,"./_wks":81}],63:[function(e,t,r){var o=e("./_object-dp").f,n=e("./_has"),i=e("./_wks")("toStringTag");t.exports=function(e,t,r){e&&!n(e=r?e:e.prototype,i)&&o(e,i,{configurable:!0,value:t})}},{"./_has":26,"./_object-dp":45,"./_wks":81}],64:[function(e,t,r){var o=e("./_shared")("keys"),n=e("./_uid");t.exports=function(e){return o[e]||(o[e]=n(e))}},{"./_shared":65,"./_uid":78}],65:[function(e,t,r){var o=e("./_core"),n=e("./_global"),i="__core-js_shared__",s=n[i]||(n[i]={});(t.exports=function(e,t){return s[e]||(s[e]=void 0!==t?t:{})})("versions",[]).push({version:o.version,mode:e("./_library")?"pure":"global",copyright:"© 2020 Denis Pushkarev (zloirock.ru)"})},{"./_core":11,"./_global":25,"./_library":42}],66:[function(e,t,r){var o=e("./_an-object"),n=e("./_a-function"),i=e("./_wks")("species");t.exports=function(e,t){var r,s=o(e).constructor;return void 0===s||null==(r=o(s)[i])?t:n(r)}},{"./_a-function":1,"./_an-object":4,"./_wks":81}],67:[function(e,t,r){"use strict";var o=e("./_fails");t.exports=function(e,t){return!!e&&o((function(){t?e.call(null,(function(){}),1):e.call(null)}))}},{"./_fails":21}],68:[function(e,t,r){var o=e("./_to-integer"),n=e("./_defined");t.exports=function(e){return function(t,r){var i,s,c=String(n(t)),a=o(r),u=c.length;return a<0||a>=u?e?"":void 0:(i=c.charCodeAt(a))<55296||i>56319||a+1===u||(s=c.charCodeAt(a+1))<56320||s>57343?e?c.charAt(a):i:e?c.slice(a,a+2):s-56320+(i-55296<<10)+65536}}},{"./_defined":14,"./_to-integer":73}],69:[function(e,t,r){var o=e("./_is-regexp"),n=e("./_defined");t.exports=function(e,t,r){if(o(t))throw TypeError("String#"+r+" doesn't accept regex!");return String(n(e))}},{"./_defined":14,"./_is-regexp":35}],70:[function(e,t,r){var o=e("./_export"),n=e("./_defined"),i=e("./_fails"),s=e("./_string-ws"),c="["+s+"]",a=RegExp("^"+c+c+"*"),u=RegExp(c+c+"*$"),l=function(e,t,r){var n={},c=i((function(){return!!s[e]()||"​"!="​"[e]()})),a=n[e]=c?t(f):s[e];r&&(n[r]=a),o(o.P+o.F*c,"String",n)},f=l.trim=function(e,t){return e=String(n(e)),1&t&&(e=e.replace(a,"")),2&t&&(e=e.replace(u,"")),e};t.exports=l},{"./_defined":14,"./_export":19,"./_fails":21,"./_string-ws":71}],71:[function(e,t,r){t.exports="\t\n\v\f\r   ᠎             　\u2028\u2029\ufeff"},{}],72:[function(e,t,r){var o=e("./_to-integer"),n=Math.max,i=Math.min;t.exports=function(e,t){return(e=o(e))<0?n(e+t,0):i(e,t)}},{"./_to-integer":73}],73:[function(e,t,r){var o=Math.ceil,n=Math.floor;t.exports=function(e){return isNaN(e=+e)?0:(e>0?n:o)(e)}},{}],74:[function(e,t,r){var o=e("./_iobject"),n=e("./_defined");t.exports=function(e){return o(n(e))}},{"./_defined":14,"./_iobject":31}],75:[function(e,t,r){var o=e("./_to-integer"),n=Math.min;t.exports=function(e){return e>0?n(o(e),9007199254740991):0}},{"./_to-integer":73}],76:[function(e,t,r){var o=e("./_defined");t.exports=function(e){return Object(o(e))}},{"./_defined":14}],77:[function(e,t,r){var o=e("./_is-object");t.exports=function(e,t){if(!o(e))return e;var r,n;if(t&&"function"==typeof(r=e.toString)&&!o(n=r.call(e)))return n;if("function"==typeof(r=e.valueOf)&&!o(n=r.call(e)))return n;if(!t&&"function"==typeof(r=e.toString)&&!o(n=r.call(e)))return n;throw TypeError("Can't convert object to primitive value")}},{"./_is-object":34}],78:[function(e,t,r){var o=0,n=Math.random();t.exports=function(e){return"Symbol(".concat(void 0===e?"":e,")_",(++o+n).toString(36))}},{}],79:[function(e,t,r){var o=e("./_global"),n=e("./_core"),i=e("./_library"),s=e("./_wks-ext"),c=e("./_object-dp").f;t.exports=function(e){var t=n.Symbol||(n.Symbol=i?{}:o.Symbol||{});"_"==e.charAt(0)||e in t||c(t,e,{value:s.f(e)})}},{"./_core":11,"./_global":25,"./_library":42,"./_object-dp":45,"./_wks-ext":80}],80:[function(e,t,r){r.f=e("./_wks")},{"./_wks":81}],81:[function(e,t,r){var o=e("./_shared")("wks"),n=e("./_uid"),i=e("./_global").Symbol,s="function"==typeof i;(t.exports=function(e){return o[e]||(o[e]=s&&i[e]||(s?i:n)("Symbol."+e))}).store=o},{"./_global":25,"./_shared":65,"./_uid":78}],82:[function(e,t,r){var o=e("./_classof"),n=e("./_wks")("iterator"),i=e("./_iterators");t.exports=e("./_core").getIteratorMethod=function(e){if(null!=e)return e[n]||e["@@iterator"]||i[o(e)]}},{"./_classof":9,"./_core":11,"./_iterators":41,"./_wks":81}],83:[function(e,t,r){"use strict";var o=e("./_export"),n=e("./_array-methods")(2);o(o.P+o.F*!e("./_strict-method")([].filter,!0),"Array",{filter:function(e){return n(this,e,arguments[1])}})},{"./_array-methods":6,"./_export":19,"./_strict-method":67}],84:[function(e,t,r){"use strict";var o=e("./_ctx"),n=e("./_export"),i=e("./_to-object"),s=e("./_iter-call"),c=e("./_is-array-iter"),a=e("./_to-length"),u=e("./_create-property"),l=e("./core.get-iterator-method");n(n.S+n.F*!e("./_iter-detect")((function(e){Array.from(e)})),"Array",{from:function(e){var t,r,n,f,d=i(e),p="function"==typeof this?this:Array,h=arguments.length,_=h>1?arguments[1]:void 0,m=void 0!==_,y=0,v=l(d);if(m&&(_=o(_,h>2?arguments[2]:void 0,2)),null==v||p==Array&&c(v))for(r=new p(t=a(d.length));t>y;y++)u(r,y,m?_(d[y],y):d[y]);else for(f=v.call(d),r=new p;!(n=f.next()).done;y++)u(r,y,m?s(f,_,[n.value,y],!0):n.value);return r.length=y,r}})},{"./_create-property":12,"./_ctx":13,"./_export":19,"./_is-array-iter":32,"./_iter-call":36,"./_iter-detect":39,"./_to-length":75,"./_to-object":76,"./core.get-iterator-method":82}],85:[function(e,t,r){"use strict";var o=e("./_add-to-unscopables"),n=e("./_iter-step"),i=e("./_iterators"),s=e("./_to-iobject");t.exports=e("./_iter-define")(Array,"Array",(function(e,t){this._t=s(e),this._i=0,this._k=t}),(function(){var e=this._t,t=this._k,r=this._i++;return!e||r>=e.length?(this._t=void 0,n(1)):n(0,"keys"==t?r:"values"==t?e[r]:[r,e[r]])}),"values"),i.Arguments=i.Array,o("keys"),o("values"),o("entries")},{"./_add-to-unscopables":2,"./_iter-define":38,"./_iter-step":40,"./_iterators":41,"./_to-iobject":74}],86:[function(e,t,r){"use strict";var o=e("./_export"),n=e("./_array-methods")(1);o(o.P+o.F*!e("./_strict-method")([].map,!0),"Array",{map:function(e){return n(this,e,arguments[1])}})},{"./_array-methods":6,"./_export":19,"./_strict-method":67}],87:[function(e,t,r){"use strict";var o=e("./_export"),n=e("./_html"),i=e("./_cof"),s=e("./_to-absolute-index"),c=e("./_to-length"),a=[].slice;o(o.P+o.F*e("./_fails")((function(){n&&a.call(n)})),"Array",{slice:function(e,t){var r=c(this.length),o=i(this);if(t=void 0===t?r:t,"Array"==o)return a.call(this,e,t);for(var n=s(e,r),u=s(t,r),l=c(u-n),f=new Array(l),d=0;d<l;d++)f[d]="String"==o?this.charAt(n+d):this[n+d];return f}})},{"./_cof":10,"./_export":19,"./_fails":21,"./_html":28,"./_to-absolute-index":72,"./_to-length":75}],88:[function(e,t,r){"use strict";var o=e("./_global"),n=e("./_has"),i=e("./_cof"),s=e("./_inherit-if-required"),c=e("./_to-primitive"),a=e("./_fails"),u=e("./_object-gopn").f,l=e("./_object-gopd").f,f=e("./_object-dp").f,d=e("./_string-trim").trim,p="Number",h=o[p],_=h,m=h.prototype,y=i(e("./_object-create")(m))==p,v="trim"in String.prototype,g=function(e){var t=c(e,!1);if("string"==typeof t&&t.length>2){var r,o,n,i=(t=v?t.trim():d(t,3)).charCodeAt(0);if(43===i||45===i){if(88===(r=t.charCodeAt(2))||120===r)return NaN}else if(48===i){switch(t.charCodeAt(1)){case 66:case 98:o=2,n=49;break;case 79:case 111:o=8,n=55;break;default:return+t}for(var s,a=t.slice(2),u=0,l=a.length;u<l;u++)if((s=a.charCodeAt(u))<48||s>n)return NaN;return parseInt(a,o)}}return+t};if(!h(" 0o1")||!h("0b1")||h("+0x1")){h=function(e){var t=arguments.length<1?0:e,r=this;return r instanceof h&&(y?a((function(){m.valueOf.call(r)})):i(r)!=p)?s(new _(g(t)),r,h):g(t)};for(var b,j=e("./_descriptors")?u(_):"MAX_VALUE,MIN_VALUE,NaN,NEGATIVE_INFINITY,POSITIVE_INFINITY,EPSILON,isFinite,isInteger,isNaN,isSafeInteger,MAX_SAFE_INTEGER,MIN_SAFE_INTEGER,parseFloat,parseInt,isInteger".split(","),w=0;j.length>w;w++)n(_,b=j[w])&&!n(h,b)&&f(h,b,l(_,b));h.prototype=m,m.constructor=h,e("./_redefine")(o,p,h)}},{"./_cof":10,"./_descriptors":15,"./_fails":21,"./_global":25,"./_has":26,"./_inherit-if-required":30,"./_object-create":44,"./_object-dp":45,"./_object-gopd":47,"./_object-gopn":49,"./_redefine":58,"./_string-trim":70,"./_to-primitive":77}],89:[function(e,t,r){var o=e("./_to-iobject"),n=e("./_object-gopd").f;e("./_object-sap")("getOwnPropertyDescriptor",(function(){return function(e,t){return n(o(e),t)}}))},{"./_object-gopd":47,"./_object-sap":55,"./_to-iobject":74}],90:[function(e,t,r){var o=e("./_to-object"),n=e("./_object-keys");e("./_object-sap")("keys",(function(){return function(e){return n(o(e))}}))},{"./_object-keys":53,"./_object-sap":55,"./_to-object":76}],91:[function(e,t,r){"use strict";var o=e("./_classof"),n={};n[e("./_wks")("toStringTag")]="z",n+""!="[object z]"&&e("./_redefine")(Object.prototype,"toString",(function(){return"[object "+o(this)+"]"}),!0)},{"./_classof":9,"./_redefine":58,"./_wks":81}],92:[function(e,t,r){var o=e("./_global"),n=e("./_inherit-if-required"),i=e("./_object-dp").f,s=e("./_object-gopn").f,c=e("./_is-regexp"),a=e("./_flags"),u=o.RegExp,l=u,f=u.prototype,d=/a/g,p=/a/g,h=new u(d)!==d;if(e("./_descriptors")&&(!h||e("./_fails")((function(){return p[e("./_wks")("match")]=!1,u(d)!=d||u(p)==p||"/a/i"!=u(d,"i")})))){u=function(e,t){var r=this instanceof u,o=c(e),i=void 0===t;return!r&&o&&e.constructor===u&&i?e:n(h?new l(o&&!i?e.source:e,t):l((o=e instanceof u)?e.source:e,o&&i?a.call(e):t),r?this:f,u)};for(var _=function(e){e in u||i(u,e,{configurable:!0,get:function(){return l[e]},set:function(t){l[e]=t}})},m=s(l),y=0;m.length>y;)_(m[y++]);f.constructor=u,u.prototype=f,e("./_redefine")(o,"RegExp",u)}e("./_set-species")("RegExp")},{"./_descriptors":15,"./_fails":21,"./_flags":23,"./_global":25,"./_inherit-if-required":30,"./_is-regexp":35,"./_object-dp":45,"./_object-gopn":49,"./_redefine":58,"./_set-species":62,"./_wks":81}],93:[function(e,t,r){"use strict";var o=e("./_regexp-exec");e("./_export")({target:"RegExp",proto:!0,forced:o!==/./.exec},{exec:o})},{"./_export":19,"./_regexp-exec":60}],94:[function(e,t,r){"use strict";var o=e("./_an-object"),n=e("./_to-length"),i=e("./_advance-string-index"),s=e("./_regexp-exec-abstract");e("./_fix-re-wks")("match",1,(function(e,t,r,c){return[function(r){var o=e(this),n=null==r?void 0:r[t];return void 0!==n?n.call(r,o):new RegExp(r)[t](String(o))},function(e){var t=c(r,e,this);if(t.done)return t.value;var a=o(e),u=String(this);if(!a.global)return s(a,u);var l=a.unicode;a.lastIndex=0;for(var f,d=[],p=0;null!==(f=s(a,u));){var h=String(f[0]);d[p]=h,""===h&&(a.lastIndex=i(u,n(a.lastIndex),l)),p++}return 0===p?null:d}]}))},{"./_advance-string-index":3,"./_an-object":4,"./_fix-re-wks":22,"./_regexp-exec-abstract":59,"./_to-length":75}],95:[function(e,t,r){"use strict";var o=e("./_an-object"),n=e("./_to-object"),i=e("./_to-length"),s=e("./_to-integer"),c=e("./_advance-string-index"),a=e("./_regexp-exec-abstract"),u=Math.max,l=Math.min,f=Math.floor,d=/\$([$&`']|\d\d?|<[^>]*>)/g,p=/\$([$&`']|\d\d?)/g;e("./_fix-re-wks")("replace",2,(function(e,t,r,h){return[function(o,n){var i=e(this),s=null==o?void 0:o[t];return void 0!==s?s.call(o,i,n):r.call(String(i),o,n)},function(e,t){var n=h(r,e,this,t);if(n.done)return n.value;var f=o(e),d=String(this),p="function"==typeof t;p||(t=String(t));var m=f.global;if(m){var y=f.unicode;f.lastIndex=0}for(var v=[];;){var g=a(f,d);if(null===g)break;if(v.push(g),!m)break;""===String(g[0])&&(f.lastIndex=c(d,i(f.lastIndex),y))}for(var b,j="",w=0,x=0;x<v.length;x++){g=v[x];for(var S=String(g[0]),k=u(l(s(g.index),d.length),0),O=[],E=1;E<g.length;E++)O.push(void 0===(b=g[E])?b:String(b));var R=g.groups;if(p){var P=[S].concat(O,k,d);void 0!==R&&P.push(R);var L=String(t.apply(void 0,P))}else L=_(S,d,k,O,R,t);k>=w&&(j+=d.slice(w,k)+L,w=k+S.length)}return j+d.slice(w)}];function _(e,t,o,i,s,c){var a=o+e.length,u=i.length,l=p;return void 0!==s&&(s=n(s),l=d),r.call(c,l,(function(r,n){var c;switch(n.charAt(0)){case"$":return"$";case"&":return e;case"`":return t.slice(0,o);case"'":return t.slice(a);case"<":c=s[n.slice(1,-1)];break;default:var l=+n;if(0===l)return r;if(l>u){var d=f(l/10);return 0===d?r:d<=u?void 0===i[d-1]?n.charAt(1):i[d-1]+n.charAt(1):r}c=i[l-1]}return void 0===c?"":c}))}}))},{"./_advance-string-index":3,"./_an-object":4,"./_fix-re-wks":22,"./_regexp-exec-abstract":59,"./_to-integer":73,"./_to-length":75,"./_to-object":76}],96:[function(e,t,r){"use strict";var o=e("./_is-regexp"),n=e("./_an-object"),i=e("./_species-constructor"),s=e("./_advance-string-index"),c=e("./_to-length"),a=e("./_regexp-exec-abstract"),u=e("./_regexp-exec"),l=e("./_fails"),f=Math.min,d=[].push,p="split",h="length",_="lastIndex",m=4294967295,y=!l((function(){RegExp(m,"y")}));e("./_fix-re-wks")("split",2,(function(e,t,r,l){var v;return v="c"=="abbc"[p](/(b)*/)[1]||4!="test"[p](/(?:)/,-1)[h]||2!="ab"[p](/(?:ab)*/)[h]||4!="."[p](/(.?)(.?)/)[h]||"."[p](/()()/)[h]>1||""[p](/.?/)[h]?function(e,t){var n=String(this);if(void 0===e&&0===t)return[];if(!o(e))return r.call(n,e,t);for(var i,s,c,a=[],l=(e.ignoreCase?"i":"")+(e.multiline?"m":"")+(e.unicode?"u":"")+(e.sticky?"y":""),f=0,p=void 0===t?m:t>>>0,y=new RegExp(e.source,l+"g");(i=u.call(y,n))&&!((s=y[_])>f&&(a.push(n.slice(f,i.index)),i[h]>1&&i.index<n[h]&&d.apply(a,i.slice(1)),c=i[0][h],f=s,a[h]>=p));)y[_]===i.index&&y[_]++;return f===n[h]?!c&&y.test("")||a.push(""):a.push(n.slice(f)),a[h]>p?a.slice(0,p):a}:"0"[p](void 0,0)[h]?function(e,t){return void 0===e&&0===t?[]:r.call(this,e,t)}:r,[function(r,o){var n=e(this),i=null==r?void 0:r[t];return void 0!==i?i.call(r,n,o):v.call(String(n),r,o)},function(e,t){var o=l(v,e,this,t,v!==r);if(o.done)return o.value;var u=n(e),d=String(this),p=i(u,RegExp),h=u.unicode,_=(u.ignoreCase?"i":"")+(u.multiline?"m":"")+(u.unicode?"u":"")+(y?"y":"g"),g=new p(y?u:"^(?:"+u.source+")",_),b=void 0===t?m:t>>>0;if(0===b)return[];if(0===d.length)return null===a(g,d)?[d]:[];for(var j=0,w=0,x=[];w<d.length;){g.lastIndex=y?w:0;var S,k=a(g,y?d:d.slice(w));if(null===k||(S=f(c(g.lastIndex+(y?0:w)),d.length))===j)w=s(d,w,h);else{if(x.push(d.slice(j,w)),x.length===b)return x;for(var O=1;O<=k.length-1;O++)if(x.push(k[O]),x.length===b)return x;w=j=S}}return x.push(d.slice(j)),x}]}))},{"./_advance-string-index":3,"./_an-object":4,"./_fails":21,"./_fix-re-wks":22,"./_is-regexp":35,"./_regexp-exec":60,"./_regexp-exec-abstract":59,"./_species-constructor":66,"./_to-length":75}],97:[function(e,t,r){"use strict";var o=e("./_export"),n=e("./_string-context"),i="includes";o(o.P+o.F*e("./_fails-is-regexp")(i),"String",{includes:function(e){return!!~n(this,e,i).indexOf(e,arguments.length>1?arguments[1]:void 0)}})},{"./_export":19,"./_fails-is-regexp":20,"./_string-context":69}],98:[function(e,t,r){"use strict";var o=e("./_string-at")(!0);e("./_iter-define")(String,"String",(function(e){this._t=String(e),this._i=0}),(function(){var e,t=this._t,r=this._i;return r>=t.length?{value:void 0,done:!0}:(e=o(t,r),this._i+=e.length,{value:e,done:!1})}))},{"./_iter-define":38,"./_string-at":68}],99:[function(e,t,r){"use strict";var o=e("./_global"),n=e("./_has"),i=e("./_descriptors"),s=e("./_export"),c=e("./_redefine"),a=e("./_meta").KEY,u=e("./_fails"),l=e("./_shared"),f=e("./_set-to-string-tag"),d=e("./_uid"),p=e("./_wks"),h=e("./_wks-ext"),_=e("./_wks-define"),m=e("./_enum-keys"),y=e("./_is-array"),v=e("./_an-object"),g=e("./_is-object"),b=e("./_to-object"),j=e("./_to-iobject"),w=e("./_to-primitive"),x=e("./_property-desc"),S=e("./_object-create"),k=e("./_object-gopn-ext"),O=e("./_object-gopd"),E=e("./_object-gops"),R=e("./_object-dp"),P=e("./_object-keys"),L=O.f,A=R.f,T=k.f,C=o.Symbol,I=o.JSON,N=I&&I.stringify,M="prototype",U=p("_hidden"),F=p("toPrimitive"),D={}.propertyIsEnumerable,$=l("symbol-registry"),B=l("symbols"),z=l("op-symbols"),q=Object[M],W="function"==typeof C&&!!E.f,H=o.QObject,G=!H||!H[M]||!H[M].findChild,V=i&&u((function(){return 7!=S(A({},"a",{get:function(){return A(this,"a",{value:7}).a}})).a}))?function(e,t,r){var o=L(q,t);o&&delete q[t],A(e,t,r),o&&e!==q&&A(q,t,o)}:A,J=function(e){var t=B[e]=S(C[M]);return t._k=e,t},K=W&&"symbol"==typeof C.iterator?function(e){return"symbol"==typeof e}:function(e){return e instanceof C},Y=function(e,t,r){return e===q&&Y(z,t,r),v(e),t=w(t,!0),v(r),n(B,t)?(r.enumerable?(n(e,U)&&e[U][t]&&(e[U][t]=!1),r=S(r,{enumerable:x(0,!1)})):(n(e,U)||A(e,U,x(1,{})),e[U][t]=!0),V(e,t,r)):A(e,t,r)},X=function(e,t){v(e);for(var r,o=m(t=j(t)),n=0,i=o.length;i>n;)Y(e,r=o[n++],t[r]);return e},Q=function(e){var t=D.call(this,e=w(e,!0));return!(this===q&&n(B,e)&&!n(z,e))&&(!(t||!n(this,e)||!n(B,e)||n(this,U)&&this[U][e])||t)},Z=function(e,t){if(e=j(e),t=w(t,!0),e!==q||!n(B,t)||n(z,t)){var r=L(e,t);return!r||!n(B,t)||n(e,U)&&e[U][t]||(r.enumerable=!0),r}},ee=function(e){for(var t,r=T(j(e)),o=[],i=0;r.length>i;)n(B,t=r[i++])||t==U||t==a||o.push(t);return o},te=function(e){for(var t,r=e===q,o=T(r?z:j(e)),i=[],s=0;o.length>s;)!n(B,t=o[s++])||r&&!n(q,t)||i.push(B[t]);return i};W||(C=function(){if(this instanceof C)throw TypeError("Symbol is not a constructor!");var e=d(arguments.length>0?arguments[0]:void 0),t=function(r){this===q&&t.call(z,r),n(this,U)&&n(this[U],e)&&(this[U][e]=!1),V(this,e,x(1,r))};return i&&G&&V(q,e,{configurable:!0,set:t}),J(e)},c(C[M],"toString",(function(){return this._k})),O.f=Z,R.f=Y,e("./_object-gopn").f=k.f=ee,e("./_object-pie").f=Q,E.f=te,i&&!e("./_library")&&c(q,"propertyIsEnumerable",Q,!0),h.f=function(e){return J(p(e))}),s(s.G+s.W+s.F*!W,{Symbol:C});for(var re="hasInstance,isConcatSpreadable,iterator,match,replace,search,species,split,toPrimitive,toStringTag,unscopables".split(","),oe=0;re.length>oe;)p(re[oe++]);for(var ne=P(p.store),ie=0;ne.length>ie;)_(ne[ie++]);s(s.S+s.F*!W,"Symbol",{for:function(e){return n($,e+="")?$[e]:$[e]=C(e)},keyFor:function(e){if(!K(e))throw TypeError(e+" is not a symbol!");for(var t in $)if($[t]===e)return t},useSetter:function(){G=!0},useSimple:function(){G=!1}}),s(s.S+s.F*!W,"Object",{create:function(e,t){return void 0===t?S(e):X(S(e),t)},defineProperty:Y,defineProperties:X,getOwnPropertyDescriptor:Z,getOwnPropertyNames:ee,getOwnPropertySymbols:te});var se=u((function(){E.f(1)}));s(s.S+s.F*se,"Object",{getOwnPropertySymbols:function(e){return E.f(b(e))}}),I&&s(s.S+s.F*(!W||u((function(){var e=C();return"[null]"!=N([e])||"{}"!=N({a:e})||"{}"!=N(Object(e))}))),"JSON",{stringify:function(e){for(var t,r,o=[e],n=1;arguments.length>n;)o.push(arguments[n++]);if(r=t=o[1],(g(t)||void 0!==e)&&!K(e))return y(t)||(t=function(e,t){if("function"==typeof r&&(t=r.call(this,e,t)),!K(t))return t}),o[1]=t,N.apply(I,o)}}),C[M][F]||e("./_hide")(C[M],F,C[M].valueOf),f(C,"Symbol"),f(Math,"Math",!0),f(o.JSON,"JSON",!0)},{"./_an-object":4,"./_descriptors":15,"./_enum-keys":18,"./_export":19,"./_fails":21,"./_global":25,"./_has":26,"./_hide":27,"./_is-array":33,"./_is-object":34,"./_library":42,"./_meta":43,"./_object-create":44,"./_object-dp":45,"./_object-gopd":47,"./_object-gopn":49,"./_object-gopn-ext":48,"./_object-gops":50,"./_object-keys":53,"./_object-pie":54,"./_property-desc":57,"./_redefine":58,"./_set-to-string-tag":63,"./_shared":65,"./_to-iobject":74,"./_to-object":76,"./_to-primitive":77,"./_uid":78,"./_wks":81,"./_wks-define":79,"./_wks-ext":80}],100:[function(e,t,r){"use strict";var o=e("./_export"),n=e("./_array-includes")(!0);o(o.P,"Array",{includes:function(e){return n(this,e,arguments.length>1?arguments[1]:void 0)}}),e("./_add-to-unscopables")("includes")},{"./_add-to-unscopables":2,"./_array-includes":5,"./_export":19}],101:[function(e,t,r){var o=e("./_export"),n=e("./_own-keys"),i=e("./_to-iobject"),s=e("./_object-gopd"),c=e("./_create-property");o(o.S,"Object",{getOwnPropertyDescriptors:function(e){for(var t,r,o=i(e),a=s.f,u=n(o),l={},f=0;u.length>f;)void 0!==(r=a(o,t=u[f++]))&&c(l,t,r);return l}})},{"./_create-property":12,"./_export":19,"./_object-gopd":47,"./_own-keys":56,"./_to-iobject":74}],102:[function(e,t,r){for(var o=e("./es6.array.iterator"),n=e("./_object-keys"),i=e("./_redefine"),s=e("./_global"),c=e("./_hide"),a=e("./_iterators"),u=e("./_wks"),l=u("iterator"),f=u("toStringTag"),d=a.Array,p={CSSRuleList:!0,CSSStyleDeclaration:!1,CSSValueList:!1,ClientRectList:!1,DOMRectList:!1,DOMStringList:!1,DOMTokenList:!0,DataTransferItemList:!1,FileList:!1,HTMLAllCollection:!1,HTMLCollection:!1,HTMLFormElement:!1,HTMLSelectElement:!1,MediaList:!0,MimeTypeArray:!1,NamedNodeMap:!1,NodeList:!0,PaintRequestList:!1,Plugin:!1,PluginArray:!1,SVGLengthList:!1,SVGNumberList:!1,SVGPathSegList:!1,SVGPointList:!1,SVGStringList:!1,SVGTransformList:!1,SourceBufferList:!1,StyleSheetList:!0,TextTrackCueList:!1,TextTrackList:!1,TouchList:!1},h=n(p),_=0;_<h.length;_++){var m,y=h[_],v=p[y],g=s[y],b=g&&g.prototype;if(b&&(b[l]||c(b,l,d),b[f]||c(b,f,y),a[y]=d,v))for(m in o)b[m]||i(b,m,o[m],!0)}},{"./_global":25,"./_hide":27,"./_iterators":41,"./_object-keys":53,"./_redefine":58,"./_wks":81,"./es6.array.iterator":85}],103:[function(e,t,r){"use strict";function o(e){return o="function"==typeof Symbol&&"symbol"==typeof Symbol.iterator?function(e){return typeof e}:function(e){return e&&"function"==typeof Symbol&&e.constructor===Symbol&&e!==Symbol.prototype?"symbol":typeof e},o(e)}function n(e,t){for(var r=0;r<t.length;r++){var n=t[r];n.enumerable=n.enumerable||!1,n.configurable=!0,"value"in n&&(n.writable=!0),Object.defineProperty(e,(i=n.key,s=void 0,s=function(e,t){if("object"!==o(e)||null===e)return e;var r=e[Symbol.toPrimitive];if(void 0!==r){var n=r.call(e,t||"default");if("object"!==o(n))return n;throw new TypeError("@@toPrimitive must return a primitive value.")}return("string"===t?String:Number)(e)}(i,"string"),"symbol"===o(s)?s:String(s)),n)}var i,s}e("core-js/modules/es6.symbol.js"),e("core-js/modules/es6.number.constructor.js"),e("core-js/modules/es6.string.iterator.js"),e("core-js/modules/es6.object.to-string.js"),e("core-js/modules/es6.array.iterator.js"),e("core-js/modules/web.dom.iterable.js");var i=e("./protocol"),s=i.Parser,c=i.PROTOCOL_6,a=i.PROTOCOL_7,u=function(){function e(t,r,o,n){var i=this;!function(e,t){if(!(e instanceof t))throw new TypeError("Cannot call a class as a function")}(this,e),this.options=t,this.WebSocket=r,this.Timer=o,this.handlers=n;var c=this.options.path?"".concat(this.options.path):"livereload",a=this.options.port?":".concat(this.options.port):"";this._uri="ws".concat(this.options.https?"s":"","://").concat(this.options.host).concat(a,"/").concat(c),this._nextDelay=this.options.mindelay,this._connectionDesired=!1,this.protocol=0,this.protocolParser=new s({connected:function(e){return i.protocol=e,i._handshakeTimeout.stop(),i._nextDelay=i.options.mindelay,i._disconnectionReason="broken",i.handlers.connected(i.protocol)},error:function(e){return i.handlers.error(e),i._closeOnError()},message:function(e){return i.handlers.message(e)}}),this._handshakeTimeout=new this.Timer((function(){if(i._isSocketConnected())return i._disconnectionReason="handshake-timeout",i.socket.close()})),this._reconnectTimer=new this.Timer((function(){if(i._connectionDesired)return i.connect()})),this.connect()}var t,r,o;return t=e,(r=[{key:"_isSocketConnected",value:function(){return this.socket&&this.socket.readyState===this.WebSocket.OPEN}},{key:"connect",value:function(){var e=this;this._connectionDesired=!0,this._isSocketConnected()||(this._reconnectTimer.stop(),this._disconnectionReason="cannot-connect",this.protocolParser.reset(),this.handlers.connecting(),this.socket=new this.WebSocket(this._uri),this.socket.onopen=function(t){return e._onopen(t)},this.socket.onclose=function(t){return e._onclose(t)},this.socket.onmessage=function(t){return e._onmessage(t)},this.socket.onerror=function(t){return e._onerror(t)})}},{key:"disconnect",value:function(){if(this._connectionDesired=!1,this._reconnectTimer.stop(),this._isSocketConnected())return this._disconnectionReason="manual",this.socket.close()}},{key:"_scheduleReconnection",value:function(){this._connectionDesired&&(this._reconnectTimer.running||(this._reconnectTimer.start(this._nextDelay),this._nextDelay=Math.min(this.options.maxdelay,2*this._nextDelay)))}},{key:"sendCommand",value:function(e){if(this.protocol)return this._sendCommand(e)}},{key:"_sendCommand",value:function(e){return this.socket.send(JSON.stringify(e))}},{key:"_closeOnError",value:function(){return this._handshakeTimeout.stop(),this._disconnectionReason="error",this.socket.close()}},{key:"_onopen",value:function(e){this.handlers.socketConnected(),this._disconnectionReason="handshake-failed";var t={command:"hello",protocols:[c,a],ver:"4.0.2"};return this.options.ext&&(t.ext=this.options.ext),this.options.extver&&(t.extver=this.options.extver),this.options.snipver&&(t.snipver=this.options.snipver),this._sendCommand(t),this._handshakeTimeout.start(this.options.handshake_timeout)}},{key:"_onclose",value:function(e){return this.protocol=0,this.handlers.disconnected(this._disconnectionReason,this._nextDelay),this._scheduleReconnection()}},{key:"_onerror",value:function(e){}},{key:"_onmessage",value:function(e){return this.protocolParser.process(e.data)}}])&&n(t.prototype,r),o&&n(t,o),Object.defineProperty(t,"prototype",{writable:!1}),e}();r.Connector=u},{"./protocol":108,"core-js/modules/es6.array.iterator.js":85,"core-js/modules/es6.number.constructor.js":88,"core-js/modules/es6.object.to-string.js":91,"core-js/modules/es6.string.iterator.js":98,"core-js/modules/es6.symbol.js":99,"core-js/modules/web.dom.iterable.js":102}],104:[function(e,t,r){"use strict";var o=function(e,t,r){if(e.addEventListener)return e.addEventListener(t,r,!1);if(e.attachEvent)return e[t]=1,e.attachEvent("onpropertychange",(function(e){if(e.propertyName===t)return r()}));throw new Error("Attempt to attach custom event ".concat(t," to something which isn't a DOMElement"))},n=function(e,t){if(e.addEventListener){var r=document.createEvent("HTMLEvents");return r.initEvent(t,!0,!0),document.dispatchEvent(r)}if(!e.attachEvent)throw new Error("Attempt to fire custom event ".concat(t," on something which isn't a DOMElement"));if(e[t])return e[t]++};r.bind=o,r.fire=n},{}],105:[function(e,t,r){"use strict";function o(e){return o="function"==typeof Symbol&&"symbol"==typeof Symbol.iterator?function(e){return typeof e}:function(e){return e&&"function"==typeof Symbol&&e.constructor===Symbol&&e!==Symbol.prototype?"symbol":typeof e},o(e)}function n(e,t){for(var r=0;r<t.length;r++){var n=t[r];n.enumerable=n.enumerable||!1,n.configurable=!0,"value"in n&&(n.writable=!0),Object.defineProperty(e,(i=n.key,s=void 0,s=function(e,t){if("object"!==o(e)||null===e)return e;var r=e[Symbol.toPrimitive];if(void 0!==r){var n=r.call(e,t||"default");if("object"!==o(n))return n;throw new TypeError("@@toPrimitive must return a primitive value.")}return("string"===t?String:Number)(e)}(i,"string"),"symbol"===o(s)?s:String(s)),n)}var i,s}e("core-js/modules/es6.regexp.match.js"),e("core-js/modules/es6.symbol.js"),e("core-js/modules/es6.array.from.js"),e("core-js/modules/es6.string.iterator.js"),e("core-js/modules/es6.object.to-string.js"),e("core-js/modules/es6.array.iterator.js"),e("core-js/modules/web.dom.iterable.js"),e("core-js/modules/es6.number.constructor.js");var i=function(){function e(t,r){!function(e,t){if(!(e instanceof t))throw new TypeError("Cannot call a class as a function")}(this,e),this.window=t,this.host=r}var t,r,o;return t=e,(r=[{key:"reload",value:function(e,t){if(this.window.less&&this.window.less.refresh){if(e.match(/\.less$/i))return this.reloadLess(e);if(t.originalPath.match(/\.less$/i))return this.reloadLess(t.originalPath)}return!1}},{key:"reloadLess",value:function(e){var t,r=function(){for(var e=[],r=0,o=Array.from(document.getElementsByTagName("link"));r<o.length;r++)((t=o[r]).href&&t.rel.match(/^stylesheet\/less$/i)||t.rel.match(/stylesheet/i)&&t.type.match(/^text\/(x-)?less$/i))&&e.push(t);return e}();if(0===r.length)return!1;for(var o=0,n=Array.from(r);o<n.length;o++)(t=n[o]).href=this.host.generateCacheBustUrl(t.href);return this.host.console.log("LiveReload is asking LESS to recompile all stylesheets"),this.window.less.refresh(!0),!0}},{key:"analyze",value:function(){return{disable:!(!this.window.less||!this.window.less.refresh)}}}])&&n(t.prototype,r),o&&n(t,o),Object.defineProperty(t,"prototype",{writable:!1}),e}();i.identifier="less",i.version="1.0",t.exports=i},{"core-js/modules/es6.array.from.js":84,"core-js/modules/es6.array.iterator.js":85,"core-js/modules/es6.number.constructor.js":88,"core-js/modules/es6.object.to-string.js":91,"core-js/modules/es6.regexp.match.js":94,"core-js/modules/es6.string.iterator.js":98,"core-js/modules/es6.symbol.js":99,"core-js/modules/web.dom.iterable.js":102}],106:[function(e,t,r){"use strict";function o(e){return o="function"==typeof Symbol&&"symbol"==typeof Symbol.iterator?function(e){return typeof e}:function(e){return e&&"function"==typeof Symbol&&e.constructor===Symbol&&e!==Symbol.prototype?"symbol":typeof e},o(e)}function n(e,t){var r="undefined"!=typeof Symbol&&e[Symbol.iterator]||e["@@iterator"];if(!r){if(Array.isArray(e)||(r=function(e,t){if(!e)return;if("string"==typeof e)return i(e,t);var r=Object.prototype.toString.call(e).slice(8,-1);"Object"===r&&e.constructor&&(r=e.constructor.name);if("Map"===r||"Set"===r)return Array.from(e);if("Arguments"===r||/^(?:Ui|I)nt(?:8|16|32)(?:Clamped)?Array$/.test(r))return i(e,t)}(e))||t&&e&&"number"==typeof e.length){r&&(e=r);var o=0,n=function(){};return{s:n,n:function(){return o>=e.length?{done:!0}:{done:!1,value:e[o++]}},e:function(e){throw e},f:n}}throw new TypeError("Invalid attempt to iterate non-iterable instance.\nIn order to be iterable, non-array objects must have a [Symbol.iterator]() method.")}var s,c=!0,a=!1;return{s:function(){r=r.call(e)},n:function(){var e=r.next();return c=e.done,e},e:function(e){a=!0,s=e},f:function(){try{c||null==r.return||r.return()}finally{if(a)throw s}}}}function i(e,t){(null==t||t>e.length)&&(t=e.length);for(var r=0,o=new Array(t);r<t;r++)o[r]=e[r];return o}function s(e,t){for(var r=0;r<t.length;r++){var n=t[r];n.enumerable=n.enumerable||!1,n.configurable=!0,"value"in n&&(n.writable=!0),Object.defineProperty(e,(i=n.key,s=void 0,s=function(e,t){if("object"!==o(e)||null===e)return e;var r=e[Symbol.toPrimitive];if(void 0!==r){var n=r.call(e,t||"default");if("object"!==o(n))return n;throw new TypeError("@@toPrimitive must return a primitive value.")}return("string"===t?String:Number)(e)}(i,"string"),"symbol"===o(s)?s:String(s)),n)}var i,s}e("core-js/modules/es6.symbol.js"),e("core-js/modules/es6.number.constructor.js"),e("core-js/modules/es6.array.slice.js"),e("core-js/modules/es6.object.to-string.js"),e("core-js/modules/es6.array.from.js"),e("core-js/modules/es6.string.iterator.js"),e("core-js/modules/es6.array.iterator.js"),e("core-js/modules/web.dom.iterable.js"),e("core-js/modules/es6.regexp.match.js"),e("core-js/modules/es6.object.keys.js");var c=e("./connector").Connector,a=e("./timer").Timer,u=e("./options").Options,l=e("./reloader").Reloader,f=e("./protocol").ProtocolError,d=function(){function e(t){var r=this;if(function(e,t){if(!(e instanceof t))throw new TypeError("Cannot call a class as a function")}(this,e),this.window=t,this.listeners={},this.plugins=[],this.pluginIdentifiers={},this.console=this.window.console&&this.window.console.log&&this.window.console.error?this.window.location.href.match(/LR-verbose/)?this.window.console:{log:function(){},error:this.window.console.error.bind(this.window.console)}:{log:function(){},error:function(){}},this.WebSocket=this.window.WebSocket||this.window.MozWebSocket){if("LiveReloadOptions"in t){this.options=new u;for(var o=0,n=Object.keys(t.LiveReloadOptions||{});o<n.length;o++){var i=n[o],s=t.LiveReloadOptions[i];this.options.set(i,s)}}else if(this.options=u.extract(this.window.document),!this.options)return void this.console.error("LiveReload disabled because it could not find its own <SCRIPT> tag");this.reloader=new l(this.window,this.console,a),this.connector=new c(this.options,this.WebSocket,a,{connecting:function(){},socketConnected:function(){},connected:function(e){"function"==typeof r.listeners.connect&&r.listeners.connect();var t=r.options.host,o=r.options.port?":".concat(r.options.port):"";return r.log("LiveReload is connected to ".concat(t).concat(o," (protocol v").concat(e,").")),r.analyze()},error:function(e){if(e instanceof f){if("undefined"!=typeof console&&null!==console)return console.log("".concat(e.message,"."))}else if("undefined"!=typeof console&&null!==console)return console.log("LiveReload internal error: ".concat(e.message))},disconnected:function(e,t){"function"==typeof r.listeners.disconnect&&r.listeners.disconnect();var o=r.options.host,n=r.options.port?":".concat(r.options.port):"";switch(e){case"cannot-connect":return r.log("LiveReload cannot connect to ".concat(o).concat(n,", will retry in ").concat(t," sec."));case"broken":return r.log("LiveReload disconnected from ".concat(o).concat(n,", reconnecting in ").concat(t," sec."));case"handshake-timeout":return r.log("LiveReload cannot connect to ".concat(o).concat(n," (handshake timeout), will retry in ").concat(t," sec."));case"handshake-failed":return r.log("LiveReload cannot connect to ".concat(o).concat(n," (handshake failed), will retry in ").concat(t," sec."));default:return r.log("LiveReload disconnected from ".concat(o).concat(n," (").concat(e,"), reconnecting in ").concat(t," sec."))}},message:function(e){switch(e.command){case"reload":return r.performReload(e);case"alert":return r.performAlert(e)}}}),this.initialized=!0}else this.console.error("LiveReload disabled because the browser does not seem to support web sockets")}var t,r,o;return t=e,(r=[{key:"on",value:function(e,t){this.listeners[e]=t}},{key:"log",value:function(e){return this.console.log("".concat(e))}},{key:"performReload",value:function(e){this.log("LiveReload received reload request: ".concat(JSON.stringify(e,null,2)));var t=this.options,r=t.host,o=t.port,n=t.pluginOrder;return this.reloader.reload(e.path,{liveCSS:null==e.liveCSS||e.liveCSS,liveImg:null==e.liveImg||e.liveImg,reloadMissingCSS:null==e.reloadMissingCSS||e.reloadMissingCSS,originalPath:e.originalPath||"",overrideURL:e.overrideURL||"",serverURL:"http://".concat(r).concat(o&&":".concat(o)),pluginOrder:n})}},{key:"performAlert",value:function(e){return alert(e.message)}},{key:"shutDown",value:function(){if(this.initialized)return this.connector.disconnect(),this.log("LiveReload disconnected."),"function"==typeof this.listeners.shutdown?this.listeners.shutdown():void 0}},{key:"hasPlugin",value:function(e){return!!this.pluginIdentifiers[e]}},{key:"addPlugin",value:function(e){var t=this;if(this.initialized&&!this.hasPlugin(e.identifier)){this.pluginIdentifiers[e.identifier]=!0;var r=new e(this.window,{_livereload:this,_reloader:this.reloader,_connector:this.connector,console:this.console,Timer:a,generateCacheBustUrl:function(e){return t.reloader.generateCacheBustUrl(e)}});this.plugins.push(r),this.reloader.addPlugin(r)}}},{key:"analyze",value:function(){if(this.initialized&&this.connector.protocol>=7){var e,t={},r=n(this.plugins);try{for(r.s();!(e=r.n()).done;){var o=e.value,i=("function"==typeof o.analyze?o.analyze():void 0)||{};t[o.constructor.identifier]=i,i.version=o.constructor.version}}catch(e){r.e(e)}finally{r.f()}this.connector.sendCommand({command:"info",plugins:t,url:this.window.location.href})}}}])&&s(t.prototype,r),o&&s(t,o),Object.defineProperty(t,"prototype",{writable:!1}),e}();r.LiveReload=d},{"./connector":103,"./options":107,"./protocol":108,"./reloader":109,"./timer":111,"core-js/modules/es6.array.from.js":84,"core-js/modules/es6.array.iterator.js":85,"core-js/modules/es6.array.slice.js":87,"core-js/modules/es6.number.constructor.js":88,"core-js/modules/es6.object.keys.js":90,"core-js/modules/es6.object.to-string.js":91,"core-js/modules/es6.regexp.match.js":94,"core-js/modules/es6.string.iterator.js":98,"core-js/modules/es6.symbol.js":99,"core-js/modules/web.dom.iterable.js":102}],107:[function(e,t,r){"use strict";function o(e,t){var r="undefined"!=typeof Symbol&&e[Symbol.iterator]||e["@@iterator"];if(!r){if(Array.isArray(e)||(r=i(e))||t&&e&&"number"==typeof e.length){r&&(e=r);var o=0,n=function(){};return{s:n,n:function(){return o>=e.length?{done:!0}:{done:!1,value:e[o++]}},e:function(e){throw e},f:n}}throw new TypeError("Invalid attempt to iterate non-iterable instance.\nIn order to be iterable, non-array objects must have a [Symbol.iterator]() method.")}var s,c=!0,a=!1;return{s:function(){r=r.call(e)},n:function(){var e=r.next();return c=e.done,e},e:function(e){a=!0,s=e},f:function(){try{c||null==r.return||r.return()}finally{if(a)throw s}}}}function n(e,t){return function(e){if(Array.isArray(e))return e}(e)||function(e,t){var r=null==e?null:"undefined"!=typeof Symbol&&e[Symbol.iterator]||e["@@iterator"];if(null!=r){var o,n,i,s,c=[],a=!0,u=!1;try{if(i=(r=r.call(e)).next,0===t){if(Object(r)!==r)return;a=!1}else for(;!(a=(o=i.call(r)).done)&&(c.push(o.value),c.length!==t);a=!0);}catch(e){u=!0,n=e}finally{try{if(!a&&null!=r.return&&(s=r.return(),Object(s)!==s))return}finally{if(u)throw n}}return c}}(e,t)||i(e,t)||function(){throw new TypeError("Invalid attempt to destructure non-iterable instance.\nIn order to be iterable, non-array objects must have a [Symbol.iterator]() method.")}()}function i(e,t){if(e){if("string"==typeof e)return s(e,t);var r=Object.prototype.toString.call(e).slice(8,-1);return"Object"===r&&e.constructor&&(r=e.constructor.name),"Map"===r||"Set"===r?Array.from(e):"Arguments"===r||/^(?:Ui|I)nt(?:8|16|32)(?:Clamped)?Array$/.test(r)?s(e,t):void 0}}function s(e,t){(null==t||t>e.length)&&(t=e.length);for(var r=0,o=new Array(t);r<t;r++)o[r]=e[r];return o}function c(e){return c="function"==typeof Symbol&&"symbol"==typeof Symbol.iterator?function(e){return typeof e}:function(e){return e&&"function"==typeof Symbol&&e.constructor===Symbol&&e!==Symbol.prototype?"symbol":typeof e},c(e)}function a(e,t){for(var r=0;r<t.length;r++){var o=t[r];o.enumerable=o.enumerable||!1,o.configurable=!0,"value"in o&&(o.writable=!0),Object.defineProperty(e,(n=o.key,i=void 0,i=function(e,t){if("object"!==c(e)||null===e)return e;var r=e[Symbol.toPrimitive];if(void 0!==r){var o=r.call(e,t||"default");if("object"!==c(o))return o;throw new TypeError("@@toPrimitive must return a primitive value.")}return("string"===t?String:Number)(e)}(n,"string"),"symbol"===c(i)?i:String(i)),o)}var n,i}e("core-js/modules/es6.regexp.split.js"),e("core-js/modules/es6.symbol.js"),e("core-js/modules/es6.number.constructor.js"),e("core-js/modules/es6.array.from.js"),e("core-js/modules/es6.string.iterator.js"),e("core-js/modules/es6.object.to-string.js"),e("core-js/modules/es6.array.iterator.js"),e("core-js/modules/web.dom.iterable.js"),e("core-js/modules/es6.regexp.match.js"),e("core-js/modules/es6.regexp.replace.js"),e("core-js/modules/es6.array.slice.js");var u=function(){function e(){!function(e,t){if(!(e instanceof t))throw new TypeError("Cannot call a class as a function")}(this,e),this.https=!1,this.host=null;var t=35729;Object.defineProperty(this,"port",{get:function(){return t},set:function(e){t=e?isNaN(e)?e:+e:""}}),this.snipver=null,this.ext=null,this.extver=null,this.mindelay=1e3,this.maxdelay=6e4,this.handshake_timeout=5e3;var r=[];Object.defineProperty(this,"pluginOrder",{get:function(){return r},set:function(e){r.push.apply(r,e.split(/[,;]/))}})}var t,r,o;return t=e,(r=[{key:"set",value:function(e,t){void 0!==t&&(isNaN(+t)||(t=+t),this[e]=t)}}])&&a(t.prototype,r),o&&a(t,o),Object.defineProperty(t,"prototype",{writable:!1}),e}();u.extract=function(e){for(var t=0,r=Array.from(e.getElementsByTagName("script"));t<r.length;t++){var i,s,c=r[t],a=c.src,l=c.getAttribute("src");if((i=a.match(/^([^:]+:\/\/([^/:]+|\[[0-9a-f:]+\])(?::(\d+))?\/|\/\/|\/)?([^/].*\/)?z?livereload\.js(?:\?(.*))?$/))&&(s=l.match(/^(?:(?:([^:/]+)?:?)\/{0,2})([^:]+|\[[0-9a-f:]+\])(?::(\d+))?/))){var f=n(i,6),d=f[2],p=f[3],h=f[5],_=n(s,4)[3],m=new u;m.https=0===c.src.indexOf("https"),m.host=d;var y=parseInt(p||_,10)||"";if(m.port=y||m.port,h){var v,g=o(h.split("&"));try{for(g.s();!(v=g.n()).done;){var b;(b=v.value.split("=")).length>1&&m.set(b[0].replace(/-/g,"_"),b.slice(1).join("="))}}catch(e){g.e(e)}finally{g.f()}}return m.port=m.port||y,m}}return null},r.Options=u},{"core-js/modules/es6.array.from.js":84,"core-js/modules/es6.array.iterator.js":85,"core-js/modules/es6.array.slice.js":87,"core-js/modules/es6.number.constructor.js":88,"core-js/modules/es6.object.to-string.js":91,"core-js/modules/es6.regexp.match.js":94,"core-js/modules/es6.regexp.replace.js":95,"core-js/modules/es6.regexp.split.js":96,"core-js/modules/es6.string.iterator.js":98,"core-js/modules/es6.symbol.js":99,"core-js/modules/web.dom.iterable.js":102}],108:[function(e,t,r){"use strict";function o(e){return o="function"==typeof Symbol&&"symbol"==typeof Symbol.iterator?function(e){return typeof e}:function(e){return e&&"function"==typeof Symbol&&e.constructor===Symbol&&e!==Symbol.prototype?"symbol":typeof e},o(e)}function n(e,t){return function(e){if(Array.isArray(e))return e}(e)||function(e,t){var r=null==e?null:"undefined"!=typeof Symbol&&e[Symbol.iterator]||e["@@iterator"];if(null!=r){var o,n,i,s,c=[],a=!0,u=!1;try{if(i=(r=r.call(e)).next,0===t){if(Object(r)!==r)return;a=!1}else for(;!(a=(o=i.call(r)).done)&&(c.push(o.value),c.length!==t);a=!0);}catch(e){u=!0,n=e}finally{try{if(!a&&null!=r.return&&(s=r.return(),Object(s)!==s))return}finally{if(u)throw n}}return c}}(e,t)||function(e,t){if(!e)return;if("string"==typeof e)return i(e,t);var r=Object.prototype.toString.call(e).slice(8,-1);"Object"===r&&e.constructor&&(r=e.constructor.name);if("Map"===r||"Set"===r)return Array.from(e);if("Arguments"===r||/^(?:Ui|I)nt(?:8|16|32)(?:Clamped)?Array$/.test(r))return i(e,t)}(e,t)||function(){throw new TypeError("Invalid attempt to destructure non-iterable instance.\nIn order to be iterable, non-array objects must have a [Symbol.iterator]() method.")}()}function i(e,t){(null==t||t>e.length)&&(t=e.length);for(var r=0,o=new Array(t);r<t;r++)o[r]=e[r];return o}function s(e,t){for(var r=0;r<t.length;r++){var n=t[r];n.enumerable=n.enumerable||!1,n.configurable=!0,"value"in n&&(n.writable=!0),Object.defineProperty(e,(i=n.key,s=void 0,s=function(e,t){if("object"!==o(e)||null===e)return e;var r=e[Symbol.toPrimitive];if(void 0!==r){var n=r.call(e,t||"default");if("object"!==o(n))return n;throw new TypeError("@@toPrimitive must return a primitive value.")}return("string"===t?String:Number)(e)}(i,"string"),"symbol"===o(s)?s:String(s)),n)}var i,s}function c(e,t,r){return t&&s(e.prototype,t),r&&s(e,r),Object.defineProperty(e,"prototype",{writable:!1}),e}function a(e,t){if(!(e instanceof t))throw new TypeError("Cannot call a class as a function")}e("core-js/modules/es6.number.constructor.js"),e("core-js/modules/es6.array.slice.js"),e("core-js/modules/es6.regexp.match.js"),e("core-js/modules/es6.regexp.constructor.js"),e("core-js/modules/es6.string.includes.js"),e("core-js/modules/es7.array.includes.js"),e("core-js/modules/es6.symbol.js"),e("core-js/modules/es6.array.from.js"),e("core-js/modules/es6.string.iterator.js"),e("core-js/modules/es6.object.to-string.js"),e("core-js/modules/es6.array.iterator.js"),e("core-js/modules/web.dom.iterable.js"),r.PROTOCOL_6="http://livereload.com/protocols/official-6",r.PROTOCOL_7="http://livereload.com/protocols/official-7";var u=c((function e(t,r){a(this,e),this.message="LiveReload protocol error (".concat(t,') after receiving data: "').concat(r,'".')})),l=function(){function e(t){a(this,e),this.handlers=t,this.reset()}return c(e,[{key:"reset",value:function(){this.protocol=null}},{key:"process",value:function(e){try{var t;if(!this.protocol){if(e.match(new RegExp("^!!ver:([\\d.]+)$")))this.protocol=6;else if(t=this._parseMessage(e,["hello"])){if(!t.protocols.length)throw new u("no protocols specified in handshake message");if(Array.from(t.protocols).includes("http://livereload.com/protocols/official-7"))this.protocol=7;else{if(!Array.from(t.protocols).includes("http://livereload.com/protocols/official-6"))throw new u("no supported protocols found");this.protocol=6}}return this.handlers.connected(this.protocol)}if(6===this.protocol){if(!(t=JSON.parse(e)).length)throw new u("protocol 6 messages must be arrays");var r=n(Array.from(t),2),o=r[0],i=r[1];if("refresh"!==o)throw new u("unknown protocol 6 command");return this.handlers.message({command:"reload",path:i.path,liveCSS:null==i.apply_css_live||i.apply_css_live})}return t=this._parseMessage(e,["reload","alert"]),this.handlers.message(t)}catch(e){if(e instanceof u)return this.handlers.error(e);throw e}}},{key:"_parseMessage",value:function(e,t){var r;try{r=JSON.parse(e)}catch(t){throw new u("unparsable JSON",e)}if(!r.command)throw new u('missing "command" key',e);if(!t.includes(r.command))throw new u("invalid command '".concat(r.command,"', only valid commands are: ").concat(t.join(", "),")"),e);return r}}]),e}();r.ProtocolError=u,r.Parser=l},{"core-js/modules/es6.array.from.js":84,"core-js/modules/es6.array.iterator.js":85,"core-js/modules/es6.array.slice.js":87,"core-js/modules/es6.number.constructor.js":88,"core-js/modules/es6.object.to-string.js":91,"core-js/modules/es6.regexp.constructor.js":92,"core-js/modules/es6.regexp.match.js":94,"core-js/modules/es6.string.includes.js":97,"core-js/modules/es6.string.iterator.js":98,"core-js/modules/es6.symbol.js":99,"core-js/modules/es7.array.includes.js":100,"core-js/modules/web.dom.iterable.js":102}],109:[function(e,t,r){"use strict";function o(e){return o="function"==typeof Symbol&&"symbol"==typeof Symbol.iterator?function(e){return typeof e}:function(e){return e&&"function"==typeof Symbol&&e.constructor===Symbol&&e!==Symbol.prototype?"symbol":typeof e},o(e)}function n(e,t){var r=Object.keys(e);if(Object.getOwnPropertySymbols){var o=Object.getOwnPropertySymbols(e);t&&(o=o.filter((function(t){return Object.getOwnPropertyDescriptor(e,t).enumerable}))),r.push.apply(r,o)}return r}function i(e){for(var t=1;t<arguments.length;t++){var r=null!=arguments[t]?arguments[t]:{};t%2?n(Object(r),!0).forEach((function(t){s(e,t,r[t])})):Object.getOwnPropertyDescriptors?Object.defineProperties(e,Object.getOwnPropertyDescriptors(r)):n(Object(r)).forEach((function(t){Object.defineProperty(e,t,Object.getOwnPropertyDescriptor(r,t))}))}return e}function s(e,t,r){return(t=a(t))in e?Object.defineProperty(e,t,{value:r,enumerable:!0,configurable:!0,writable:!0}):e[t]=r,e}function c(e,t){for(var r=0;r<t.length;r++){var o=t[r];o.enumerable=o.enumerable||!1,o.configurable=!0,"value"in o&&(o.writable=!0),Object.defineProperty(e,a(o.key),o)}}function a(e){var t=function(e,t){if("object"!==o(e)||null===e)return e;var r=e[Symbol.toPrimitive];if(void 0!==r){var n=r.call(e,t||"default");if("object"!==o(n))return n;throw new TypeError("@@toPrimitive must return a primitive value.")}return("string"===t?String:Number)(e)}(e,"string");return"symbol"===o(t)?t:String(t)}function u(e,t){var r="undefined"!=typeof Symbol&&e[Symbol.iterator]||e["@@iterator"];if(!r){if(Array.isArray(e)||(r=function(e,t){if(!e)return;if("string"==typeof e)return l(e,t);var r=Object.prototype.toString.call(e).slice(8,-1);"Object"===r&&e.constructor&&(r=e.constructor.name);if("Map"===r||"Set"===r)return Array.from(e);if("Arguments"===r||/^(?:Ui|I)nt(?:8|16|32)(?:Clamped)?Array$/.test(r))return l(e,t)}(e))||t&&e&&"number"==typeof e.length){r&&(e=r);var o=0,n=function(){};return{s:n,n:function(){return o>=e.length?{done:!0}:{done:!1,value:e[o++]}},e:function(e){throw e},f:n}}throw new TypeError("Invalid attempt to iterate non-iterable instance.\nIn order to be iterable, non-array objects must have a [Symbol.iterator]() method.")}var i,s=!0,c=!1;return{s:function(){r=r.call(e)},n:function(){var e=r.next();return s=e.done,e},e:function(e){c=!0,i=e},f:function(){try{s||null==r.return||r.return()}finally{if(c)throw i}}}}function l(e,t){(null==t||t>e.length)&&(t=e.length);for(var r=0,o=new Array(t);r<t;r++)o[r]=e[r];return o}function f(e){var t="",r="",o=e.indexOf("#");o>=0&&(t=e.slice(o),e=e.slice(0,o));var n=e.indexOf("??");return n>=0?n+1!==e.lastIndexOf("?")&&(o=e.lastIndexOf("?")):o=e.indexOf("?"),o>=0&&(r=e.slice(o),e=e.slice(0,o)),{url:e,params:r,hash:t}}function d(e){return e?(t=0===(e=f(e).url).indexOf("file://")?e.replace(new RegExp("^file://(localhost)?"),""):e.replace(new RegExp("^([^:]+:)?//([^:/]+)(:\\d*)?/"),"/"),decodeURIComponent(t)):"";var t}function p(e,t){if((e=e.replace(/^\/+/,"").toLowerCase())===(t=t.replace(/^\/+/,"").toLowerCase()))return 1e4;for(var r=e.split(/\/|\\/).reverse(),o=t.split(/\/|\\/).reverse(),n=Math.min(r.length,o.length),i=0;i<n&&r[i]===o[i];)++i;return i}function h(e,t){var r,o,n=arguments.length>2&&void 0!==arguments[2]?arguments[2]:function(e){return e},i={score:0},s=u(t);try{for(s.s();!(o=s.n()).done;){var c=o.value;(r=p(e,n(c)))>i.score&&(i={object:c,score:r})}}catch(e){s.e(e)}finally{s.f()}return 0===i.score?null:i}function _(e,t){return p(e,t)>0}e("core-js/modules/es6.number.constructor.js"),e("core-js/modules/es6.object.keys.js"),e("core-js/modules/es6.object.get-own-property-descriptor.js"),e("core-js/modules/es7.object.get-own-property-descriptors.js"),e("core-js/modules/es6.array.slice.js"),e("core-js/modules/es6.regexp.replace.js"),e("core-js/modules/es6.regexp.constructor.js"),e("core-js/modules/es6.regexp.split.js"),e("core-js/modules/es6.symbol.js"),e("core-js/modules/es6.array.from.js"),e("core-js/modules/es6.string.iterator.js"),e("core-js/modules/es6.object.to-string.js"),e("core-js/modules/es6.array.iterator.js"),e("core-js/modules/web.dom.iterable.js"),e("core-js/modules/es6.regexp.match.js"),e("core-js/modules/es6.array.filter.js"),e("core-js/modules/es6.array.map.js");var m=[{selector:"background",styleNames:["backgroundImage"]},{selector:"border",styleNames:["borderImage","webkitBorderImage","MozBorderImage"]}],y={stylesheetReloadTimeout:15e3},v=/\.(jpe?g|png|gif|svg)$/i,g=function(){function e(t,r,o){!function(e,t){if(!(e instanceof t))throw new TypeError("Cannot call a class as a function")}(this,e),this.window=t,this.console=r,this.Timer=o,this.document=this.window.document,this.importCacheWaitPeriod=200,this.plugins=[]}var t,r,o;return t=e,r=[{key:"addPlugin",value:function(e){return this.plugins.push(e)}},{key:"analyze",value:function(e){}},{key:"reload",value:function(e){var t=arguments.length>1&&void 0!==arguments[1]?arguments[1]:{};if(this.options=i(i({},y),t),this.options.pluginOrder&&this.options.pluginOrder.length)this.runPluginsByOrder(e,t);else{for(var r=0,o=Array.from(this.plugins);r<o.length;r++){var n=o[r];if(n.reload&&n.reload(e,t))return}if(!(t.liveCSS&&e.match(/\.css(?:\.map)?$/i)&&this.reloadStylesheet(e)))if(t.liveImg&&e.match(v))this.reloadImages(e);else{if(!t.isChromeExtension)return this.reloadPage();this.reloadChromeExtension()}}}},{key:"runPluginsByOrder",value:function(e,t){var r=this;t.pluginOrder.some((function(o){return!!("css"===o&&t.liveCSS&&e.match(/\.css(?:\.map)?$/i)&&r.reloadStylesheet(e))||("img"===o&&t.liveImg&&e.match(v)?(r.reloadImages(e),!0):"extension"===o&&t.isChromeExtension?(r.reloadChromeExtension(),!0):"others"===o?(r.reloadPage(),!0):"external"===o?r.plugins.some((function(r){return r.reload&&r.reload(e,t)})):r.plugins.filter((function(e){return e.constructor.identifier===o})).some((function(r){return r.reload&&r.reload(e,t)})))}))}},{key:"reloadPage",value:function(){return this.window.document.location.reload()}},{key:"reloadChromeExtension",value:function(){return this.window.chrome.runtime.reload()}},{key:"reloadImages",value:function(e){for(var t,r=this,o=this.generateUniqueString(),n=0,i=Array.from(this.document.images);n<i.length;n++)t=i[n],_(e,d(t.src))&&(t.src=this.generateCacheBustUrl(t.src,o));if(this.document.querySelectorAll)for(var s=0,c=m;s<c.length;s++)for(var a=c[s],u=a.selector,l=a.styleNames,f=0,p=Array.from(this.document.querySelectorAll("[style*=".concat(u,"]")));f<p.length;f++)t=p[f],this.reloadStyleImages(t.style,l,e,o);if(this.document.styleSheets)return Array.from(this.document.styleSheets).map((function(t){return r.reloadStylesheetImages(t,e,o)}))}},{key:"reloadStylesheetImages",value:function(e,t,r){var o;try{o=(e||{}).cssRules}catch(e){}if(o)for(var n=0,i=Array.from(o);n<i.length;n++){var s=i[n];switch(s.type){case CSSRule.IMPORT_RULE:this.reloadStylesheetImages(s.styleSheet,t,r);break;case CSSRule.STYLE_RULE:for(var c=0,a=m;c<a.length;c++){var u=a[c].styleNames;this.reloadStyleImages(s.style,u,t,r)}break;case CSSRule.MEDIA_RULE:this.reloadStylesheetImages(s,t,r)}}}},{key:"reloadStyleImages",value:function(e,t,r,o){var n,i=this,s=u(t);try{for(s.s();!(n=s.n()).done;){var c=n.value,a=e[c];if("string"==typeof a){var l=a.replace(new RegExp("\\burl\\s*\\(([^)]*)\\)"),(function(e,t){return _(r,d(t))?"url(".concat(i.generateCacheBustUrl(t,o),")"):e}));l!==a&&(e[c]=l)}}}catch(e){s.e(e)}finally{s.f()}}},{key:"reloadStylesheet",value:function(e){for(var t,r,o=this,n=this.options||y,i=function(){for(var e=[],t=0,n=Array.from(o.document.getElementsByTagName("link"));t<n.length;t++)(r=n[t]).rel.match(/^stylesheet$/i)&&!r.__LiveReload_pendingRemoval&&e.push(r);return e}(),s=[],c=0,a=Array.from(this.document.getElementsByTagName("style"));c<a.length;c++)(t=a[c]).sheet&&this.collectImportedStylesheets(t,t.sheet,s);for(var u=0,l=Array.from(i);u<l.length;u++)r=l[u],this.collectImportedStylesheets(r,r.sheet,s);if(this.window.StyleFix&&this.document.querySelectorAll)for(var f=0,p=Array.from(this.document.querySelectorAll("style[data-href]"));f<p.length;f++)t=p[f],i.push(t);this.console.log("LiveReload found ".concat(i.length," LINKed stylesheets, ").concat(s.length," @imported stylesheets"));var _=h(e,i.concat(s),(function(e){return d(o.linkHref(e))}));if(_)_.object.rule?(this.console.log("LiveReload is reloading imported stylesheet: ".concat(_.object.href)),this.reattachImportedRule(_.object)):(this.console.log("LiveReload is reloading stylesheet: ".concat(this.linkHref(_.object))),this.reattachStylesheetLink(_.object));else if(n.reloadMissingCSS){this.console.log("LiveReload will reload all stylesheets because path '".concat(e,"' did not match any specific one. To disable this behavior, set 'options.reloadMissingCSS' to 'false'."));for(var m=0,v=Array.from(i);m<v.length;m++)r=v[m],this.reattachStylesheetLink(r)}else this.console.log("LiveReload will not reload path '".concat(e,"' because the stylesheet was not found on the page and 'options.reloadMissingCSS' was set to 'false'."));return!0}},{key:"collectImportedStylesheets",value:function(e,t,r){var o;try{o=(t||{}).cssRules}catch(e){}if(o&&o.length)for(var n=0;n<o.length;n++){var i=o[n];switch(i.type){case CSSRule.CHARSET_RULE:continue;case CSSRule.IMPORT_RULE:r.push({link:e,rule:i,index:n,href:i.href}),this.collectImportedStylesheets(e,i.styleSheet,r)}}}},{key:"waitUntilCssLoads",value:function(e,t){var r,o=this,n=this.options||y,i=!1,s=function(){if(!i)return i=!0,t()};return e.onload=function(){return o.console.log("LiveReload: the new stylesheet has finished loading"),o.knownToSupportCssOnLoad=!0,s()},this.knownToSupportCssOnLoad||(r=function(){return e.sheet?(o.console.log("LiveReload is polling until the new CSS finishes loading..."),s()):o.Timer.start(50,r)})(),this.Timer.start(n.stylesheetReloadTimeout,s)}},{key:"linkHref",value:function(e){return e.href||e.getAttribute&&e.getAttribute("data-href")}},{key:"reattachStylesheetLink",value:function(e){var t,r=this;if(!e.__LiveReload_pendingRemoval){e.__LiveReload_pendingRemoval=!0,"STYLE"===e.tagName?((t=this.document.createElement("link")).rel="stylesheet",t.media=e.media,t.disabled=e.disabled):t=e.cloneNode(!1),t.href=this.generateCacheBustUrl(this.linkHref(e));var o=e.parentNode;return o.lastChild===e?o.appendChild(t):o.insertBefore(t,e.nextSibling),this.waitUntilCssLoads(t,(function(){var o;return o=/AppleWebKit/.test(r.window.navigator.userAgent)?5:200,r.Timer.start(o,(function(){if(e.parentNode)return e.parentNode.removeChild(e),t.onreadystatechange=null,r.window.StyleFix?r.window.StyleFix.link(t):void 0}))}))}}},{key:"reattachImportedRule",value:function(e){var t=this,r=e.rule,o=e.index,n=e.link,i=r.parentStyleSheet,s=this.generateCacheBustUrl(r.href),c=r.media.length?[].join.call(r.media,", "):"",a='@import url("'.concat(s,'") ').concat(c,";");r.__LiveReload_newHref=s;var u=this.document.createElement("link");return u.rel="stylesheet",u.href=s,u.__LiveReload_pendingRemoval=!0,n.parentNode&&n.parentNode.insertBefore(u,n),this.Timer.start(this.importCacheWaitPeriod,(function(){if(u.parentNode&&u.parentNode.removeChild(u),r.__LiveReload_newHref===s)return i.insertRule(a,o),i.deleteRule(o+1),(r=i.cssRules[o]).__LiveReload_newHref=s,t.Timer.start(t.importCacheWaitPeriod,(function(){if(r.__LiveReload_newHref===s)return i.insertRule(a,o),i.deleteRule(o+1)}))}))}},{key:"generateUniqueString",value:function(){return"livereload=".concat(Date.now())}},{key:"generateCacheBustUrl",value:function(e,t){var r,o,n=this.options||y;t||(t=this.generateUniqueString());var i=f(e);if(e=i.url,r=i.hash,o=i.params,n.overrideURL&&e.indexOf(n.serverURL)<0){var s=e;e=n.serverURL+n.overrideURL+"?url="+encodeURIComponent(e),this.console.log("LiveReload is overriding source URL ".concat(s," with ").concat(e))}var c=o.replace(/(\?|&)livereload=(\d+)/,(function(e,r){return"".concat(r).concat(t)}));return c===o&&(c=0===o.length?"?".concat(t):"".concat(o,"&").concat(t)),e+c+r}}],r&&c(t.prototype,r),o&&c(t,o),Object.defineProperty(t,"prototype",{writable:!1}),e}();r.splitUrl=f,r.pathFromUrl=d,r.numberOfMatchingSegments=p,r.pickBestMatch=h,r.pathsMatch=_,r.Reloader=g},{"core-js/modules/es6.array.filter.js":83,"core-js/modules/es6.array.from.js":84,"core-js/modules/es6.array.iterator.js":85,"core-js/modules/es6.array.map.js":86,"core-js/modules/es6.array.slice.js":87,"core-js/modules/es6.number.constructor.js":88,"core-js/modules/es6.object.get-own-property-descriptor.js":89,"core-js/modules/es6.object.keys.js":90,"core-js/modules/es6.object.to-string.js":91,"core-js/modules/es6.regexp.constructor.js":92,"core-js/modules/es6.regexp.match.js":94,"core-js/modules/es6.regexp.replace.js":95,"core-js/modules/es6.regexp.split.js":96,"core-js/modules/es6.string.iterator.js":98,"core-js/modules/es6.symbol.js":99,"core-js/modules/es7.object.get-own-property-descriptors.js":101,"core-js/modules/web.dom.iterable.js":102}],110:[function(e,t,r){"use strict";e("core-js/modules/es6.regexp.match.js");var o=e("./customevents"),n=window.LiveReload=new(e("./livereload").LiveReload)(window);for(var i in window)i.match(/^LiveReloadPlugin/)&&n.addPlugin(window[i]);n.addPlugin(e("./less")),n.on("shutdown",(function(){return delete window.LiveReload})),n.on("connect",(function(){return o.fire(document,"LiveReloadConnect")})),n.on("disconnect",(function(){return o.fire(document,"LiveReloadDisconnect")})),o.bind(document,"LiveReloadShutDown",(function(){return n.shutDown()}))},{"./customevents":104,"./less":105,"./livereload":106,"core-js/modules/es6.regexp.match.js":94}],111:[function(e,t,r){"use strict";function o(e){return o="function"==typeof Symbol&&"symbol"==typeof Symbol.iterator?function(e){return typeof e}:function(e){return e&&"function"==typeof Symbol&&e.constructor===Symbol&&e!==Symbol.prototype?"symbol":typeof e},o(e)}function n(e,t){for(var r=0;r<t.length;r++){var n=t[r];n.enumerable=n.enumerable||!1,n.configurable=!0,"value"in n&&(n.writable=!0),Object.defineProperty(e,(i=n.key,s=void 0,s=function(e,t){if("object"!==o(e)||null===e)return e;var r=e[Symbol.toPrimitive];if(void 0!==r){var n=r.call(e,t||"default");if("object"!==o(n))return n;throw new TypeError("@@toPrimitive must return a primitive value.")}return("string"===t?String:Number)(e)}(i,"string"),"symbol"===o(s)?s:String(s)),n)}var i,s}e("core-js/modules/es6.string.iterator.js"),e("core-js/modules/es6.object.to-string.js"),e("core-js/modules/es6.array.iterator.js"),e("core-js/modules/web.dom.iterable.js"),e("core-js/modules/es6.symbol.js"),e("core-js/modules/es6.number.constructor.js");var i=function(){function e(t){var r=this;!function(e,t){if(!(e instanceof t))throw new TypeError("Cannot call a class as a function")}(this,e),this.func=t,this.running=!1,this.id=null,this._handler=function(){return r.running=!1,r.id=null,r.func()}}var t,r,o;return t=e,(r=[{key:"start",value:function(e){this.running&&clearTimeout(this.id),this.id=setTimeout(this._handler,e),this.running=!0}},{key:"stop",value:function(){this.running&&(clearTimeout(this.id),this.running=!1,this.id=null)}}])&&n(t.prototype,r),o&&n(t,o),Object.defineProperty(t,"prototype",{writable:!1}),e}();i.start=function(e,t){return setTimeout(t,e)},r.Timer=i},{"core-js/modules/es6.array.iterator.js":85,"core-js/modules/es6.number.constructor.js":88,"core-js/modules/es6.object.to-string.js":91,"core-js/modules/es6.string.iterator.js":98,"core-js/modules/es6.symbol.js":99,"core-js/modules/web.dom.iterable.js":102}]},{},[110]);
+// Norgolith development server live reload client.
+//
+// Handles the reload messages of `lith dev`:
+//   - `css`: swaps the changed stylesheet in place (every stylesheet when it matches none).
+//   - `page`: reloads the page when it is the one viewed.
+//   - `full`: reloads the page.
+// The scroll position is restored after reloading. Errors are shown in an overlay, dismissed by
+// the next reload message (i.e. the next successful rebuild).
+(function () {
+  "use strict";
+
+  var SCROLL_KEY = "norgolith:livereload:scroll";
+  var OVERLAY_ID = "norgolith-error-overlay";
+  var OVERLAY_STYLE = [
+    ":host { all: initial; }",
+    ".backdrop { position: fixed; inset: 0; z-index: 2147483647; overflow: auto;",
+    "  background: rgba(0, 0, 0, 0.66); font: 14px/1.5 ui-monospace, SFMono-Regular, Menlo, monospace; }",
+    ".panel { max-width: 960px; margin: 48px auto; padding: 24px; border-top: 4px solid #e5484d;",
+    "  border-radius: 6px; background: #1c1c1f; color: #ededef; box-shadow: 0 8px 32px rgba(0, 0, 0, 0.5); }",
+    "h1 { margin: 0 0 8px; font-size: 18px; color: #ff6369; white-space: pre-wrap; }",
+    ".location { margin-bottom: 16px; color: #a0a0a8; }",
+    "ol { margin: 0 0 16px; padding-left: 20px; }",
+    "li { white-space: pre-wrap; }",
+    "pre { margin: 0; padding: 12px 0; overflow-x: auto; background: #111113; border-radius: 4px; }",
+    ".line { display: block; padding: 0 12px; }",
+    ".line.error { background: rgba(229, 72, 77, 0.25); }",
+    ".number { display: inline-block; min-width: 4ch; margin-right: 12px; color: #6f6f78; text-align: right; }",
+    "button { float: right; border: 0; background: none; color: #a0a0a8; font: inherit; cursor: pointer; }",
+  ].join("\n");
+  var script = document.currentScript;
+  var params = new URL(script ? script.src : location.href, location.href).searchParams;
+  var port = params.get("port") || "35729";
+  var minDelay = parseInt(params.get("mindelay") || "0", 10);
+
+  // Permalink of a URL path, e.g. `/posts/foo` for `/posts/foo/` or `/posts/foo/index.html`
+  function permalink(path) {
+    path = path.replace(/\.html$/, "").replace(/\/index$/, "").replace(/\/+$/, "");
+    return path === "" ? "/" : path;
+  }
+
+  function restoreScroll() {
+    var saved = sessionStorage.getItem(SCROLL_KEY);
+    if (!saved) {
+      return;
+    }
+    sessionStorage.removeItem(SCROLL_KEY);
+    try {
+      var scroll = JSON.parse(saved);
+      if (scroll.path === location.pathname) {
+        window.scrollTo(scroll.x, scroll.y);
+      }
+    } catch (e) {
+      // Ignore malformed positions
+    }
+  }
+
+  function reloadPage() {
+    sessionStorage.setItem(
+      SCROLL_KEY,
+      JSON.stringify({ path: location.pathname, x: window.scrollX, y: window.scrollY })
+    );
+    setTimeout(function () {
+      location.reload();
+    }, minDelay);
+  }
+
+  function reloadStylesheet(link) {
+    var url = new URL(link.href, location.href);
+    url.searchParams.set("livereload", Date.now().toString());
+    // The new stylesheet is loaded before removing the old one, so the page does not flash
+    var clone = link.cloneNode();
+    clone.href = url.toString();
+    clone.addEventListener("load", function () {
+      link.remove();
+    });
+    clone.addEventListener("error", function () {
+      clone.remove();
+    });
+    link.after(clone);
+  }
+
+  function reloadStylesheets(path) {
+    var links = Array.prototype.slice.call(
+      document.querySelectorAll('link[rel="stylesheet"][href]')
+    );
+    var matching = links.filter(function (link) {
+      return new URL(link.href, location.href).pathname === path;
+    });
+    // Stylesheet partials match no stylesheet, they may be imported by any of them
+    (matching.length > 0 ? matching : links).forEach(reloadStylesheet);
+  }
+
+  function element(tag, className, text) {
+    var node = document.createElement(tag);
+    if (className) {
+      node.className = className;
+    }
+    if (text !== undefined) {
+      node.textContent = text;
+    }
+    return node;
+  }
+
+  function hideError() {
+    var overlay = document.getElementById(OVERLAY_ID);
+    if (overlay) {
+      overlay.remove();
+    }
+  }
+
+  function showError(error) {
+    hideError();
+    var host = element("div");
+    host.id = OVERLAY_ID;
+    // The shadow root keeps the site styles away from the overlay
+    var root = host.attachShadow({ mode: "open" });
+    var style = element("style");
+    style.textContent = OVERLAY_STYLE;
+    root.appendChild(style);
+
+    var backdrop = element("div", "backdrop");
+    var panel = element("div", "panel");
+    var close = element("button", "", "\u2715");
+    close.title = "Dismiss";
+    close.addEventListener("click", hideError);
+    panel.appendChild(close);
+    panel.appendChild(element("h1", "", error.message));
+
+    var position = [];
+    if (error.file) {
+      position.push(
+        error.file + (error.line ? ":" + error.line + (error.column ? ":" + error.column : "") : "")
+      );
+    }
+    if (error.template) {
+      position.push("template " + error.template);
+    }
+    if (position.length > 0) {
+      panel.appendChild(element("div", "location", position.join(" \u00b7 ")));
+    }
+
+    var causes = error.chain.slice(1);
+    if (causes.length > 0) {
+      var list = element("ol");
+      causes.forEach(function (cause) {
+        list.appendChild(element("li", "", cause));
+      });
+      panel.appendChild(list);
+    }
+
+    if (error.excerpt) {
+      var code = element("pre");
+      error.excerpt.lines.forEach(function (line, index) {
+        var number = error.excerpt.start_line + index;
+        var row = element("span", number === error.line ? "line error" : "line");
+        row.appendChild(element("span", "number", String(number)));
+        row.appendChild(document.createTextNode(line));
+        code.appendChild(row);
+      });
+      panel.appendChild(code);
+    }
+
+    backdrop.appendChild(panel);
+    root.appendChild(backdrop);
+    document.body.appendChild(host);
+  }
+
+  document.addEventListener("keydown", function (event) {
+    if (event.key === "Escape") {
+      hideError();
+    }
+  });
+
+  function handleMessage(message) {
+    if (message.command === "error") {
+      showError(message.error);
+      return;
+    }
+    if (message.command !== "reload") {
+      return;
+    }
+    hideError();
+    switch (message.kind) {
+      case "css":
+        reloadStylesheets(message.path);
+        break;
+      case "page":
+        if (permalink(location.pathname) === permalink(message.path)) {
+          reloadPage();
+        }
+        break;
+      default:
+        reloadPage();
+    }
+  }
+
+  function connect() {
+    var protocol = location.protocol === "https:" ? "wss:" : "ws:";
+    var socket = new WebSocket(protocol + "//" + location.hostname + ":" + port + "/livereload");
+    socket.addEventListener("message", function (event) {
+      try {
+        handleMessage(JSON.parse(event.data));
+      } catch (e) {
+        console.error("[norgolith] Invalid live reload message", e);
+      }
+    });
+    // Reconnect when the server restarts
+    socket.addEventListener("close", function () {
+      setTimeout(connect, 1000);
+    });
+  }
+
+  restoreScroll();
+  connect();
+})();
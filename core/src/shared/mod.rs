@@ -1,16 +1,17 @@
 use std::collections::{HashMap, HashSet};
-use std::error::Error;
 use std::path::{Path, PathBuf};
 use std::time::Instant;
 
 use colored::Colorize;
-use eyre::{eyre, Result};
+use eyre::{eyre, Result, WrapErr};
 use tera::{Context, Tera};
-use tracing::{error, warn};
+use tracing::{debug, error, warn};
 use walkdir::WalkDir;
 
+use crate::cache::BuildCache;
 use crate::config::{CollectionConfig, SiteConfig};
 use crate::converter;
+use crate::converter::diagnostics::Diagnostic;
 use crate::schema::{format_errors, validate_metadata, ContentSchema};
 
 /// Pre-computed collection subsets: collection name → filtered posts.
@@ -84,16 +85,9 @@ pub fn render_norg_page(
     context.insert("content", content);
     context.insert("metadata", metadata);
 
+    // Keep the reasons why Tera failed to render the template as the error causes
     tera.render(&format!("{}.html", layout), &context)
-        .map_err(|e| {
-            // Store the reason why Tera failed to render the template
-            let msg = format!("Failed to render template for '{}'", layout).bold();
-            if let Some(source) = e.source() {
-                eyre!("{msg}: {source}")
-            } else {
-                eyre!(msg)
-            }
-        })
+        .wrap_err_with(|| format!("Failed to render template for '{}'", layout).bold())
 }
 
 pub fn render_category_index(
@@ -114,14 +108,8 @@ pub fn render_category_index(
         ctx
     };
 
-    tera.render("categories.html", &context).map_err(|e| {
-        let msg = "Failed to render categories index".bold();
-        if let Some(source) = e.source() {
-            eyre!("{msg}: {source}")
-        } else {
-            eyre!(msg)
-        }
-    })
+    tera.render("categories.html", &context)
+        .wrap_err_with(|| "Failed to render categories index".bold())
 }
 
 pub fn render_category_page(
@@ -137,14 +125,8 @@ pub fn render_category_page(
         ctx.insert("posts", cat_posts);
         ctx
     };
-    tera.render("category.html", &context).map_err(|e| {
-        let msg = "Failed to render category page".bold();
-        if let Some(source) = e.source() {
-            eyre!("{msg}: {source}")
-        } else {
-            eyre!(msg)
-        }
-    })
+    tera.render("category.html", &context)
+        .wrap_err_with(|| "Failed to render category page".bold())
 }
 
 pub fn get_elapsed_time(instant: Instant) -> String {
@@ -172,32 +154,33 @@ pub fn init_tera(templates_dir: &str, theme_templates_dir: &Path) -> Result<Tera
     if theme_templates_dir.exists() {
         let theme_glob = format!("{}/**/*.html", theme_templates_dir.display());
         let theme_tera =
-            Tera::parse(&theme_glob).map_err(|e| eyre!("Error parsing theme templates: {}", e))?;
+            Tera::parse(&theme_glob).wrap_err("Error parsing theme templates")?;
         tera.extend(&theme_tera)?;
 
         let theme_xml_glob = format!("{}/**/*.xml", theme_templates_dir.display());
         let theme_xml_tera = Tera::parse(&theme_xml_glob)
-            .map_err(|e| eyre!("Error parsing theme XML templates: {}", e))?;
+            .wrap_err("Error parsing theme XML templates")?;
         tera.extend(&theme_xml_tera)?;
     }
 
     // Load user's templates
     let user_glob = format!("{}/**/*.html", templates_dir);
     let user_tera =
-        Tera::parse(&user_glob).map_err(|e| eyre!("Error parsing user templates: {}", e))?;
+        Tera::parse(&user_glob).wrap_err("Error parsing user templates")?;
     tera.extend(&user_tera)?;
 
     let xml_glob = format!("{}/**/*.xml", templates_dir);
     let xml_tera =
-        Tera::parse(&xml_glob).map_err(|e| eyre!("Error parsing user XML templates: {}", e))?;
+        Tera::parse(&xml_glob).wrap_err("Error parsing user XML templates")?;
     tera.extend(&xml_tera)?;
 
     tera.build_inheritance_chains()
-        .map_err(|e| eyre!("{}: {}", "Failed to build templates inheritance".bold(), e))?;
+        .wrap_err_with(|| "Failed to build templates inheritance".bold())?;
 
     // Register functions
     tera.register_function("now", crate::tera_functions::NowFunction);
     tera.register_function("generate_toc", crate::tera_functions::GenerateToc);
+    tera.register_function("asset_url", crate::tera_functions::AssetUrl::default());
 
     Ok(tera)
 }
@@ -233,25 +216,239 @@ fn normalize_datetimes(metadata: &mut toml::Value) {
     }
 }
 
+/// Builds the Norg-to-HTML conversion options from the site configuration.
+///
+/// `routes_url` is the URL prepended to local links, which differs from `rootUrl` while
+/// running the development server.
+pub fn convert_options(config: &SiteConfig, routes_url: &str) -> converter::html::ConvertOptions {
+    converter::html::ConvertOptions {
+        root_url: routes_url.to_string(),
+        math: config
+            .math
+            .as_ref()
+            .map(|math| math.renderer.clone())
+            .unwrap_or_default(),
+        highlight: config
+            .highlighter
+            .as_ref()
+            .filter(|highlighter| highlighter.is_builtin())
+            .map(|highlighter| converter::highlight::HighlightOptions {
+                theme: highlighter
+                    .theme
+                    .clone()
+                    .unwrap_or_else(|| converter::highlight::DEFAULT_THEME.to_string()),
+                style: highlighter.style,
+                line_numbers: highlighter.line_numbers,
+            }),
+        links: None,
+        source_path: PathBuf::new(),
+        slug: config
+            .headings
+            .as_ref()
+            .map(|headings| headings.slug)
+            .unwrap_or_default(),
+        omit_footnotes: config
+            .footnotes
+            .as_ref()
+            .is_some_and(|footnotes| !footnotes.section),
+        macros: None,
+        shortcodes: None,
+        images: None,
+    }
+}
+
+/// Loads the user-defined macros of a site.
+///
+/// # Arguments
+/// * `root_dir` - The site root directory.
+/// * `config` - The site configuration, its `[macros]` section declares inline macros.
+///
+/// # Returns
+/// `None` when the site does not define any macro.
+pub fn load_macros(
+    root_dir: &Path,
+    config: &SiteConfig,
+) -> Result<Option<std::sync::Arc<converter::macros::Macros>>> {
+    let macros = converter::macros::Macros::load(
+        root_dir,
+        config.macros.as_ref().unwrap_or(&HashMap::new()),
+    )?;
+    Ok((!macros.is_empty()).then(|| std::sync::Arc::new(macros)))
+}
+
+/// Creates the responsive image pipeline of a site.
+///
+/// # Arguments
+/// * `root_dir` - The site root directory.
+/// * `config` - The site configuration, its `[images]` section configures the pipeline.
+///
+/// # Returns
+/// `None` when the pipeline is disabled.
+pub fn load_images(
+    root_dir: &Path,
+    config: &SiteConfig,
+) -> Result<Option<std::sync::Arc<converter::images::ImagePipeline>>> {
+    let images = config.images.clone().unwrap_or_default();
+    if !images.enable {
+        return Ok(None);
+    }
+    let assets_dirs = vec![root_dir.join("assets"), root_dir.join("theme/assets")];
+    let pipeline = converter::images::ImagePipeline::new(
+        images,
+        assets_dirs,
+        crate::cache::images_dir(root_dir)?,
+    );
+    Ok(Some(std::sync::Arc::new(pipeline)))
+}
+
+/// Builds the index of every page and heading in the content directory.
+///
+/// The index lets the converter resolve links between files to their real permalinks and
+/// report links whose target file or heading does not exist.
+///
+/// # Arguments
+/// * `content_dir` - The content directory.
+/// * `options` - Conversion options, their `root_url` is prepended to the permalinks and their
+///   slug strategy is used for the heading ids.
+/// * `include_drafts` - Whether draft pages can be linked to.
+pub fn build_link_index(
+    content_dir: &Path,
+    options: &converter::html::ConvertOptions,
+    include_drafts: bool,
+) -> converter::links::LinkIndex {
+    use rayon::prelude::*;
+
+    let entries: Vec<_> = WalkDir::new(content_dir)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.path().extension().is_some_and(|ext| ext == "norg"))
+        .collect();
+
+    let pages: Vec<_> = entries
+        .par_iter()
+        .filter_map(|entry| {
+            let rel_path = entry.path().strip_prefix(content_dir).ok()?;
+            let content = std::fs::read_to_string(entry.path()).ok()?;
+            let metadata = extract_metadata_from_content(&content, rel_path, &options.root_url);
+            let is_draft = metadata
+                .get("draft")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false);
+            if is_draft && !include_drafts {
+                return None;
+            }
+
+            let page = converter::links::IndexedPage {
+                permalink: metadata
+                    .get("permalink")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or("/")
+                    .to_string(),
+                title: metadata
+                    .get("title")
+                    .and_then(|v| v.as_str())
+                    .map(String::from),
+                headings: converter::html::heading_ids(&content, options.slug),
+                aliases: metadata
+                    .get("aliases")
+                    .and_then(|v| v.as_array())
+                    .map(|aliases| {
+                        aliases
+                            .iter()
+                            .filter_map(|alias| alias.as_str().map(String::from))
+                            .collect()
+                    })
+                    .unwrap_or_default(),
+            };
+            Some((rel_path.to_path_buf(), page))
+        })
+        .collect();
+
+    let mut index = converter::links::LinkIndex::default();
+    for (rel_path, page) in pages {
+        index.insert(&rel_path, page);
+    }
+    index
+}
+
+/// Prints conversion diagnostics grouped by source file.
+///
+/// # Arguments
+/// * `diagnostics` - Diagnostics collected from the converted pages.
+pub fn print_diagnostics_report(diagnostics: &[Diagnostic]) {
+    let mut by_file: std::collections::BTreeMap<&Path, Vec<&Diagnostic>> =
+        std::collections::BTreeMap::new();
+    for diagnostic in diagnostics {
+        by_file
+            .entry(diagnostic.file.as_path())
+            .or_default()
+            .push(diagnostic);
+    }
+
+    println!(
+        "  {} {}  {:<12}  {}",
+        "!".yellow().bold(),
+        format!("{:<12}", "Diagnostics").bold(),
+        format!("{} issues", diagnostics.len()),
+        format!("in {} files", by_file.len()).dimmed()
+    );
+    for (file, mut file_diagnostics) in by_file {
+        file_diagnostics.sort_by_key(|d| (d.span.start_line, d.span.start_column));
+        println!("    {}", file.display().to_string().bold());
+        for diagnostic in file_diagnostics {
+            println!(
+                "      {:<8} {:<22} {}",
+                diagnostic.span.to_string().dimmed(),
+                diagnostic.kind.to_string().yellow(),
+                diagnostic.node
+            );
+        }
+    }
+}
+
 /// Full metadata + HTML conversion from pre-read content.
 ///
 /// This is the inner function that does the actual work. It does NOT read from disk.
-pub fn load_metadata_from_content(content: &str, rel_path: &Path, routes_url: &str) -> toml::Value {
-    let (html, toc) = converter::html::convert(content, routes_url);
-    let mut metadata = match converter::meta::convert(content, Some(converter::html::toc_to_toml(&toc))) {
+/// Returns the metadata along with the conversion diagnostics, tagged with `rel_path`.
+pub fn load_metadata_from_content(
+    content: &str,
+    rel_path: &Path,
+    options: &converter::html::ConvertOptions,
+) -> (toml::Value, Vec<Diagnostic>) {
+    let options = converter::html::ConvertOptions {
+        source_path: rel_path.to_path_buf(),
+        ..options.clone()
+    };
+    let mut converted = converter::html::convert(content, &options);
+    for diagnostic in &mut converted.diagnostics {
+        diagnostic.file = rel_path.to_path_buf();
+    }
+    let mut metadata = match converter::meta::convert(content, Some(converter::html::toc_to_toml(&converted.toc))) {
         Ok(m) => m,
         Err(e) => {
             warn!("Failed to parse metadata for {}: {}", rel_path.display(), e);
             toml::Value::Table(toml::map::Map::new())
         }
     };
-    let permalink = compute_permalink(rel_path, routes_url);
+    let permalink = compute_permalink(rel_path, &options.root_url);
     normalize_datetimes(&mut metadata);
     if let toml::Value::Table(ref mut table) = metadata {
-        table.insert("raw".to_string(), toml::Value::String(html.to_string()));
+        table.insert("raw".to_string(), toml::Value::String(converted.html));
+        table.insert(
+            "footnotes".to_string(),
+            converter::html::footnotes_to_toml(&converted.footnotes),
+        );
+        table.insert(
+            "images".to_string(),
+            converter::html::images_to_toml(&converted.images),
+        );
+        table.insert(
+            "links".to_string(),
+            converter::html::links_to_toml(&converted.links),
+        );
         table.insert("permalink".to_string(), toml::Value::String(permalink));
     }
-    metadata
+    (metadata, converted.diagnostics)
 }
 
 /// Lightweight metadata extraction from pre-read content (no parse_tree).
@@ -273,11 +470,51 @@ pub fn extract_metadata_from_content(content: &str, rel_path: &Path, routes_url:
     metadata
 }
 
+/// Looks up the cached conversion of a document, see [`BuildCache::get`].
+///
+/// Entries are keyed by the document source and the site inputs, the link targets and the local
+/// images a page uses are checked on their own so changing a page or an image only converts the
+/// pages using it again.
+///
+/// # Arguments
+/// * `cache` - The build cache.
+/// * `cache_key` - The document path relative to the content directory, without extension.
+/// * `content` - The document source.
+/// * `options` - The conversion options, holding the link index and the image pipeline.
+pub fn cached_conversion(
+    cache: &BuildCache,
+    cache_key: &Path,
+    content: &str,
+    options: &converter::html::ConvertOptions,
+) -> Option<serde_json::Value> {
+    let cached = cache.get(cache_key, content)?;
+    if let Some(links) = &options.links {
+        if !links.is_current(&cached) {
+            debug!(path = %cache_key.display(), "cache miss (link targets changed)");
+            return None;
+        }
+    }
+    if let Some(images) = &options.images {
+        if !images.register(&cached) {
+            debug!(path = %cache_key.display(), "cache miss (images changed)");
+            return None;
+        }
+    }
+    Some(cached)
+}
+
 /// Full metadata extraction including HTML content (`raw` field).
 ///
-/// Calls `load_metadata_from_content` which runs the full Norg→HTML conversion.
+/// Calls `load_metadata_from_content` which runs the full Norg→HTML conversion, unless `cache`
+/// holds the conversion of the unchanged document. Misses are stored in `cache`.
 /// The `raw` field is required by templates that list posts (e.g. posts.html).
-pub fn extract_metadata_only(path: PathBuf, rel_path: PathBuf, routes_url: &str) -> toml::Value {
+/// Conversion diagnostics are discarded, they are reported when the page itself is built.
+pub fn extract_metadata_only(
+    path: PathBuf,
+    rel_path: PathBuf,
+    options: &converter::html::ConvertOptions,
+    cache: Option<&mut BuildCache>,
+) -> toml::Value {
     let Ok(content) = std::fs::read_to_string(&path) else {
         error!(
             "{} {}",
@@ -286,7 +523,23 @@ pub fn extract_metadata_only(path: PathBuf, rel_path: PathBuf, routes_url: &str)
         );
         return toml::Value::Table(toml::map::Map::new());
     };
-    load_metadata_from_content(&content, &rel_path, routes_url)
+
+    let cache_key = rel_path.with_extension("");
+    let cached = cache
+        .as_deref()
+        .and_then(|cache| cached_conversion(cache, &cache_key, &content, options))
+        .and_then(|cached| serde_json::from_value(cached).ok());
+    if let Some(metadata) = cached {
+        return metadata;
+    }
+
+    let (metadata, diagnostics) = load_metadata_from_content(&content, &rel_path, options);
+    if let Some(cache) = cache {
+        if let Ok(value) = serde_json::to_value(&metadata) {
+            cache.insert(&cache_key, &content, value, diagnostics);
+        }
+    }
+    metadata
 }
 
 /// Validates content metadata against a schema.
@@ -356,8 +609,9 @@ pub fn collect_all_posts_categories(posts: &[toml::Value]) -> HashSet<String> {
 
 pub fn collect_all_posts_metadata(
     content_dir: &Path,
-    routes_url: &str,
+    options: &converter::html::ConvertOptions,
     collections: &[CollectionConfig],
+    mut cache: Option<&mut BuildCache>,
 ) -> Result<Vec<toml::Value>> {
     // Collect paths first (WalkDir is sync)
     let entries: Vec<_> = WalkDir::new(content_dir)
@@ -386,10 +640,12 @@ pub fn collect_all_posts_metadata(
         })
         .collect();
 
-    // Process metadata extraction
+    // Process metadata extraction, unchanged posts are read from the cache
     let mut posts: Vec<toml::Value> = entries
         .into_iter()
-        .map(|(path, rel_path)| extract_metadata_only(path, rel_path, routes_url))
+        .map(|(path, rel_path)| {
+            extract_metadata_only(path, rel_path, options, cache.as_deref_mut())
+        })
         .collect();
 
     posts.sort_by(|a, b| {
@@ -1,7 +1,10 @@
 use std::collections::HashMap;
+use std::sync::Arc;
 
 use tera::{Error, Function, Result, Value};
 
+use crate::assets::fingerprint::Fingerprints;
+
 fn encode_uri_component(s: &str) -> String {
     s.bytes()
         .map(|b| match b {
@@ -159,3 +162,28 @@ impl Function for GenerateToc {
         true
     }
 }
+
+/// Asset URL function, returns the fingerprinted path of an asset when fingerprinting is enabled
+/// Template usage: {{ asset_url(path="css/style.css") }} → "/assets/css/style.1a2b3c4d.css"
+#[derive(Default)]
+pub struct AssetUrl {
+    pub fingerprints: Option<Arc<Fingerprints>>,
+}
+impl Function for AssetUrl {
+    fn call(&self, args: &HashMap<String, Value>) -> Result<Value> {
+        let path = args
+            .get("path")
+            .ok_or_else(|| Error::msg("Missing 'path' argument"))?
+            .as_str()
+            .ok_or_else(|| Error::msg("`path` must be a string"))?;
+
+        let url = match &self.fingerprints {
+            Some(fingerprints) => fingerprints.url(path),
+            None => format!(
+                "/assets/{}",
+                path.trim_start_matches('/').trim_start_matches("assets/")
+            ),
+        };
+        Ok(Value::String(url))
+    }
+}
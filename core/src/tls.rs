@@ -0,0 +1,129 @@
+//! Self-signed certificates to serve the development server over HTTPS.
+//!
+//! The certificate is generated once and cached in `~/.cache/norgolith/dev-certificate/`
+//! (XDG_CACHE_HOME), it is generated again when the served host names change or when it is
+//! about to expire.
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+
+use chrono::{Datelike, Utc};
+use colored::Colorize;
+use eyre::{eyre, Result, WrapErr};
+use rcgen::{CertificateParams, KeyPair};
+use rustls::pki_types::{pem::PemObject, CertificateDer, PrivateKeyDer};
+
+/// Name of the directory holding the certificate, inside the Norgolith cache directory
+const CERT_DIR: &str = "dev-certificate";
+const CERT_FILE: &str = "cert.pem";
+const KEY_FILE: &str = "key.pem";
+/// Name of the file listing the host names of the certificate
+const NAMES_FILE: &str = "names";
+
+/// Days a certificate is valid for, browsers reject certificates valid for more than 398 days
+const VALIDITY_DAYS: u64 = 365;
+/// Certificates are generated again this many days before they expire
+const RENEWAL_DAYS: u64 = 30;
+
+/// Builds the TLS configuration of the development server, generating its certificate if
+/// needed.
+///
+/// # Arguments
+/// * `names` - The host names and IP addresses the server is reached at.
+///
+/// # Returns
+/// * `Result<Arc<rustls::ServerConfig>>` - The TLS configuration, otherwise an error.
+pub fn server_config(names: &[String]) -> Result<Arc<rustls::ServerConfig>> {
+    let cache_dir = dirs::cache_dir()
+        .ok_or_else(|| eyre!("{}: cannot determine cache directory", "Failed".bold()))?;
+    let (cert_path, key_path) = certificate(&cache_dir.join("norgolith").join(CERT_DIR), names)?;
+
+    let certs = CertificateDer::pem_file_iter(&cert_path)
+        .and_then(|certs| certs.collect::<Result<Vec<_>, _>>())
+        .map_err(|e| eyre!("Failed to read certificate {}: {}", cert_path.display(), e))?;
+    let key = PrivateKeyDer::from_pem_file(&key_path)
+        .map_err(|e| eyre!("Failed to read private key {}: {}", key_path.display(), e))?;
+
+    let mut config = rustls::ServerConfig::builder_with_provider(Arc::new(
+        rustls::crypto::ring::default_provider(),
+    ))
+    .with_safe_default_protocol_versions()?
+    .with_no_client_auth()
+    .with_single_cert(certs, key)
+    .wrap_err_with(|| "Invalid development certificate".bold())?;
+    config.alpn_protocols = vec![b"h2".to_vec(), b"http/1.1".to_vec()];
+    Ok(Arc::new(config))
+}
+
+/// Returns the paths of the cached certificate and private key, generating them when they are
+/// missing, about to expire or issued for other host names.
+///
+/// # Arguments
+/// * `dir` - The directory the certificate is cached in.
+/// * `names` - The host names and IP addresses of the certificate.
+fn certificate(dir: &Path, names: &[String]) -> Result<(PathBuf, PathBuf)> {
+    let cert_path = dir.join(CERT_FILE);
+    let key_path = dir.join(KEY_FILE);
+    let names_path = dir.join(NAMES_FILE);
+
+    let cached_names = std::fs::read_to_string(&names_path).unwrap_or_default();
+    let renew_age = Duration::from_secs((VALIDITY_DAYS - RENEWAL_DAYS) * 24 * 60 * 60);
+    let is_fresh = std::fs::metadata(&cert_path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+        .and_then(|modified| SystemTime::now().duration_since(modified).ok())
+        .is_some_and(|age| age < renew_age);
+    if is_fresh && key_path.is_file() && cached_names == names.join("\n") {
+        return Ok((cert_path, key_path));
+    }
+
+    tracing::debug!(?names, "Generating development certificate");
+    let mut params = CertificateParams::new(names.to_vec())
+        .wrap_err_with(|| "Invalid certificate host names".bold())?;
+    let today = Utc::now().date_naive();
+    let expiry = today + chrono::Days::new(VALIDITY_DAYS);
+    params.not_before = rcgen::date_time_ymd(today.year(), today.month() as u8, today.day() as u8);
+    params.not_after =
+        rcgen::date_time_ymd(expiry.year(), expiry.month() as u8, expiry.day() as u8);
+    let key_pair = KeyPair::generate()?;
+    let cert = params
+        .self_signed(&key_pair)
+        .wrap_err_with(|| "Failed to generate the development certificate".bold())?;
+
+    std::fs::create_dir_all(dir).wrap_err_with(|| format!("Failed to create {}", dir.display()))?;
+    std::fs::write(&cert_path, cert.pem())?;
+    std::fs::write(&key_path, key_pair.serialize_pem())?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(&key_path, std::fs::Permissions::from_mode(0o600))?;
+    }
+    std::fs::write(&names_path, names.join("\n"))?;
+    Ok((cert_path, key_path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_certificate_is_cached() {
+        let dir = tempfile::tempdir().unwrap();
+        let names = vec!["localhost".to_string(), "127.0.0.1".to_string()];
+
+        let (cert_path, key_path) = certificate(dir.path(), &names).unwrap();
+        let cert = std::fs::read_to_string(&cert_path).unwrap();
+        assert!(cert.starts_with("-----BEGIN CERTIFICATE-----"));
+        assert!(key_path.is_file());
+
+        // The same names reuse the cached certificate
+        certificate(dir.path(), &names).unwrap();
+        assert_eq!(std::fs::read_to_string(&cert_path).unwrap(), cert);
+
+        // Other names generate a new one
+        let names = vec!["localhost".to_string(), "192.168.1.10".to_string()];
+        certificate(dir.path(), &names).unwrap();
+        assert_ne!(std::fs::read_to_string(&cert_path).unwrap(), cert);
+    }
+}